@@ -0,0 +1,38 @@
+use std::{env, fs, path::Path};
+
+// Embeds every standard library in default_std, so adding one is only a matter of adding its file
+fn main() {
+    let default_std = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("default_std");
+    println!("cargo:rerun-if-changed={}", default_std.display());
+
+    let mut libraries = fs::read_dir(&default_std)
+        .expect("couldn't read default_std")
+        .map(|entry| entry.expect("couldn't read default_std entry").path())
+        .filter(|path| path.extension().and_then(|extension| extension.to_str()) == Some("toml"))
+        .map(|path| {
+            println!("cargo:rerun-if-changed={}", path.display());
+            let name = path.file_stem().unwrap().to_string_lossy().into_owned();
+            (name, path)
+        })
+        .collect::<Vec<_>>();
+
+    libraries.sort();
+
+    let mut output = String::from("&[\n");
+
+    for (name, path) in libraries {
+        output.push_str(&format!(
+            "    ({:?}, include_str!({:?})),\n",
+            name,
+            path.to_string_lossy(),
+        ));
+    }
+
+    output.push_str("]\n");
+
+    fs::write(
+        Path::new(&env::var("OUT_DIR").unwrap()).join("default_std.rs"),
+        output,
+    )
+    .expect("couldn't write default_std.rs");
+}
@@ -0,0 +1,43 @@
+use super::range;
+
+use full_moon::{ast::Ast, tokenizer::TokenReference, visitors::Visitor};
+
+/// A single or multi-line comment, including the `--` that starts it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Comment {
+    pub range: (usize, usize),
+    pub text: String,
+}
+
+/// Every comment in the file, in order.
+pub fn comments(ast: &Ast) -> Vec<Comment> {
+    let mut visitor = CommentVisitor {
+        comments: Vec::new(),
+    };
+
+    visitor.visit_ast(ast);
+    visitor.comments
+}
+
+struct CommentVisitor {
+    comments: Vec<Comment>,
+}
+
+impl CommentVisitor {
+    fn push(&mut self, token: &TokenReference) {
+        self.comments.push(Comment {
+            range: range(token),
+            text: token.to_string(),
+        });
+    }
+}
+
+impl Visitor<'_> for CommentVisitor {
+    fn visit_multi_line_comment(&mut self, token: &TokenReference) {
+        self.push(token);
+    }
+
+    fn visit_single_line_comment(&mut self, token: &TokenReference) {
+        self.push(token);
+    }
+}
@@ -0,0 +1,454 @@
+use super::{is_type_function, range};
+use std::fmt;
+
+use full_moon::{
+    ast,
+    tokenizer::{Symbol, TokenType},
+};
+
+// full-moon parses binary operators as a flat, right leaning list, and unary operators as applying
+// to everything after them. This rebuilds the expression with Lua's actual precedence, so that
+// `not x == y` is understood as `(not x) == y`, and `a + b * c` as `a + (b * c)`.
+pub enum ExpressionTree<'a, 'ast> {
+    Binary {
+        lhs: Box<ExpressionTree<'a, 'ast>>,
+        op: &'a ast::BinOp<'ast>,
+        rhs: Box<ExpressionTree<'a, 'ast>>,
+    },
+
+    Unary {
+        op: &'a ast::UnOp<'ast>,
+        operand: Box<ExpressionTree<'a, 'ast>>,
+    },
+
+    // `(expression)`, the inside has its own tree
+    Parentheses(&'a ast::Expression<'ast>),
+
+    Value(&'a ast::Value<'ast>),
+}
+
+enum Item<'a, 'ast> {
+    Atom(ExpressionTree<'a, 'ast>),
+    Binary(&'a ast::BinOp<'ast>),
+    Unary(&'a ast::UnOp<'ast>),
+}
+
+const UNARY_PRECEDENCE: u8 = 7;
+
+pub fn precedence(op: &ast::BinOp) -> u8 {
+    match op {
+        ast::BinOp::Or(_) => 1,
+        ast::BinOp::And(_) => 2,
+        ast::BinOp::GreaterThan(_)
+        | ast::BinOp::GreaterThanEqual(_)
+        | ast::BinOp::LessThan(_)
+        | ast::BinOp::LessThanEqual(_)
+        | ast::BinOp::TildeEqual(_)
+        | ast::BinOp::TwoEqual(_) => 3,
+        ast::BinOp::TwoDots(_) => 4,
+        ast::BinOp::Plus(_) | ast::BinOp::Minus(_) => 5,
+        ast::BinOp::Star(_) | ast::BinOp::Slash(_) | ast::BinOp::Percent(_) => 6,
+        ast::BinOp::Caret(_) => 8,
+    }
+}
+
+pub fn operator_text(op: &ast::BinOp) -> &'static str {
+    match op {
+        ast::BinOp::And(_) => "and",
+        ast::BinOp::Caret(_) => "^",
+        ast::BinOp::GreaterThan(_) => ">",
+        ast::BinOp::GreaterThanEqual(_) => ">=",
+        ast::BinOp::LessThan(_) => "<",
+        ast::BinOp::LessThanEqual(_) => "<=",
+        ast::BinOp::Minus(_) => "-",
+        ast::BinOp::Or(_) => "or",
+        ast::BinOp::Percent(_) => "%",
+        ast::BinOp::Plus(_) => "+",
+        ast::BinOp::Slash(_) => "/",
+        ast::BinOp::Star(_) => "*",
+        ast::BinOp::TildeEqual(_) => "~=",
+        ast::BinOp::TwoDots(_) => "..",
+        ast::BinOp::TwoEqual(_) => "==",
+    }
+}
+
+fn is_right_associative(op: &ast::BinOp) -> bool {
+    matches!(op, ast::BinOp::Caret(_) | ast::BinOp::TwoDots(_))
+}
+
+pub fn is_comparison(op: &ast::BinOp) -> bool {
+    precedence(op) == 3
+}
+
+pub fn is_equality(op: &ast::BinOp) -> bool {
+    matches!(op, ast::BinOp::TildeEqual(_) | ast::BinOp::TwoEqual(_))
+}
+
+// Every expression full-moon gives us in a chain, other than the first. Visitors will visit all of
+// these as expressions of their own, and can use this to only look at the whole expression once.
+pub fn chained_expressions<'a, 'ast>(expression: &'a ast::Expression<'ast>) -> Vec<(usize, usize)> {
+    let mut chained = Vec::new();
+    let mut current = expression;
+
+    loop {
+        current = match current {
+            ast::Expression::Value {
+                binop: Some(binop), ..
+            } => binop.rhs(),
+
+            ast::Expression::UnaryOperator { expression, .. } => expression,
+
+            _ => return chained,
+        };
+
+        chained.push(range(current));
+    }
+}
+
+impl<'a, 'ast> ExpressionTree<'a, 'ast> {
+    pub fn new(expression: &'a ast::Expression<'ast>) -> Self {
+        let mut items = Vec::new();
+        flatten(expression, &mut items);
+        items.reverse();
+
+        let tree = parse(&mut items, 0);
+        assert!(items.is_empty(), "expression tree did not use every item");
+        tree
+    }
+
+    pub fn range(&self) -> (usize, usize) {
+        match self {
+            ExpressionTree::Binary { lhs, rhs, .. } => (lhs.range().0, rhs.range().1),
+            ExpressionTree::Unary { op, operand } => (range(op).0, operand.range().1),
+            ExpressionTree::Parentheses(expression) => range(expression),
+            ExpressionTree::Value(value) => value_range(value),
+        }
+    }
+
+    // Calls the callback on every binary operation in the tree, not including ones in parentheses
+    pub fn for_each_binary<F: FnMut(&Self, &'a ast::BinOp<'ast>, &Self)>(&self, callback: &mut F) {
+        match self {
+            ExpressionTree::Binary { lhs, op, rhs } => {
+                lhs.for_each_binary(callback);
+                callback(lhs, op, rhs);
+                rhs.for_each_binary(callback);
+            }
+
+            ExpressionTree::Unary { operand, .. } => operand.for_each_binary(callback),
+
+            ExpressionTree::Parentheses(_) | ExpressionTree::Value(_) => {}
+        }
+    }
+
+    // The value inside, looking through any parentheses
+    pub fn value(&self) -> Option<&'a ast::Value<'ast>> {
+        match self {
+            ExpressionTree::Value(value) => match value {
+                ast::Value::ParseExpression(expression) => ExpressionTree::new(expression).value(),
+
+                _ => Some(value),
+            },
+
+            ExpressionTree::Parentheses(ast::Expression::Parentheses { expression, .. }) => {
+                ExpressionTree::new(expression).value()
+            }
+
+            _ => None,
+        }
+    }
+
+    // The type of the expression if it is a literal, such as `1`, `"foo"`, or `{}`
+    pub fn literal_type(&self) -> Option<StaticType> {
+        self.value().and_then(literal_type)
+    }
+
+    // The type of the expression if it can be known without any inference on variables.
+    // Literals, as well as things like `#x`, `not x`, and `type(x)` are included.
+    pub fn static_type(&self, roblox: bool) -> Option<StaticType> {
+        match self {
+            ExpressionTree::Binary { lhs, op, rhs } => match op {
+                _ if is_comparison(op) => Some(StaticType::Boolean),
+
+                ast::BinOp::And(_) | ast::BinOp::Or(_) => None,
+
+                // Both sides must be typed, otherwise metamethods could return anything
+                ast::BinOp::TwoDots(_) => {
+                    match (lhs.static_type(roblox)?, rhs.static_type(roblox)?) {
+                        (StaticType::Number, StaticType::Number)
+                        | (StaticType::Number, StaticType::String)
+                        | (StaticType::String, StaticType::Number)
+                        | (StaticType::String, StaticType::String) => Some(StaticType::String),
+                        _ => None,
+                    }
+                }
+
+                _ => match (lhs.static_type(roblox)?, rhs.static_type(roblox)?) {
+                    (StaticType::Number, StaticType::Number) => Some(StaticType::Number),
+                    _ => None,
+                },
+            },
+
+            ExpressionTree::Unary { op, operand } => match op {
+                // CAVEAT: __len on userdata can return anything, but it's not worth caring about
+                ast::UnOp::Hash(_) => Some(StaticType::Number),
+                ast::UnOp::Not(_) => Some(StaticType::Boolean),
+                ast::UnOp::Minus(_) => match operand.static_type(roblox)? {
+                    StaticType::Number => Some(StaticType::Number),
+                    _ => None,
+                },
+            },
+
+            ExpressionTree::Parentheses(expression) => match expression {
+                ast::Expression::Parentheses { expression, .. } => {
+                    ExpressionTree::new(expression).static_type(roblox)
+                }
+
+                _ => None,
+            },
+
+            ExpressionTree::Value(value) => match value {
+                ast::Value::FunctionCall(call) => {
+                    if_chain::if_chain! {
+                        if let ast::Prefix::Name(name) = call.prefix();
+                        if is_type_function(&name.to_string(), roblox);
+                        let mut suffixes = call.iter_suffixes();
+                        if let Some(ast::Suffix::Call(ast::Call::AnonymousCall(_))) = suffixes.next();
+                        if suffixes.next().is_none();
+                        then {
+                            Some(StaticType::String)
+                        } else {
+                            None
+                        }
+                    }
+                }
+
+                ast::Value::ParseExpression(expression) => {
+                    ExpressionTree::new(expression).static_type(roblox)
+                }
+
+                _ => literal_type(value),
+            },
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StaticType {
+    Boolean,
+    Function,
+    Nil,
+    Number,
+    String,
+    Table,
+}
+
+impl StaticType {
+    // The type as it would be written in a sentence, such as "a number" or "nil"
+    pub fn describe(self) -> String {
+        match self {
+            StaticType::Nil => "nil".to_owned(),
+            _ => format!("a {}", self),
+        }
+    }
+}
+
+impl fmt::Display for StaticType {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StaticType::Boolean => write!(formatter, "boolean"),
+            StaticType::Function => write!(formatter, "function"),
+            StaticType::Nil => write!(formatter, "nil"),
+            StaticType::Number => write!(formatter, "number"),
+            StaticType::String => write!(formatter, "string"),
+            StaticType::Table => write!(formatter, "table"),
+        }
+    }
+}
+
+// full-moon ends `x[y]` at `y` rather than the closing bracket, which would cut it off
+fn value_range(value: &ast::Value) -> (usize, usize) {
+    let (start, end) = range(value);
+
+    if let ast::Value::Var(ast::Var::Expression(var_expression)) = value {
+        if let Some(ast::Suffix::Index(ast::Index::Brackets { brackets, .. })) =
+            var_expression.iter_suffixes().last()
+        {
+            return (start, range(brackets.tokens().1).1);
+        }
+    }
+
+    (start, end)
+}
+
+pub fn literal_type(value: &ast::Value) -> Option<StaticType> {
+    match value {
+        ast::Value::Function(_) => Some(StaticType::Function),
+        ast::Value::Number(_) => Some(StaticType::Number),
+        ast::Value::String(_) => Some(StaticType::String),
+        ast::Value::TableConstructor(_) => Some(StaticType::Table),
+        ast::Value::Symbol(symbol) => match *symbol.token_type() {
+            TokenType::Symbol {
+                symbol: Symbol::False,
+            }
+            | TokenType::Symbol {
+                symbol: Symbol::True,
+            } => Some(StaticType::Boolean),
+            TokenType::Symbol {
+                symbol: Symbol::Nil,
+            } => Some(StaticType::Nil),
+            _ => None,
+        },
+        ast::Value::ParseExpression(expression) => ExpressionTree::new(expression).literal_type(),
+        ast::Value::FunctionCall(_) | ast::Value::Var(_) => None,
+    }
+}
+
+fn flatten<'a, 'ast>(expression: &'a ast::Expression<'ast>, items: &mut Vec<Item<'a, 'ast>>) {
+    match expression {
+        ast::Expression::Parentheses { .. } => {
+            items.push(Item::Atom(ExpressionTree::Parentheses(expression)));
+        }
+
+        ast::Expression::UnaryOperator { unop, expression } => {
+            items.push(Item::Unary(unop));
+            flatten(expression, items);
+        }
+
+        ast::Expression::Value { value, binop } => {
+            items.push(Item::Atom(ExpressionTree::Value(value)));
+
+            if let Some(binop) = binop {
+                items.push(Item::Binary(binop.bin_op()));
+                flatten(binop.rhs(), items);
+            }
+        }
+    }
+}
+
+// Precedence climbing, `items` is reversed so that popping gives the next item
+fn parse<'a, 'ast>(
+    items: &mut Vec<Item<'a, 'ast>>,
+    min_precedence: u8,
+) -> ExpressionTree<'a, 'ast> {
+    let mut lhs = match items.pop().expect("expected an operand") {
+        Item::Atom(atom) => atom,
+
+        // Only `^` binds tighter than unary operators, so `-x ^ 2` is `-(x ^ 2)`
+        Item::Unary(op) => ExpressionTree::Unary {
+            op,
+            operand: Box::new(parse(items, UNARY_PRECEDENCE + 1)),
+        },
+
+        Item::Binary(_) => unreachable!("binary operator without a left hand side"),
+    };
+
+    loop {
+        let op = match items.last() {
+            Some(Item::Binary(op)) if precedence(op) >= min_precedence => *op,
+            _ => return lhs,
+        };
+
+        items.pop();
+
+        let next_precedence = if is_right_associative(op) {
+            precedence(op)
+        } else {
+            precedence(op) + 1
+        };
+
+        lhs = ExpressionTree::Binary {
+            lhs: Box::new(lhs),
+            op,
+            rhs: Box::new(parse(items, next_precedence)),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use full_moon::visitors::Visitor;
+
+    fn unary_operator_text(op: &ast::UnOp) -> &'static str {
+        match op {
+            ast::UnOp::Hash(_) => "#",
+            ast::UnOp::Minus(_) => "-",
+            ast::UnOp::Not(_) => "not",
+        }
+    }
+
+    fn print_tree(source: &str, tree: &ExpressionTree) -> String {
+        match tree {
+            ExpressionTree::Binary { lhs, op, rhs } => format!(
+                "({} {} {})",
+                print_tree(source, lhs),
+                operator_text(op),
+                print_tree(source, rhs)
+            ),
+
+            ExpressionTree::Unary { op, operand } => {
+                format!(
+                    "({} {})",
+                    unary_operator_text(op),
+                    print_tree(source, operand)
+                )
+            }
+
+            _ => {
+                let (start, end) = tree.range();
+                source[start..end].trim().to_owned()
+            }
+        }
+    }
+
+    fn trees(source: &str) -> Vec<String> {
+        struct TreeVisitor<'s> {
+            source: &'s str,
+            trees: Vec<String>,
+        }
+
+        impl Visitor<'_> for TreeVisitor<'_> {
+            fn visit_local_assignment(&mut self, node: &ast::LocalAssignment) {
+                for expression in node.expr_list() {
+                    self.trees
+                        .push(print_tree(self.source, &ExpressionTree::new(expression)));
+                }
+            }
+        }
+
+        let ast = full_moon::parse(source).unwrap();
+        let mut visitor = TreeVisitor {
+            source,
+            trees: Vec::new(),
+        };
+
+        visitor.visit_ast(&ast);
+        visitor.trees
+    }
+
+    #[test]
+    fn test_precedence() {
+        assert_eq!(
+            trees(
+                "local _ = a + b * c
+local _ = not x == y
+local _ = a or b and c
+local _ = -x ^ 2
+local _ = a .. b .. c
+local _ = a - b - c
+local _ = 2 ^ -x ^ 2
+local _ = (a + b) * c"
+            ),
+            vec![
+                "(a + (b * c))",
+                "((not x) == y)",
+                "(a or (b and c))",
+                "(- (x ^ 2))",
+                "(a .. (b .. c))",
+                "((a - b) - c)",
+                "(2 ^ (- (x ^ 2)))",
+                "((a + b) * c)",
+            ]
+        );
+    }
+}
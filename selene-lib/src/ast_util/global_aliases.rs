@@ -0,0 +1,111 @@
+use super::{range, scopes::ScopeManager};
+use std::collections::HashMap;
+
+use full_moon::{
+    ast::{self, Ast},
+    tokenizer::TokenReference,
+};
+
+// A use of a global, such as `debug.sethook`, possibly through a local that aliases part of it
+pub struct GlobalPath {
+    pub path: Vec<String>,
+    // From the start of the name to the end of the last field, `debug.sethook` in `debug.sethook()`
+    pub range: (usize, usize),
+    // Where the alias this went through was declared, if it went through one
+    pub alias: Option<(usize, usize)>,
+}
+
+// Locals that are direct aliases of a global, such as `local execute = os.execute` or
+// `local system = os`, so that uses through them can be followed back to the global
+pub struct GlobalAliases {
+    // The global path of every alias, by the range of its declaration
+    aliases: HashMap<(usize, usize), Vec<String>>,
+    scope_manager: ScopeManager,
+}
+
+impl GlobalAliases {
+    pub fn new(ast: &Ast) -> Self {
+        Self {
+            aliases: HashMap::new(),
+            scope_manager: ScopeManager::new(ast),
+        }
+    }
+
+    pub fn insert(&mut self, name: &TokenReference, path: Vec<String>) {
+        self.aliases.insert(range(name), path);
+    }
+
+    // The global path for something like `os.execute`, following aliases.
+    // Returns None for anything that isn't a global, such as a local that isn't an alias.
+    pub fn path<'a, 'ast: 'a>(
+        &self,
+        name: &TokenReference,
+        suffixes: impl Iterator<Item = &'a ast::Suffix<'ast>>,
+    ) -> Option<GlobalPath> {
+        let (start, mut end) = range(name);
+
+        let (mut path, alias) = match self
+            .scope_manager
+            .reference_at_byte(start)
+            .and_then(|reference| reference.resolved)
+        {
+            Some(variable) => {
+                let declaration = self.scope_manager.variables[variable].identifiers[0];
+                (self.aliases.get(&declaration)?.clone(), Some(declaration))
+            }
+
+            None => (vec![name.to_string()], None),
+        };
+
+        for suffix in suffixes {
+            match suffix {
+                ast::Suffix::Index(ast::Index::Dot { name, .. }) => {
+                    path.push(name.to_string());
+                    end = range::<_, usize>(name).1;
+                }
+
+                _ => break,
+            }
+        }
+
+        Some(GlobalPath {
+            path,
+            range: (start, end),
+            alias,
+        })
+    }
+
+    // The global that a local would alias if it was defined as `expression`, along with the
+    // var it is read from. Only direct aliases count, such as `local sethook = debug.sethook`.
+    pub fn aliased_global<'a, 'ast>(
+        &self,
+        expression: &'a ast::Expression<'ast>,
+    ) -> Option<(&'a ast::Var<'ast>, GlobalPath)> {
+        let var = match expression {
+            ast::Expression::Value { value, binop: None } => match &**value {
+                ast::Value::Var(var) => var,
+                _ => return None,
+            },
+
+            _ => return None,
+        };
+
+        let path = match var {
+            ast::Var::Name(global) => self.path(global, std::iter::empty()),
+
+            ast::Var::Expression(var_expression) => match var_expression.prefix() {
+                ast::Prefix::Name(global)
+                    if var_expression.iter_suffixes().all(|suffix| {
+                        matches!(suffix, ast::Suffix::Index(ast::Index::Dot { .. }))
+                    }) =>
+                {
+                    self.path(global, var_expression.iter_suffixes())
+                }
+
+                _ => None,
+            },
+        }?;
+
+        Some((var, path))
+    }
+}
@@ -0,0 +1,74 @@
+use super::expression_tree::{ExpressionTree, StaticType};
+use std::collections::HashMap;
+
+use full_moon::ast;
+
+// Tracks the locals in each block that were assigned a literal, such as `local count = 5`.
+// Locals stop being tracked once they are assigned anything else, anywhere, so only uses in the
+// same block as the assignment can be trusted to still have the literal's type.
+// Visitors should call each method from the visitor function of the same name.
+#[derive(Default)]
+pub struct LiteralLocals {
+    blocks: Vec<HashMap<String, StaticType>>,
+    // Locals are only in scope after their assignment, so `local x = #x` uses the outer `x`
+    pending: Vec<(String, Option<StaticType>)>,
+}
+
+impl LiteralLocals {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // The type of the literal a local was assigned, if it was assigned in the current block
+    pub fn get(&self, name: &str) -> Option<StaticType> {
+        self.blocks.last()?.get(name).copied()
+    }
+
+    fn forget(&mut self, name: &str) {
+        for block in &mut self.blocks {
+            block.remove(name);
+        }
+    }
+
+    pub fn visit_assignment(&mut self, assignment: &ast::Assignment) {
+        for var in assignment.var_list() {
+            if let ast::Var::Name(name) = var {
+                self.forget(&name.to_string());
+            }
+        }
+    }
+
+    pub fn visit_block(&mut self) {
+        self.blocks.push(HashMap::new());
+    }
+
+    pub fn visit_block_end(&mut self) {
+        self.blocks.pop();
+    }
+
+    pub fn visit_local_assignment(&mut self, local_assignment: &ast::LocalAssignment) {
+        let mut expressions = local_assignment.expr_list().iter();
+
+        for name in local_assignment.name_list() {
+            let literal_type = expressions
+                .next()
+                .and_then(|expression| ExpressionTree::new(expression).literal_type());
+
+            self.pending.push((name.to_string(), literal_type));
+        }
+    }
+
+    pub fn visit_local_assignment_end(&mut self) {
+        for (name, literal_type) in std::mem::take(&mut self.pending) {
+            self.forget(&name);
+
+            if let (Some(block), Some(literal_type)) = (self.blocks.last_mut(), literal_type) {
+                block.insert(name, literal_type);
+            }
+        }
+    }
+
+    pub fn visit_local_function(&mut self, local_function: &ast::LocalFunction) {
+        self.forget(&local_function.name().to_string());
+    }
+}
@@ -0,0 +1,150 @@
+use super::range;
+
+use full_moon::{ast, node::Node};
+
+// A loop that a visitor is entering, given to `open_loop` by `visit_loops!`
+#[derive(Clone, Copy)]
+pub enum Loop<'a, 'ast> {
+    GenericFor(&'a ast::GenericFor<'ast>),
+    NumericFor(&'a ast::NumericFor<'ast>),
+    Repeat(&'a ast::Repeat<'ast>),
+    While(&'a ast::While<'ast>),
+}
+
+impl<'a, 'ast> Loop<'a, 'ast> {
+    // The condition checked every iteration, `#queue > 0` in `while #queue > 0 do`
+    pub fn condition(self) -> Option<&'a ast::Expression<'ast>> {
+        match self {
+            Loop::Repeat(repeat) => Some(repeat.until()),
+            Loop::While(while_loop) => Some(while_loop.condition()),
+            Loop::GenericFor(_) | Loop::NumericFor(_) => None,
+        }
+    }
+
+    // The values of a for loop, such as `pairs(t)` or `1, #t`, which are only evaluated once
+    pub fn header(self) -> Option<(usize, usize)> {
+        fn between<N: Node>(start: N, end: N) -> Option<(usize, usize)> {
+            Some((range::<_, usize>(start).0, range::<_, usize>(end).1))
+        }
+
+        match self {
+            Loop::GenericFor(generic_for) => {
+                let expressions = generic_for.expr_list();
+                between(
+                    expressions.iter().next().unwrap(),
+                    expressions.iter().last().unwrap(),
+                )
+            }
+
+            Loop::NumericFor(numeric_for) => between(
+                numeric_for.start(),
+                numeric_for.step().unwrap_or_else(|| numeric_for.end()),
+            ),
+
+            Loop::Repeat(_) | Loop::While(_) => None,
+        }
+    }
+}
+
+// The loops around what is being visited, innermost last. Function bodies start over, as they
+// don't run as part of the loops they are created in.
+pub struct LoopStack<T> {
+    functions: Vec<Vec<T>>,
+}
+
+impl<T> LoopStack<T> {
+    pub fn new() -> Self {
+        Self {
+            functions: vec![Vec::new()],
+        }
+    }
+
+    fn loops(&self) -> &Vec<T> {
+        self.functions.last().unwrap()
+    }
+
+    fn loops_mut(&mut self) -> &mut Vec<T> {
+        self.functions.last_mut().unwrap()
+    }
+
+    pub fn open_function(&mut self) {
+        self.functions.push(Vec::new());
+    }
+
+    pub fn close_function(&mut self) {
+        self.functions.pop();
+    }
+
+    // Whether anything is inside of a function that started inside of what is being visited
+    pub fn in_function(&self) -> bool {
+        self.functions.len() > 1
+    }
+
+    pub fn open_loop(&mut self, value: T) {
+        self.loops_mut().push(value);
+    }
+
+    pub fn close_loop(&mut self) -> T {
+        self.loops_mut().pop().unwrap()
+    }
+
+    pub fn in_loop(&self) -> bool {
+        !self.loops().is_empty()
+    }
+
+    // The innermost loop, if it is in the same function
+    pub fn current_mut(&mut self) -> Option<&mut T> {
+        self.loops_mut().last_mut()
+    }
+
+    // Every loop in the same function, innermost last
+    pub fn iter_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut T> {
+        self.loops_mut().iter_mut()
+    }
+}
+
+impl<T> Default for LoopStack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Implements the `Visitor` methods for every kind of loop, calling `self.open_loop` with the
+// `Loop` being entered and `self.close_loop()` when it is left.
+macro_rules! visit_loops {
+    () => {
+        fn visit_generic_for(&mut self, node: &full_moon::ast::GenericFor) {
+            self.open_loop($crate::ast_util::loops::Loop::GenericFor(node));
+        }
+
+        fn visit_generic_for_end(&mut self, _: &full_moon::ast::GenericFor) {
+            self.close_loop();
+        }
+
+        fn visit_numeric_for(&mut self, node: &full_moon::ast::NumericFor) {
+            self.open_loop($crate::ast_util::loops::Loop::NumericFor(node));
+        }
+
+        fn visit_numeric_for_end(&mut self, _: &full_moon::ast::NumericFor) {
+            self.close_loop();
+        }
+
+        fn visit_repeat(&mut self, node: &full_moon::ast::Repeat) {
+            self.open_loop($crate::ast_util::loops::Loop::Repeat(node));
+        }
+
+        fn visit_repeat_end(&mut self, _: &full_moon::ast::Repeat) {
+            self.close_loop();
+        }
+
+        fn visit_while(&mut self, node: &full_moon::ast::While) {
+            self.open_loop($crate::ast_util::loops::Loop::While(node));
+        }
+
+        fn visit_while_end(&mut self, _: &full_moon::ast::While) {
+            self.close_loop();
+        }
+    };
+}
+
+pub(crate) use visit_loops;
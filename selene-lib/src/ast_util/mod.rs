@@ -1,16 +1,136 @@
 use std::convert::{TryFrom, TryInto};
 
-use full_moon::node::Node;
+use full_moon::{
+    ast,
+    node::Node,
+    tokenizer::{Symbol, TokenReference, TokenType},
+};
 
+pub mod comments;
+pub mod expression_tree;
+pub mod global_aliases;
+pub mod literal_locals;
+pub mod loops;
 pub mod scopes;
 mod side_effects;
 
 pub use side_effects::HasSideEffects;
 
+// The name of a call like `pcall()`, `task.wait()`, or `RunService.Heartbeat:Wait()`.
+// Returns None if it is not a plain call to a name or a field of one.
+pub fn call_name(call: &ast::FunctionCall) -> Option<String> {
+    let mut name = match call.prefix() {
+        ast::Prefix::Name(name) => name.to_string(),
+        _ => return None,
+    };
+
+    let suffixes = call.iter_suffixes().collect::<Vec<_>>();
+    let (last, fields) = suffixes.split_last()?;
+
+    for field in fields {
+        match field {
+            ast::Suffix::Index(ast::Index::Dot { name: field, .. }) => {
+                name.push('.');
+                name.push_str(&field.to_string());
+            }
+
+            _ => return None,
+        }
+    }
+
+    match last {
+        ast::Suffix::Call(ast::Call::AnonymousCall(_)) => {}
+
+        ast::Suffix::Call(ast::Call::MethodCall(method_call)) => {
+            name.push(':');
+            name.push_str(&method_call.name().to_string());
+        }
+
+        _ => return None,
+    }
+
+    Some(name)
+}
+
+// The names in a call like `io.open(x)` or `workspace:FindFirstChild(x)`, alongside its arguments
+// and whether it is a method call. Returns None if it is not a plain call to a name or a field of one.
+pub fn call_path<'a, 'ast>(
+    call: &'a ast::FunctionCall<'ast>,
+) -> Option<(Vec<String>, &'a ast::FunctionArgs<'ast>, bool)> {
+    let mut names = match call.prefix() {
+        ast::Prefix::Name(name) => vec![name.to_string()],
+        _ => return None,
+    };
+
+    let mut suffixes = call.iter_suffixes().peekable();
+
+    while let Some(suffix) = suffixes.next() {
+        match suffix {
+            ast::Suffix::Index(ast::Index::Dot { name, .. }) => names.push(name.to_string()),
+
+            ast::Suffix::Call(ast::Call::AnonymousCall(arguments)) if suffixes.peek().is_none() => {
+                return Some((names, arguments, false))
+            }
+
+            ast::Suffix::Call(ast::Call::MethodCall(method_call)) if suffixes.peek().is_none() => {
+                names.push(method_call.name().to_string());
+                return Some((names, method_call.args(), true));
+            }
+
+            _ => return None,
+        }
+    }
+
+    None
+}
+
 pub fn is_type_function(name: &str, roblox: bool) -> bool {
     name == "type" || (name == "typeof" && roblox)
 }
 
+// The value of a number token, including hexadecimal ones such as `0xFF`
+pub fn parse_number(text: &str) -> Option<f64> {
+    if let Some(hex) = text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        u64::from_str_radix(hex, 16).ok().map(|value| value as f64)
+    } else {
+        text.parse().ok()
+    }
+}
+
+// The value of `5`, `0xFF`, or `-5`, but not `5 + x`
+pub fn number_literal(expression: &ast::Expression) -> Option<f64> {
+    match expression {
+        ast::Expression::UnaryOperator {
+            unop: ast::UnOp::Minus(_),
+            expression,
+        } => number_literal(expression).map(|number| -number),
+
+        ast::Expression::Value { value, binop: None } => match &**value {
+            ast::Value::Number(token) => parse_number(&token.to_string()),
+            _ => None,
+        },
+
+        _ => None,
+    }
+}
+
+// Whether an expression could be any amount of values, such as `...` or `f()`
+pub fn is_multiple_values(expression: &ast::Expression) -> bool {
+    match expression {
+        ast::Expression::Value { value, binop: None } => match &**value {
+            ast::Value::FunctionCall(_) => true,
+            ast::Value::Symbol(symbol) => {
+                *symbol.token_type()
+                    == (TokenType::Symbol {
+                        symbol: Symbol::Ellipse,
+                    })
+            }
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
 pub fn range<N: Node, P: TryFrom<usize>>(node: N) -> (P, P)
 where
     <P as TryFrom<usize>>::Error: std::fmt::Debug,
@@ -26,3 +146,29 @@ where
             .expect("range end_position couldn't convert"),
     )
 }
+
+// The contents of a string literal, `hello` in `"hello"`
+pub fn string_literal(token: &TokenReference) -> Option<String> {
+    match &*token.token_type() {
+        TokenType::StringLiteral { literal, .. } => Some(literal.to_string()),
+        _ => None,
+    }
+}
+
+// The contents of an expression that is only a string literal, such as `"hello"` or `("hello")`,
+// along with its token
+pub fn expression_string_literal<'a, 'ast>(
+    expression: &'a ast::Expression<'ast>,
+) -> Option<(String, &'a TokenReference<'ast>)> {
+    match expression {
+        ast::Expression::Parentheses { expression, .. } => expression_string_literal(expression),
+
+        ast::Expression::Value { value, binop: None } => match &**value {
+            ast::Value::ParseExpression(expression) => expression_string_literal(expression),
+            ast::Value::String(token) => string_literal(token).map(|literal| (literal, token)),
+            _ => None,
+        },
+
+        _ => None,
+    }
+}
@@ -49,9 +49,9 @@ impl ScopeManager {
 
 #[derive(Debug, Default)]
 pub struct Scope {
-    block: Range,
-    references: Vec<Id<Reference>>,
-    variables: Vec<Id<Variable>>,
+    pub block: Range,
+    pub references: Vec<Id<Reference>>,
+    pub variables: Vec<Id<Variable>>,
 }
 
 #[derive(Debug, Default)]
@@ -1,5 +1,10 @@
 #![recursion_limit = "1000"]
-use std::{collections::HashMap, error::Error, fmt};
+use std::{
+    collections::{BTreeMap, HashMap},
+    error::Error,
+    fmt,
+    path::Path,
+};
 
 use full_moon::ast::Ast;
 use serde::{
@@ -8,11 +13,12 @@ use serde::{
 };
 
 mod ast_util;
+pub mod project;
 pub mod rules;
 pub mod standard_library;
 
 use rules::{Context, Diagnostic, Rule, Severity};
-use standard_library::StandardLibrary;
+use standard_library::{Field, StandardLibrary};
 
 #[derive(Debug)]
 pub struct CheckerError {
@@ -49,6 +55,8 @@ impl Error for CheckerError {}
 #[serde(default)]
 pub struct CheckerConfig<V> {
     pub config: HashMap<String, V>,
+    /// Globals specific to the project, added on top of the standard library
+    pub globals: BTreeMap<String, Field>,
     pub rules: HashMap<String, RuleVariation>,
     pub std: String,
 }
@@ -58,6 +66,7 @@ impl<V> Default for CheckerConfig<V> {
     fn default() -> Self {
         CheckerConfig {
             config: HashMap::new(),
+            globals: BTreeMap::new(),
             rules: HashMap::new(),
             std: "lua51".to_owned(),
         }
@@ -87,7 +96,7 @@ macro_rules! use_rules {
     } => {
         pub struct Checker<V: 'static + DeserializeOwned> {
             config: CheckerConfig<V>,
-            context: Context,
+            standard_library: StandardLibrary,
 
             $(
                 $rule_name: Option<$rule_path>,
@@ -105,8 +114,10 @@ macro_rules! use_rules {
             // TODO: Be more strict about config? Make sure all keys exist
             pub fn new(
                 mut config: CheckerConfig<V>,
-                standard_library: StandardLibrary,
+                mut standard_library: StandardLibrary,
             ) -> Result<Self, CheckerError> where V: for<'de> Deserializer<'de> {
+                standard_library.globals.extend(std::mem::take(&mut config.globals));
+
                 macro_rules! rule_field {
                     ($name:ident, $path:ty) => {{
                         let rule_name = stringify!($name);
@@ -161,19 +172,32 @@ macro_rules! use_rules {
                         )+
                     )+
                     config,
-                    context: Context {
-                        standard_library,
-                    },
+                    standard_library,
                 })
             }
 
             pub fn test_on(&self, ast: &Ast<'static>) -> Vec<CheckerDiagnostic> {
+                self.test_on_context(ast, &Context {
+                    standard_library: &self.standard_library,
+                    path: None,
+                })
+            }
+
+            /// Like `test_on`, but for a file at a known path, which some rules check
+            pub fn test_on_path(&self, ast: &Ast<'static>, path: &Path) -> Vec<CheckerDiagnostic> {
+                self.test_on_context(ast, &Context {
+                    standard_library: &self.standard_library,
+                    path: Some(path),
+                })
+            }
+
+            fn test_on_context(&self, ast: &Ast<'static>, context: &Context) -> Vec<CheckerDiagnostic> {
                 let mut diagnostics = Vec::new();
 
                 macro_rules! check_rule {
                     ($name:ident) => {
                         if let Some(rule) = &self.$name {
-                            diagnostics.extend(&mut rule.pass(ast, &self.context).into_iter().map(|diagnostic| {
+                            diagnostics.extend(&mut rule.pass(ast, context).into_iter().map(|diagnostic| {
                                 CheckerDiagnostic {
                                     diagnostic,
                                     severity: match self.config.rules.get(stringify!($name)) {
@@ -214,25 +238,135 @@ pub struct CheckerDiagnostic {
 
 use_rules! {
     almost_swapped: rules::almost_swapped::AlmostSwappedLint,
+    and_or_ternary: rules::and_or_ternary::AndOrTernaryLint,
+    assert_no_message: rules::assert_no_message::AssertNoMessageLint,
+    boolean_comparison: rules::boolean_comparison::BooleanComparisonLint,
+    consecutive_string_concat: rules::consecutive_string_concat::ConsecutiveStringConcatLint,
+    dead_assignment: rules::dead_assignment::DeadAssignmentLint,
+    debug_library: rules::debug_library::DebugLibraryLint,
+    deprecated_global: rules::deprecated_global::DeprecatedGlobalLint,
     divide_by_zero: rules::divide_by_zero::DivideByZeroLint,
+    duplicate_field_assignment: rules::duplicate_field_assignment::DuplicateFieldAssignmentLint,
+    duplicate_local: rules::duplicate_local::DuplicateLocalLint,
+    duplicate_parameter: rules::duplicate_parameter::DuplicateParameterLint,
+    eager_assert_message: rules::eager_assert_message::EagerAssertMessageLint,
     empty_if: rules::empty_if::EmptyIfLint,
+    function_in_loop: rules::function_in_loop::FunctionInLoopLint,
+    global_in_hot_path: rules::global_in_hot_path::GlobalInHotPathLint,
     global_usage: rules::global_usage::GlobalLint,
+    if_return_bool: rules::if_return_bool::IfReturnBoolLint,
     if_same_then_else: rules::if_same_then_else::IfSameThenElseLint,
     ifs_same_cond: rules::ifs_same_cond::IfsSameCondLint,
+    implicit_arg_table: rules::implicit_arg_table::ImplicitArgTableLint,
+    implicit_nil_return: rules::implicit_nil_return::ImplicitNilReturnLint,
+    incompatible_comparison: rules::incompatible_comparison::IncompatibleComparisonLint,
+    incompatible_equality: rules::incompatible_equality::IncompatibleEqualityLint,
+    inconsistent_self: rules::inconsistent_self::InconsistentSelfLint,
     incorrect_standard_library_use: rules::standard_library::StandardLibraryLint,
+    infinite_loop: rules::infinite_loop::InfiniteLoopLint,
+    invalid_concatenation: rules::invalid_concatenation::InvalidConcatenationLint,
+    invalid_gsub_replacement: rules::invalid_gsub_replacement::InvalidGsubReplacementLint,
+    invalid_length_operand: rules::invalid_length_operand::InvalidLengthOperandLint,
+    invalid_os_date_format: rules::invalid_os_date_format::InvalidOsDateFormatLint,
+    invalid_random_range: rules::invalid_random_range::InvalidRandomRangeLint,
+    io_open_unclosed: rules::io_open_unclosed::IoOpenUnclosedLint,
+    io_popen_security: rules::io_popen_security::IoPopenSecurityLint,
+    len_in_loop_condition: rules::len_in_loop_condition::LenInLoopConditionLint,
+    line_length: rules::line_length::LineLengthLint,
+    load_security: rules::load_security::LoadSecurityLint,
+    magic_number: rules::magic_number::MagicNumberLint,
+    max_file_lines: rules::max_file_lines::MaxFileLinesLint,
+    max_nesting_depth: rules::max_nesting_depth::MaxNestingDepthLint,
+    max_parameters: rules::max_parameters::MaxParametersLint,
+    metamethod_typo: rules::metamethod_typo::MetamethodTypoLint,
+    method_call_style: rules::method_call_style::MethodCallStyleLint,
+    missing_error_handling: rules::missing_error_handling::MissingErrorHandlingLint,
+    mixed_indentation: rules::mixed_indentation::MixedIndentationLint,
+    multiple_returns_mismatch: rules::multiple_returns_mismatch::MultipleReturnsMismatchLint,
     multiple_statements: rules::multiple_statements::MultipleStatementsLint,
+    naming_convention: rules::naming_convention::NamingConventionLint,
+    nan_comparison: rules::nan_comparison::NanComparisonLint,
+    not_comparison_precedence: rules::not_comparison_precedence::NotComparisonPrecedenceLint,
+    not_equals_simplification: rules::not_equals_simplification::NotEqualsSimplificationLint,
+    operator_precedence_ambiguity: rules::operator_precedence_ambiguity::OperatorPrecedenceAmbiguityLint,
+    os_execute_security: rules::os_execute_security::OsExecuteSecurityLint,
+    package_path_modification: rules::package_path_modification::PackagePathModificationLint,
     parenthese_conditions: rules::parenthese_conditions::ParentheseConditionsLint,
+    pcall_non_function: rules::pcall_non_function::PcallNonFunctionLint,
+    pcall_return_ignored: rules::pcall_return_ignored::PcallReturnIgnoredLint,
+    print_in_production: rules::print_in_production::PrintInProductionLint,
+    quote_style: rules::quote_style::QuoteStyleLint,
+    redundant_do_block: rules::redundant_do_block::RedundantDoBlockLint,
+    redundant_return: rules::redundant_return::RedundantReturnLint,
+    repeat_until_true: rules::repeat_until_true::RepeatUntilTrueLint,
+    require_non_literal: rules::require_non_literal::RequireNonLiteralLint,
+    require_return_ignored: rules::require_return_ignored::RequireReturnIgnoredLint,
+    restricted_globals: rules::restricted_globals::RestrictedGlobalsLint,
+    self_assignment: rules::self_assignment::SelfAssignmentLint,
+    shadowed_self: rules::shadowed_self::ShadowedSelfLint,
     shadowing: rules::shadowing::ShadowingLint,
+    string_concat_in_loop: rules::string_concat_in_loop::StringConcatInLoopLint,
+    string_format_arg_mismatch: rules::string_format_arg_mismatch::StringFormatArgMismatchLint,
     suspicious_reverse_loop: rules::suspicious_reverse_loop::SuspiciousReverseLoopLint,
+    table_creation_in_loop: rules::table_creation_in_loop::TableCreationInLoopLint,
+    todo_comment: rules::todo_comment::TodoCommentLint,
+    trailing_whitespace: rules::trailing_whitespace::TrailingWhitespaceLint,
+    type_check_completeness: rules::type_check_completeness::TypeCheckCompletenessLint,
     type_check_inside_call: rules::type_check_inside_call::TypeCheckInsideCallLint,
     unbalanced_assignments: rules::unbalanced_assignments::UnbalancedAssignmentsLint,
+    uncallable_literal: rules::uncallable_literal::UncallableLiteralLint,
+    uncallable_table: rules::uncallable_table::UncallableTableLint,
     undefined_variable: rules::undefined_variable::UndefinedVariableLint,
+    unindexable_literal: rules::unindexable_literal::UnindexableLiteralLint,
+    uninitialized_variable: rules::uninitialized_variable::UninitializedVariableLint,
+    unknown_global_write: rules::unknown_global_write::UnknownGlobalWriteLint,
+    unnecessary_rawaccess: rules::unnecessary_rawaccess::UnnecessaryRawaccessLint,
+    unnecessary_semicolon: rules::unnecessary_semicolon::UnnecessarySemicolonLint,
     unscoped_variables: rules::unscoped_variables::UnscopedVariablesLint,
     unused_variable: rules::unused_variable::UnusedVariableLint,
+    use_before_declaration: rules::use_before_declaration::UseBeforeDeclarationLint,
+    vararg_outside_function: rules::vararg_outside_function::VarargOutsideFunctionLint,
+    write_only_variable: rules::write_only_variable::WriteOnlyVariableLint,
 
     #[cfg(feature = "roblox")]
     {
         roblox_incorrect_color3_new_bounds: rules::roblox_incorrect_color3_new_bounds::Color3BoundsLint,
         roblox_incorrect_roact_usage: rules::roblox_incorrect_roact_usage::IncorrectRoactUsageLint,
+        roblox_instance_new_parent: rules::roblox_instance_new_parent::InstanceNewParentLint,
+        roblox_suspicious_udim2_new: rules::roblox_suspicious_udim2_new::SuspiciousUDim2NewLint,
     },
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use full_moon::ast::owned::Owned;
+
+    #[test]
+    fn test_project_globals() {
+        let config: CheckerConfig<toml::value::Value> = toml::from_str(
+            r#"
+            [globals.GameState]
+            any = true
+
+            [globals.VERSION]
+            property = true
+            "#,
+        )
+        .unwrap();
+
+        let checker = Checker::new(config, StandardLibrary::from_name("lua51").unwrap()).unwrap();
+        let ast = full_moon::parse("print(GameState.round, VERSION, Unknown)")
+            .unwrap()
+            .owned();
+
+        let undefined = checker
+            .test_on(&ast)
+            .into_iter()
+            .filter(|diagnostic| diagnostic.diagnostic.code == "undefined_variable")
+            .map(|diagnostic| diagnostic.diagnostic.message)
+            .collect::<Vec<_>>();
+
+        assert_eq!(undefined, vec!["`Unknown` is not defined"]);
+    }
+}
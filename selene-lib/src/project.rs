@@ -0,0 +1,341 @@
+//! Analysis that needs more than one file at a time, such as following requires.
+use crate::ast_util::{range, string_literal};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    path::{Path, PathBuf},
+};
+
+use full_moon::{
+    ast::{self, Ast},
+    visitors::Visitor,
+};
+
+/// A `require` of a string literal, such as `require("game.inventory")`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Require {
+    pub module: String,
+    pub range: (usize, usize),
+}
+
+/// A chain of requires that leads back to where it started.
+/// Each file is given alongside the require it uses to get to the next file.
+/// The last require leads back to the first file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RequireCycle {
+    pub requires: Vec<(PathBuf, Require)>,
+}
+
+/// The files that every file requires, used to find circular requires.
+#[derive(Debug, Default)]
+pub struct RequireGraph {
+    files: BTreeMap<PathBuf, Vec<Require>>,
+}
+
+impl RequireGraph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_file(&mut self, path: &Path, ast: &Ast) {
+        let mut visitor = RequireVisitor {
+            requires: Vec::new(),
+        };
+
+        visitor.visit_ast(ast);
+        self.files.insert(path.to_owned(), visitor.requires);
+    }
+
+    // The files every module name can refer to. `game.inventory` can refer to both
+    // `game/inventory.lua` and `game/inventory/init.lua`, wherever they are in the project, so
+    // `src/game/inventory.lua` is indexed as `src/game/inventory`, `game/inventory`, and `inventory`.
+    fn module_index(&self) -> HashMap<String, Vec<usize>> {
+        let mut index: HashMap<String, Vec<usize>> = HashMap::new();
+
+        for (file, path) in self.files.keys().enumerate() {
+            let without_extension = path.with_extension("");
+
+            let name =
+                if without_extension.file_name().and_then(|name| name.to_str()) == Some("init") {
+                    without_extension
+                        .parent()
+                        .unwrap_or(&without_extension)
+                        .to_owned()
+                } else {
+                    without_extension
+                };
+
+            let name = name.to_string_lossy().replace('\\', "/");
+            let components = name.split('/').collect::<Vec<_>>();
+
+            for start in 0..components.len() {
+                index
+                    .entry(components[start..].join("/"))
+                    .or_default()
+                    .push(file);
+            }
+        }
+
+        index
+    }
+
+    // The files each file requires, by their index in `files`, along with the first require of
+    // each. Modules that could refer to more than one file are skipped rather than guessed.
+    fn edges(&self) -> Vec<Vec<(usize, &Require)>> {
+        let index = self.module_index();
+
+        self.files
+            .values()
+            .map(|requires| {
+                let mut edges: Vec<(usize, &Require)> = Vec::new();
+
+                for require in requires {
+                    if let Some([required]) = index
+                        .get(&require.module.replace('.', "/"))
+                        .map(Vec::as_slice)
+                    {
+                        if edges.iter().all(|(file, _)| file != required) {
+                            edges.push((*required, require));
+                        }
+                    }
+                }
+
+                edges
+            })
+            .collect()
+    }
+
+    /// Every cycle of requires, each starting from its first file in path order.
+    pub fn cycles(&self) -> Vec<RequireCycle> {
+        let mut search = CycleSearch {
+            paths: self.files.keys().map(PathBuf::as_path).collect(),
+            edges: self.edges(),
+            start: 0,
+            stack: Vec::new(),
+            blocked: vec![false; self.files.len()],
+            blocked_by: vec![HashSet::new(); self.files.len()],
+            cycles: Vec::new(),
+        };
+
+        for start in 0..self.files.len() {
+            search.start = start;
+            search
+                .blocked
+                .iter_mut()
+                .for_each(|blocked| *blocked = false);
+            search.blocked_by.iter_mut().for_each(HashSet::clear);
+            search.find_cycles(start);
+        }
+
+        search.cycles
+    }
+}
+
+// Johnson's algorithm for finding every elementary cycle. Each cycle is found from its lowest
+// file, only going through files after it, and files that can't lead back to it are blocked
+// until something they lead to is unblocked.
+struct CycleSearch<'a> {
+    paths: Vec<&'a Path>,
+    edges: Vec<Vec<(usize, &'a Require)>>,
+    start: usize,
+    // The chain of requires that led to the current file
+    stack: Vec<(usize, &'a Require)>,
+    blocked: Vec<bool>,
+    // The files to unblock when a file is unblocked
+    blocked_by: Vec<HashSet<usize>>,
+    cycles: Vec<RequireCycle>,
+}
+
+impl CycleSearch<'_> {
+    // Whether a cycle back to `start` was found through `file`
+    fn find_cycles(&mut self, file: usize) -> bool {
+        let mut found = false;
+        self.blocked[file] = true;
+
+        for (required, require) in self.edges[file].clone() {
+            if required < self.start {
+                continue;
+            }
+
+            self.stack.push((file, require));
+
+            if required == self.start {
+                self.cycles.push(RequireCycle {
+                    requires: self
+                        .stack
+                        .iter()
+                        .map(|(file, require)| {
+                            (self.paths[*file].to_path_buf(), (*require).clone())
+                        })
+                        .collect(),
+                });
+
+                found = true;
+            } else if !self.blocked[required] && self.find_cycles(required) {
+                found = true;
+            }
+
+            self.stack.pop();
+        }
+
+        if found {
+            self.unblock(file);
+        } else {
+            for (required, _) in &self.edges[file] {
+                if *required >= self.start {
+                    self.blocked_by[*required].insert(file);
+                }
+            }
+        }
+
+        found
+    }
+
+    fn unblock(&mut self, file: usize) {
+        self.blocked[file] = false;
+
+        for blocked in std::mem::take(&mut self.blocked_by[file]) {
+            if self.blocked[blocked] {
+                self.unblock(blocked);
+            }
+        }
+    }
+}
+
+struct RequireVisitor {
+    requires: Vec<Require>,
+}
+
+impl Visitor<'_> for RequireVisitor {
+    fn visit_function_call(&mut self, call: &ast::FunctionCall) {
+        if_chain::if_chain! {
+            if let ast::Prefix::Name(name) = call.prefix();
+            if name.to_string() == "require";
+            let mut suffixes = call.iter_suffixes();
+            if let Some(ast::Suffix::Call(ast::Call::AnonymousCall(arguments))) = suffixes.next();
+            if suffixes.next().is_none();
+            then {
+                let module = match arguments {
+                    ast::FunctionArgs::Parentheses { arguments, .. } if arguments.len() == 1 => {
+                        match arguments.iter().next() {
+                            Some(ast::Expression::Value { value, binop: None }) => match &**value {
+                                ast::Value::String(token) => string_literal(token),
+                                _ => None,
+                            },
+                            _ => None,
+                        }
+                    }
+
+                    ast::FunctionArgs::String(token) => string_literal(token),
+                    _ => None,
+                };
+
+                if let Some(module) = module {
+                    self.requires.push(Require {
+                        module,
+                        range: range(call),
+                    });
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph(files: &[(&str, &str)]) -> RequireGraph {
+        let mut graph = RequireGraph::new();
+
+        for (path, code) in files {
+            graph.add_file(Path::new(path), &full_moon::parse(code).unwrap());
+        }
+
+        graph
+    }
+
+    fn cycle_files(cycle: &RequireCycle) -> Vec<&str> {
+        cycle
+            .requires
+            .iter()
+            .map(|(path, _)| path.to_str().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_no_cycles() {
+        let graph = graph(&[
+            ("src/a.lua", "local b = require('b')"),
+            ("src/b.lua", "local c = require('c')"),
+            ("src/c.lua", "return {}"),
+        ]);
+
+        assert!(graph.cycles().is_empty());
+    }
+
+    #[test]
+    fn test_cycle() {
+        let graph = graph(&[
+            ("src/a.lua", "local b = require('b')"),
+            ("src/b.lua", "local c = require('game.c')"),
+            ("src/game/c/init.lua", "local a = require 'a'"),
+        ]);
+
+        let cycles = graph.cycles();
+        assert_eq!(cycles.len(), 1);
+        assert_eq!(
+            cycle_files(&cycles[0]),
+            vec!["src/a.lua", "src/b.lua", "src/game/c/init.lua"]
+        );
+        assert_eq!(cycles[0].requires[1].1.module, "game.c");
+    }
+
+    #[test]
+    fn test_self_require() {
+        let graph = graph(&[("a.lua", "require('a')"), ("b.lua", "require(name)")]);
+
+        let cycles = graph.cycles();
+        assert_eq!(cycles.len(), 1);
+        assert_eq!(cycle_files(&cycles[0]), vec!["a.lua"]);
+    }
+
+    #[test]
+    fn test_separate_cycles() {
+        let graph = graph(&[
+            ("a.lua", "require('b')"),
+            ("b.lua", "require('a') require('c')"),
+            ("c.lua", "require('d')"),
+            ("d.lua", "require('c')"),
+        ]);
+
+        let cycles = graph.cycles();
+        assert_eq!(cycles.len(), 2);
+        assert_eq!(cycle_files(&cycles[0]), vec!["a.lua", "b.lua"]);
+        assert_eq!(cycle_files(&cycles[1]), vec!["c.lua", "d.lua"]);
+    }
+
+    #[test]
+    fn test_overlapping_cycles() {
+        let graph = graph(&[
+            ("a.lua", "require('b') require('c')"),
+            ("b.lua", "require('a')"),
+            ("c.lua", "require('b')"),
+        ]);
+
+        let cycles = graph.cycles();
+        assert_eq!(cycles.len(), 2);
+        assert_eq!(cycle_files(&cycles[0]), vec!["a.lua", "b.lua"]);
+        assert_eq!(cycle_files(&cycles[1]), vec!["a.lua", "c.lua", "b.lua"]);
+    }
+
+    #[test]
+    fn test_ambiguous_module() {
+        let graph = graph(&[
+            ("src/main.lua", "require('util')"),
+            ("src/a/util.lua", "require('main')"),
+            ("src/b/util.lua", "require('main')"),
+        ]);
+
+        assert!(graph.cycles().is_empty());
+    }
+}
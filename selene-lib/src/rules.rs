@@ -1,5 +1,5 @@
 use crate::standard_library::StandardLibrary;
-use std::convert::TryInto;
+use std::{convert::TryInto, path::Path};
 
 use codespan_reporting::diagnostic::{
     Diagnostic as CodespanDiagnostic, Label as CodespanLabel, Severity as CodespanSeverity,
@@ -8,21 +8,95 @@ use full_moon::node::Node;
 use serde::de::DeserializeOwned;
 
 pub mod almost_swapped;
+pub mod and_or_ternary;
+pub mod assert_no_message;
+pub mod boolean_comparison;
+pub mod consecutive_string_concat;
+pub mod dead_assignment;
+pub mod debug_library;
+pub mod deprecated_global;
 pub mod divide_by_zero;
+pub mod duplicate_field_assignment;
+pub mod duplicate_local;
+pub mod duplicate_parameter;
+pub mod eager_assert_message;
 pub mod empty_if;
+pub mod function_in_loop;
+pub mod global_in_hot_path;
 pub mod global_usage;
+pub mod if_return_bool;
 pub mod if_same_then_else;
 pub mod ifs_same_cond;
+pub mod implicit_arg_table;
+pub mod implicit_nil_return;
+pub mod incompatible_comparison;
+pub mod incompatible_equality;
+pub mod inconsistent_self;
+pub mod infinite_loop;
+pub mod invalid_concatenation;
+pub mod invalid_gsub_replacement;
+pub mod invalid_length_operand;
+pub mod invalid_os_date_format;
+pub mod invalid_random_range;
+pub mod io_open_unclosed;
+pub mod io_popen_security;
+pub mod len_in_loop_condition;
+pub mod line_length;
+pub mod load_security;
+pub mod magic_number;
+pub mod max_file_lines;
+pub mod max_nesting_depth;
+pub mod max_parameters;
+pub mod metamethod_typo;
+pub mod method_call_style;
+pub mod missing_error_handling;
+pub mod mixed_indentation;
+pub mod multiple_returns_mismatch;
 pub mod multiple_statements;
+pub mod naming_convention;
+pub mod nan_comparison;
+pub mod not_comparison_precedence;
+pub mod not_equals_simplification;
+pub mod operator_precedence_ambiguity;
+pub mod os_execute_security;
+pub mod package_path_modification;
 pub mod parenthese_conditions;
+pub mod pcall_non_function;
+pub mod pcall_return_ignored;
+pub mod print_in_production;
+pub mod quote_style;
+pub mod redundant_do_block;
+pub mod redundant_return;
+pub mod repeat_until_true;
+pub mod require_non_literal;
+pub mod require_return_ignored;
+pub mod restricted_globals;
+pub mod self_assignment;
+pub mod shadowed_self;
 pub mod shadowing;
 pub mod standard_library;
+pub mod string_concat_in_loop;
+pub mod string_format_arg_mismatch;
 pub mod suspicious_reverse_loop;
+pub mod table_creation_in_loop;
+pub mod todo_comment;
+pub mod trailing_whitespace;
+pub mod type_check_completeness;
 pub mod type_check_inside_call;
 pub mod unbalanced_assignments;
+pub mod uncallable_literal;
+pub mod uncallable_table;
 pub mod undefined_variable;
+pub mod unindexable_literal;
+pub mod uninitialized_variable;
+pub mod unknown_global_write;
+pub mod unnecessary_rawaccess;
+pub mod unnecessary_semicolon;
 pub mod unscoped_variables;
 pub mod unused_variable;
+pub mod use_before_declaration;
+pub mod vararg_outside_function;
+pub mod write_only_variable;
 
 #[cfg(feature = "roblox")]
 pub mod roblox_incorrect_color3_new_bounds;
@@ -30,6 +104,12 @@ pub mod roblox_incorrect_color3_new_bounds;
 #[cfg(feature = "roblox")]
 pub mod roblox_incorrect_roact_usage;
 
+#[cfg(feature = "roblox")]
+pub mod roblox_instance_new_parent;
+
+#[cfg(feature = "roblox")]
+pub mod roblox_suspicious_udim2_new;
+
 #[cfg(test)]
 mod test_util;
 
@@ -180,12 +260,27 @@ impl Label {
     }
 }
 
-#[derive(Clone, Debug)]
-pub struct Context {
-    pub standard_library: StandardLibrary,
+#[derive(Clone, Copy, Debug)]
+pub struct Context<'a> {
+    pub standard_library: &'a StandardLibrary,
+    /// The path of the file being checked, if it is known
+    pub path: Option<&'a Path>,
 }
 
-impl Context {
+impl Context<'_> {
+    /// Whether the file being checked is a test, such as `module.spec.lua` or `module_test.lua`
+    pub fn is_test_file(&self) -> bool {
+        let stem = match self.path.and_then(Path::file_stem) {
+            Some(stem) => stem.to_string_lossy(),
+            None => return false,
+        };
+
+        stem.starts_with("test_")
+            || [".spec", ".test", "_spec", "_test"]
+                .iter()
+                .any(|suffix| stem.ends_with(suffix))
+    }
+
     #[cfg(feature = "roblox")]
     pub fn is_roblox(&self) -> bool {
         if let Some(ref meta) = self.standard_library.meta {
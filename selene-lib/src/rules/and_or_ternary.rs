@@ -0,0 +1,190 @@
+use super::*;
+use crate::ast_util::{
+    expression_tree::{chained_expressions, ExpressionTree, StaticType},
+    range,
+};
+use std::{collections::HashSet, convert::Infallible};
+
+use full_moon::{
+    ast::{self, Ast},
+    tokenizer::{Symbol, TokenType},
+    visitors::Visitor,
+};
+use serde::Deserialize;
+
+#[derive(Clone, Copy, Default, Deserialize)]
+#[serde(default)]
+pub struct AndOrTernaryConfig {
+    strict: bool,
+}
+
+pub struct AndOrTernaryLint {
+    config: AndOrTernaryConfig,
+}
+
+impl Rule for AndOrTernaryLint {
+    type Config = AndOrTernaryConfig;
+    type Error = Infallible;
+
+    fn new(config: Self::Config) -> Result<Self, Self::Error> {
+        Ok(AndOrTernaryLint { config })
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let source = full_moon::print(ast);
+
+        let mut visitor = AndOrTernaryVisitor {
+            chained: HashSet::new(),
+            source: &source,
+            ternaries: Vec::new(),
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .ternaries
+            .into_iter()
+            .filter_map(|ternary| match ternary.middle {
+                Middle::Falsy => Some(Diagnostic::new_complete(
+                    "and_or_ternary",
+                    format!(
+                        "the middle of this `and`/`or` is `{}`, so it will always evaluate to what is after `or`",
+                        ternary.middle_text
+                    ),
+                    Label::new(ternary.range),
+                    vec!["help: use an if statement instead".to_owned()],
+                    Vec::new(),
+                )),
+
+                Middle::Unknown if self.config.strict => Some(Diagnostic::new_complete(
+                    "and_or_ternary",
+                    format!(
+                        "this `and`/`or` will evaluate to what is after `or` if `{}` is `nil` or `false`",
+                        ternary.middle_text
+                    ),
+                    Label::new(ternary.range),
+                    vec!["help: use an if statement if that isn't intended".to_owned()],
+                    vec![Label::new_with_message(
+                        (ternary.middle_range.0 as u32, ternary.middle_range.1 as u32),
+                        "this could be `nil` or `false`".to_owned(),
+                    )],
+                )),
+
+                Middle::Unknown | Middle::Truthy => None,
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Correctness
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Middle {
+    // `nil` or `false`, so the `or` side is always used
+    Falsy,
+    // A literal that can't be `nil` or `false`, such as `"yes"`, so the pattern works as intended
+    Truthy,
+    Unknown,
+}
+
+fn middle(tree: &ExpressionTree) -> Middle {
+    match tree.literal_type() {
+        Some(StaticType::Nil) => Middle::Falsy,
+
+        Some(StaticType::Boolean) => match tree.value() {
+            Some(ast::Value::Symbol(symbol))
+                if *symbol.token_type()
+                    == (TokenType::Symbol {
+                        symbol: Symbol::False,
+                    }) =>
+            {
+                Middle::Falsy
+            }
+
+            _ => Middle::Truthy,
+        },
+
+        Some(_) => Middle::Truthy,
+        None => Middle::Unknown,
+    }
+}
+
+struct Ternary {
+    middle: Middle,
+    middle_range: (usize, usize),
+    middle_text: String,
+    range: (usize, usize),
+}
+
+struct AndOrTernaryVisitor<'a> {
+    chained: HashSet<(usize, usize)>,
+    source: &'a str,
+    ternaries: Vec<Ternary>,
+}
+
+impl Visitor<'_> for AndOrTernaryVisitor<'_> {
+    fn visit_expression(&mut self, expression: &ast::Expression) {
+        if self.chained.contains(&range(expression)) {
+            return;
+        }
+
+        self.chained.extend(chained_expressions(expression));
+
+        let source = self.source;
+        let mut ternaries = Vec::new();
+
+        ExpressionTree::new(expression).for_each_binary(&mut |lhs, op, rhs| {
+            if !matches!(op, ast::BinOp::Or(_)) {
+                return;
+            }
+
+            // `a and b or c`, where `b` is the rhs of the `and`
+            if let ExpressionTree::Binary {
+                op: ast::BinOp::And(_),
+                rhs: and_rhs,
+                ..
+            } = lhs
+            {
+                let middle_range = and_rhs.range();
+
+                ternaries.push(Ternary {
+                    middle: middle(and_rhs),
+                    middle_range,
+                    middle_text: source[middle_range.0..middle_range.1].trim().to_owned(),
+                    range: (lhs.range().0, rhs.range().1),
+                });
+            }
+        });
+
+        self.ternaries.extend(ternaries);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_and_or_ternary() {
+        test_lint(
+            AndOrTernaryLint::new(AndOrTernaryConfig::default()).unwrap(),
+            "and_or_ternary",
+            "and_or_ternary",
+        );
+    }
+
+    #[test]
+    fn test_strict() {
+        test_lint(
+            AndOrTernaryLint::new(AndOrTernaryConfig { strict: true }).unwrap(),
+            "and_or_ternary",
+            "strict",
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use super::*;
+use crate::ast_util::{is_multiple_values, range, scopes::ScopeManager};
+use std::convert::Infallible;
+
+use full_moon::{
+    ast::{self, Ast},
+    node::Node,
+    visitors::Visitor,
+};
+
+pub struct AssertNoMessageLint;
+
+impl Rule for AssertNoMessageLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(AssertNoMessageLint)
+    }
+
+    fn pass(&self, ast: &Ast, context: &Context) -> Vec<Diagnostic> {
+        // Test frameworks give their own messages for failed assertions
+        if context.is_test_file() {
+            return Vec::new();
+        }
+
+        let mut visitor = AssertNoMessageVisitor {
+            positions: Vec::new(),
+            scope_manager: ScopeManager::new(ast),
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .positions
+            .iter()
+            .map(|position| {
+                Diagnostic::new_complete(
+                    "assert_no_message",
+                    "`assert` is called without an error message".to_owned(),
+                    Label::new(*position),
+                    vec![
+                        "help: add a message explaining what went wrong, such as `assert(value, \"value must not be nil\")`".to_owned(),
+                    ],
+                    Vec::new(),
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Style
+    }
+}
+
+struct AssertNoMessageVisitor {
+    positions: Vec<(usize, usize)>,
+    scope_manager: ScopeManager,
+}
+
+impl Visitor<'_> for AssertNoMessageVisitor {
+    fn visit_function_call(&mut self, call: &ast::FunctionCall) {
+        if_chain::if_chain! {
+            if let ast::Prefix::Name(name) = call.prefix();
+            if name.to_string() == "assert";
+            if let Some(ast::Suffix::Call(ast::Call::AnonymousCall(
+                ast::FunctionArgs::Parentheses { arguments, .. }
+            ))) = call.iter_suffixes().next();
+            if arguments.len() == 1;
+            // `assert(io.open(path))` uses the error message returned by `io.open`
+            if !is_multiple_values(arguments.iter().next().unwrap());
+            then {
+                if let Some(reference) = self
+                    .scope_manager
+                    .reference_at_byte(call.start_position().unwrap().bytes())
+                {
+                    if reference.resolved.is_some() {
+                        return;
+                    }
+                }
+
+                self.positions.push(range(call));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_assert_no_message() {
+        test_lint(
+            AssertNoMessageLint::new(()).unwrap(),
+            "assert_no_message",
+            "assert_no_message",
+        );
+    }
+
+    #[test]
+    fn test_test_file() {
+        test_lint(
+            AssertNoMessageLint::new(()).unwrap(),
+            "assert_no_message",
+            "assert_no_message_spec",
+        );
+    }
+}
@@ -0,0 +1,168 @@
+use super::*;
+use crate::ast_util::{
+    expression_tree::{chained_expressions, is_equality, operator_text, ExpressionTree},
+    range,
+};
+use std::{collections::HashSet, convert::Infallible};
+
+use full_moon::{
+    ast::{self, Ast},
+    tokenizer::{Symbol, TokenType},
+    visitors::Visitor,
+};
+
+pub struct BooleanComparisonLint;
+
+impl Rule for BooleanComparisonLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(BooleanComparisonLint)
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let source = full_moon::print(ast);
+
+        let mut visitor = BooleanComparisonVisitor {
+            chained: HashSet::new(),
+            comparisons: Vec::new(),
+            source: &source,
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .comparisons
+            .into_iter()
+            .map(|comparison| {
+                Diagnostic::new_complete(
+                    "boolean_comparison",
+                    format!(
+                        "comparing to `{}` with `{}` is unnecessary",
+                        comparison.literal, comparison.operator
+                    ),
+                    Label::new(comparison.range),
+                    vec![
+                        format!("help: try `{}`", comparison.suggestion),
+                        "note: this is only the same when the other side is always a boolean"
+                            .to_owned(),
+                    ],
+                    Vec::new(),
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Style
+    }
+}
+
+fn boolean_literal(tree: &ExpressionTree) -> Option<bool> {
+    if let Some(ast::Value::Symbol(symbol)) = tree.value() {
+        match *symbol.token_type() {
+            TokenType::Symbol {
+                symbol: Symbol::True,
+            } => Some(true),
+            TokenType::Symbol {
+                symbol: Symbol::False,
+            } => Some(false),
+            _ => None,
+        }
+    } else {
+        None
+    }
+}
+
+struct Comparison {
+    literal: bool,
+    operator: &'static str,
+    range: (usize, usize),
+    suggestion: String,
+}
+
+struct BooleanComparisonVisitor<'a> {
+    chained: HashSet<(usize, usize)>,
+    comparisons: Vec<Comparison>,
+    source: &'a str,
+}
+
+impl BooleanComparisonVisitor<'_> {
+    fn text(&self, tree: &ExpressionTree) -> &str {
+        let (start, end) = tree.range();
+        self.source[start..end].trim()
+    }
+
+    // `x`, or `not x` when `negated`, without any unnecessary parentheses
+    fn simplify(&self, tree: &ExpressionTree, negated: bool) -> String {
+        if !negated {
+            return self.text(tree).to_owned();
+        }
+
+        match tree {
+            ExpressionTree::Unary {
+                op: ast::UnOp::Not(_),
+                operand,
+            } => self.text(operand).to_owned(),
+
+            ExpressionTree::Binary { .. } => format!("not ({})", self.text(tree)),
+
+            _ => format!("not {}", self.text(tree)),
+        }
+    }
+}
+
+impl Visitor<'_> for BooleanComparisonVisitor<'_> {
+    fn visit_expression(&mut self, expression: &ast::Expression) {
+        if self.chained.contains(&range(expression)) {
+            return;
+        }
+
+        self.chained.extend(chained_expressions(expression));
+
+        let mut comparisons = Vec::new();
+
+        ExpressionTree::new(expression).for_each_binary(&mut |lhs, op, rhs| {
+            if !is_equality(op) {
+                return;
+            }
+
+            let (other, literal) = match (boolean_literal(lhs), boolean_literal(rhs)) {
+                // `true == false` is better left to other lints
+                (Some(_), Some(_)) | (None, None) => return,
+                (Some(literal), None) => (rhs, literal),
+                (None, Some(literal)) => (lhs, literal),
+            };
+
+            let equal = matches!(op, ast::BinOp::TwoEqual(_));
+
+            comparisons.push(Comparison {
+                literal,
+                operator: operator_text(op),
+                range: (lhs.range().0, rhs.range().1),
+                suggestion: self.simplify(other, literal != equal),
+            });
+        });
+
+        self.comparisons.extend(comparisons);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_boolean_comparison() {
+        test_lint(
+            BooleanComparisonLint::new(()).unwrap(),
+            "boolean_comparison",
+            "boolean_comparison",
+        );
+    }
+}
@@ -0,0 +1,188 @@
+use super::*;
+use crate::ast_util::{
+    expression_tree::{chained_expressions, ExpressionTree, StaticType},
+    range,
+};
+use std::{collections::HashSet, convert::Infallible};
+
+use full_moon::{
+    ast::{self, Ast},
+    visitors::Visitor,
+};
+use serde::Deserialize;
+
+#[derive(Clone, Copy, Deserialize)]
+#[serde(default)]
+pub struct ConsecutiveStringConcatConfig {
+    min_operands: usize,
+}
+
+impl Default for ConsecutiveStringConcatConfig {
+    fn default() -> Self {
+        Self { min_operands: 5 }
+    }
+}
+
+pub struct ConsecutiveStringConcatLint {
+    config: ConsecutiveStringConcatConfig,
+}
+
+impl Rule for ConsecutiveStringConcatLint {
+    type Config = ConsecutiveStringConcatConfig;
+    type Error = Infallible;
+
+    fn new(config: Self::Config) -> Result<Self, Self::Error> {
+        Ok(ConsecutiveStringConcatLint { config })
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let mut visitor = ConsecutiveStringConcatVisitor {
+            chained: HashSet::new(),
+            concatenations: Vec::new(),
+            min_operands: self.config.min_operands,
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .concatenations
+            .iter()
+            .map(|concatenation| {
+                Diagnostic::new_complete(
+                    "consecutive_string_concat",
+                    format!(
+                        "{} values are concatenated one after another",
+                        concatenation.operands
+                    ),
+                    Label::new(concatenation.range),
+                    vec![
+                        "help: put the values in a table, and use `table.concat` instead, such as `table.concat({ a, b, c })`".to_owned(),
+                    ],
+                    Vec::new(),
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Performance
+    }
+
+    fn allow(&self) -> bool {
+        true
+    }
+}
+
+fn is_concatenation(tree: &ExpressionTree) -> bool {
+    matches!(
+        tree,
+        ExpressionTree::Binary {
+            op: ast::BinOp::TwoDots(_),
+            ..
+        }
+    )
+}
+
+// Every value in `a .. b .. c`. Parentheses are a separate concatenation.
+fn operands<'a, 'b, 'ast>(
+    tree: &'b ExpressionTree<'a, 'ast>,
+    operands: &mut Vec<&'b ExpressionTree<'a, 'ast>>,
+) {
+    match tree {
+        ExpressionTree::Binary { lhs, rhs, .. } if is_concatenation(tree) => {
+            self::operands(lhs, operands);
+            self::operands(rhs, operands);
+        }
+
+        _ => operands.push(tree),
+    }
+}
+
+struct Concatenation {
+    operands: usize,
+    range: (usize, usize),
+}
+
+struct ConsecutiveStringConcatVisitor {
+    chained: HashSet<(usize, usize)>,
+    concatenations: Vec<Concatenation>,
+    min_operands: usize,
+}
+
+impl ConsecutiveStringConcatVisitor {
+    fn check(&mut self, tree: &ExpressionTree) {
+        match tree {
+            ExpressionTree::Binary { .. } if is_concatenation(tree) => {
+                let mut values = Vec::new();
+                operands(tree, &mut values);
+
+                // Literals can be written as one string instead
+                let all_literals = values.iter().all(|value| {
+                    matches!(
+                        value.literal_type(),
+                        Some(StaticType::Number) | Some(StaticType::String)
+                    )
+                });
+
+                if values.len() >= self.min_operands && !all_literals {
+                    self.concatenations.push(Concatenation {
+                        operands: values.len(),
+                        range: tree.range(),
+                    });
+                }
+
+                for value in values {
+                    self.check(value);
+                }
+            }
+
+            ExpressionTree::Binary { lhs, rhs, .. } => {
+                self.check(lhs);
+                self.check(rhs);
+            }
+
+            ExpressionTree::Unary { operand, .. } => self.check(operand),
+
+            ExpressionTree::Parentheses(_) | ExpressionTree::Value(_) => {}
+        }
+    }
+}
+
+impl Visitor<'_> for ConsecutiveStringConcatVisitor {
+    fn visit_expression(&mut self, expression: &ast::Expression) {
+        if self.chained.contains(&range(expression)) {
+            return;
+        }
+
+        self.chained.extend(chained_expressions(expression));
+        self.check(&ExpressionTree::new(expression));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_consecutive_string_concat() {
+        test_lint(
+            ConsecutiveStringConcatLint::new(ConsecutiveStringConcatConfig::default()).unwrap(),
+            "consecutive_string_concat",
+            "consecutive_string_concat",
+        );
+    }
+
+    #[test]
+    fn test_min_operands() {
+        test_lint(
+            ConsecutiveStringConcatLint::new(ConsecutiveStringConcatConfig { min_operands: 3 })
+                .unwrap(),
+            "consecutive_string_concat",
+            "min_operands",
+        );
+    }
+}
@@ -0,0 +1,338 @@
+use super::*;
+use crate::ast_util::{
+    range,
+    scopes::{ScopeManager, Variable},
+};
+use std::{
+    collections::{HashMap, HashSet},
+    convert::Infallible,
+};
+
+use full_moon::{
+    ast::{self, Ast},
+    tokenizer::{Symbol, TokenType},
+    visitors::Visitor,
+};
+use id_arena::Id;
+
+pub struct DeadAssignmentLint;
+
+impl Rule for DeadAssignmentLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(DeadAssignmentLint)
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let scope_manager = ScopeManager::new(ast);
+
+        let mut declaration_visitor = DeclarationVisitor {
+            function_bodies: Vec::new(),
+            locals: Vec::new(),
+        };
+
+        declaration_visitor.visit_ast(ast);
+
+        let variables = scope_manager
+            .variables
+            .iter()
+            .map(|(id, variable)| (variable.identifiers[0], id))
+            .collect::<HashMap<_, _>>();
+
+        let captured = captured_variables(&scope_manager, &declaration_visitor.function_bodies);
+
+        let mut visitor = DeadAssignmentVisitor {
+            dead_assignments: Vec::new(),
+            locals: declaration_visitor
+                .locals
+                .iter()
+                .filter_map(|local| variables.get(local).copied())
+                .filter(|id| !captured.contains(id))
+                .collect(),
+            scope_manager: &scope_manager,
+            variables,
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .dead_assignments
+            .iter()
+            .map(|dead_assignment| {
+                let message = format!(
+                    "the value assigned to `{}` here is never read",
+                    dead_assignment.name
+                );
+
+                match dead_assignment.overwritten {
+                    Some(overwritten) => Diagnostic::new_complete(
+                        "dead_assignment",
+                        message,
+                        Label::new(dead_assignment.range),
+                        Vec::new(),
+                        vec![Label::new_with_message(
+                            (overwritten.0 as u32, overwritten.1 as u32),
+                            "overwritten here before it is read".to_owned(),
+                        )],
+                    ),
+
+                    None => Diagnostic::new_complete(
+                        "dead_assignment",
+                        message,
+                        Label::new(dead_assignment.range),
+                        vec![format!(
+                            "note: `{}` goes out of scope before it is read again",
+                            dead_assignment.name
+                        )],
+                        Vec::new(),
+                    ),
+                }
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Correctness
+    }
+}
+
+// Variables that are used inside of a function other than the one they were declared in.
+// These can be read whenever that function is called, so their values are never clearly dead.
+pub(crate) fn captured_variables(
+    scope_manager: &ScopeManager,
+    function_bodies: &[(usize, usize)],
+) -> HashSet<Id<Variable>> {
+    let contains =
+        |body: &(usize, usize), position: usize| position >= body.0 && position <= body.1;
+
+    scope_manager
+        .variables
+        .iter()
+        .filter(|(_, variable)| {
+            let declared = variable.identifiers[0].0;
+
+            variable.references.iter().any(|reference| {
+                let position = scope_manager.references[*reference].identifier.0;
+
+                function_bodies
+                    .iter()
+                    .any(|body| contains(body, position) && !contains(body, declared))
+            })
+        })
+        .map(|(id, _)| id)
+        .collect()
+}
+
+fn is_nil(expression: &ast::Expression) -> bool {
+    match expression {
+        ast::Expression::Value { value, binop: None } => match &**value {
+            ast::Value::Symbol(symbol) => {
+                *symbol.token_type()
+                    == (TokenType::Symbol {
+                        symbol: Symbol::Nil,
+                    })
+            }
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+struct DeadAssignment {
+    name: String,
+    range: (usize, usize),
+    // The assignment that replaces the value, if there is one.
+    // Otherwise, the variable goes out of scope without being read.
+    overwritten: Option<(usize, usize)>,
+}
+
+// A local given a value by a statement itself, rather than somewhere inside of it
+struct Write {
+    variable: Id<Variable>,
+    range: (usize, usize),
+    // Assigning nil is how values are cleared, so they are never reported
+    nil: bool,
+    declaration: bool,
+}
+
+struct DeclarationVisitor {
+    function_bodies: Vec<(usize, usize)>,
+    locals: Vec<(usize, usize)>,
+}
+
+impl Visitor<'_> for DeclarationVisitor {
+    fn visit_function_body(&mut self, body: &ast::FunctionBody) {
+        self.function_bodies.push(range(body));
+    }
+
+    fn visit_local_assignment(&mut self, local_assignment: &ast::LocalAssignment) {
+        self.locals
+            .extend(local_assignment.name_list().iter().map(range));
+    }
+}
+
+struct DeadAssignmentVisitor<'a> {
+    dead_assignments: Vec<DeadAssignment>,
+    // Locals declared with `local`, and never captured by another function
+    locals: HashSet<Id<Variable>>,
+    scope_manager: &'a ScopeManager,
+    // Every variable by where it was first declared
+    variables: HashMap<(usize, usize), Id<Variable>>,
+}
+
+impl DeadAssignmentVisitor<'_> {
+    fn writes(&self, stmt: &ast::Stmt) -> Vec<Write> {
+        let mut writes = Vec::new();
+
+        match stmt {
+            ast::Stmt::Assignment(assignment) => {
+                let mut expressions = assignment.expr_list().iter();
+
+                for var in assignment.var_list() {
+                    let expression = expressions.next();
+
+                    if let ast::Var::Name(name) = var {
+                        let resolved = self
+                            .scope_manager
+                            .reference_at_byte(range(name).0)
+                            .and_then(|reference| reference.resolved);
+
+                        if let Some(variable) = resolved {
+                            writes.push(Write {
+                                variable,
+                                range: range(name),
+                                nil: expression.map(is_nil).unwrap_or(true),
+                                declaration: false,
+                            });
+                        }
+                    }
+                }
+            }
+
+            ast::Stmt::LocalAssignment(local_assignment) => {
+                let mut expressions = local_assignment.expr_list().iter();
+
+                for name in local_assignment.name_list() {
+                    let expression = match expressions.next() {
+                        Some(expression) => expression,
+                        None => break,
+                    };
+
+                    if let Some(variable) = self.variables.get(&range(name)) {
+                        writes.push(Write {
+                            variable: *variable,
+                            range: range(name),
+                            nil: is_nil(expression),
+                            declaration: true,
+                        });
+                    }
+                }
+            }
+
+            _ => {}
+        }
+
+        writes
+            .into_iter()
+            .filter(|write| self.locals.contains(&write.variable))
+            .collect()
+    }
+
+    fn is_read_between(&self, variable: Id<Variable>, start: usize, end: usize) -> bool {
+        self.scope_manager.variables[variable]
+            .references
+            .iter()
+            .map(|reference| &self.scope_manager.references[*reference])
+            .any(|reference| {
+                reference.read && reference.identifier.0 > start && reference.identifier.0 <= end
+            })
+    }
+}
+
+impl Visitor<'_> for DeadAssignmentVisitor<'_> {
+    fn visit_block(&mut self, block: &ast::Block) {
+        let stmts = block
+            .iter_stmts()
+            .map(|stmt| (range(stmt), self.writes(stmt)))
+            .collect::<Vec<_>>();
+
+        let declared = stmts
+            .iter()
+            .flat_map(|(_, writes)| writes.iter())
+            .filter(|write| write.declaration)
+            .map(|write| write.variable)
+            .collect::<HashSet<_>>();
+
+        for (index, (stmt_range, writes)) in stmts.iter().enumerate() {
+            for write in writes.iter().filter(|write| !write.nil) {
+                let variable = &self.scope_manager.variables[write.variable];
+
+                // Variables that are never read at all are reported by unused_variable
+                let is_ever_read = variable
+                    .references
+                    .iter()
+                    .any(|reference| self.scope_manager.references[*reference].read);
+
+                if !is_ever_read {
+                    continue;
+                }
+
+                let overwrite = stmts[index + 1..]
+                    .iter()
+                    .find_map(|(next_range, next_writes)| {
+                        next_writes
+                            .iter()
+                            .find(|next_write| next_write.variable == write.variable)
+                            .map(|next_write| (next_range.1, next_write.range))
+                    });
+
+                let dead_assignment = match overwrite {
+                    Some((end, overwrite_range)) => {
+                        if self.is_read_between(write.variable, stmt_range.1, end) {
+                            continue;
+                        }
+
+                        Some(overwrite_range)
+                    }
+
+                    // The rest of the block is the rest of the variable's scope
+                    None if declared.contains(&write.variable) => {
+                        if self.is_read_between(write.variable, stmt_range.1, usize::MAX) {
+                            continue;
+                        }
+
+                        None
+                    }
+
+                    None => continue,
+                };
+
+                self.dead_assignments.push(DeadAssignment {
+                    name: variable.name.to_owned(),
+                    range: write.range,
+                    overwritten: dead_assignment,
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_dead_assignment() {
+        test_lint(
+            DeadAssignmentLint::new(()).unwrap(),
+            "dead_assignment",
+            "dead_assignment",
+        );
+    }
+}
@@ -0,0 +1,177 @@
+use super::*;
+use crate::ast_util::{range, scopes::ScopeManager};
+use std::convert::Infallible;
+
+use full_moon::{
+    ast::{self, Ast},
+    tokenizer::TokenReference,
+    visitors::Visitor,
+};
+use serde::Deserialize;
+
+#[derive(Clone, Deserialize)]
+#[serde(default)]
+pub struct DebugLibraryConfig {
+    allow: Vec<String>,
+}
+
+impl Default for DebugLibraryConfig {
+    fn default() -> Self {
+        Self {
+            allow: vec!["traceback".to_owned()],
+        }
+    }
+}
+
+pub struct DebugLibraryLint {
+    config: DebugLibraryConfig,
+}
+
+impl Rule for DebugLibraryLint {
+    type Config = DebugLibraryConfig;
+    type Error = Infallible;
+
+    fn new(config: Self::Config) -> Result<Self, Self::Error> {
+        Ok(DebugLibraryLint { config })
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let mut visitor = DebugLibraryVisitor {
+            uses: Vec::new(),
+            scope_manager: ScopeManager::new(ast),
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .uses
+            .iter()
+            .filter(|debug_use| match &debug_use.member {
+                Some(member) => !self.config.allow.contains(member),
+                None => true,
+            })
+            .map(|debug_use| {
+                Diagnostic::new_complete(
+                    "debug_library",
+                    match &debug_use.member {
+                        Some(member) => format!("use of `debug.{}`", member),
+                        None => "use of the `debug` library".to_owned(),
+                    },
+                    Label::new(debug_use.range),
+                    vec![
+                        "note: the debug library is meant for debugging tools, and can be slow or break assumptions other code relies on".to_owned(),
+                    ],
+                    Vec::new(),
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Correctness
+    }
+}
+
+struct DebugUse {
+    // None when `debug` is used by itself, such as `local d = debug`
+    member: Option<String>,
+    range: (usize, usize),
+}
+
+struct DebugLibraryVisitor {
+    uses: Vec<DebugUse>,
+    scope_manager: ScopeManager,
+}
+
+impl DebugLibraryVisitor {
+    fn is_debug(&self, name: &TokenReference) -> bool {
+        if name.to_string() != "debug" {
+            return false;
+        }
+
+        match self
+            .scope_manager
+            .reference_at_byte(range::<_, usize>(name).0)
+        {
+            Some(reference) => reference.resolved.is_none(),
+            None => true,
+        }
+    }
+
+    fn check<'a, 'ast: 'a>(
+        &mut self,
+        prefix: &ast::Prefix,
+        mut suffixes: impl Iterator<Item = &'a ast::Suffix<'ast>>,
+        range: (usize, usize),
+    ) {
+        if let ast::Prefix::Name(name) = prefix {
+            if !self.is_debug(name) {
+                return;
+            }
+
+            let member = match suffixes.next() {
+                Some(ast::Suffix::Index(ast::Index::Dot { name, .. })) => Some(name.to_string()),
+                _ => None,
+            };
+
+            self.uses.push(DebugUse { member, range });
+        }
+    }
+}
+
+impl Visitor<'_> for DebugLibraryVisitor {
+    fn visit_function_call(&mut self, call: &ast::FunctionCall) {
+        self.check(call.prefix(), call.iter_suffixes(), range(call));
+    }
+
+    fn visit_var(&mut self, var: &ast::Var) {
+        match var {
+            ast::Var::Expression(var_expression) => {
+                self.check(
+                    var_expression.prefix(),
+                    var_expression.iter_suffixes(),
+                    range(var_expression),
+                );
+            }
+
+            ast::Var::Name(name) => {
+                if self.is_debug(name) {
+                    self.uses.push(DebugUse {
+                        member: None,
+                        range: range(name),
+                    });
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_debug_library() {
+        test_lint(
+            DebugLibraryLint::new(DebugLibraryConfig::default()).unwrap(),
+            "debug_library",
+            "debug_library",
+        );
+    }
+
+    #[test]
+    fn test_allow() {
+        test_lint(
+            DebugLibraryLint::new(DebugLibraryConfig {
+                allow: vec!["getinfo".to_owned()],
+            })
+            .unwrap(),
+            "debug_library",
+            "allow",
+        );
+    }
+}
@@ -0,0 +1,245 @@
+use super::*;
+use crate::{
+    ast_util::{call_path, range, scopes::ScopeManager},
+    standard_library::{Deprecated, Field},
+};
+use std::{collections::HashSet, convert::Infallible};
+
+use full_moon::{
+    ast::{self, Ast},
+    tokenizer::{Symbol, TokenType},
+    visitors::Visitor,
+};
+
+pub struct DeprecatedGlobalLint;
+
+impl Rule for DeprecatedGlobalLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(DeprecatedGlobalLint)
+    }
+
+    fn pass(&self, ast: &Ast, context: &Context) -> Vec<Diagnostic> {
+        // ScopeManager repeats references
+        let mut read = HashSet::new();
+
+        let mut diagnostics = Vec::new();
+        let scope_manager = ScopeManager::new(ast);
+
+        for (_, reference) in &scope_manager.references {
+            if reference.resolved.is_some() || !reference.read || !read.insert(reference.identifier)
+            {
+                continue;
+            }
+
+            if let Some(Field::Deprecated {
+                removed_in,
+                suggestion,
+            }) = context.standard_library.globals.get(&reference.name)
+            {
+                diagnostics.push(Diagnostic::new_complete(
+                    "deprecated_global",
+                    format!("`{}` was removed in {}", reference.name, removed_in),
+                    Label::new(reference.identifier),
+                    suggestion
+                        .iter()
+                        .map(|suggestion| format!("help: {}", suggestion))
+                        .collect(),
+                    Vec::new(),
+                ));
+            }
+        }
+
+        let mut visitor = DeprecatedFunctionVisitor {
+            calls: Vec::new(),
+            scope_manager: &scope_manager,
+            source: full_moon::print(ast),
+            standard_library: context.standard_library,
+        };
+
+        visitor.visit_ast(ast);
+
+        for call in visitor.calls {
+            let mut notes = vec![format!("note: {}", call.deprecated.message)];
+
+            if let Some(replacement) = call
+                .arguments
+                .as_ref()
+                .and_then(|arguments| call.deprecated.replacement(arguments))
+            {
+                notes.push(format!("try: {}", replacement));
+            }
+
+            diagnostics.push(Diagnostic::new_complete(
+                "deprecated_global",
+                format!("`{}` is deprecated", call.name),
+                Label::new(call.range),
+                notes,
+                Vec::new(),
+            ));
+        }
+
+        diagnostics
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Correctness
+    }
+}
+
+struct DeprecatedCall<'a> {
+    // The source of every argument, if they are simple enough to put in the replacement
+    arguments: Option<Vec<String>>,
+    deprecated: &'a Deprecated,
+    name: String,
+    range: (usize, usize),
+}
+
+struct DeprecatedFunctionVisitor<'a> {
+    calls: Vec<DeprecatedCall<'a>>,
+    scope_manager: &'a ScopeManager,
+    source: String,
+    standard_library: &'a StandardLibrary,
+}
+
+impl DeprecatedFunctionVisitor<'_> {
+    // Arguments that span multiple lines, such as functions, would make the replacement unreadable
+    fn simple_text(&self, start: usize, end: usize) -> Option<String> {
+        let text = self.source[start..end].trim();
+
+        if text.contains('\n') {
+            None
+        } else {
+            Some(text.to_owned())
+        }
+    }
+
+    // Arguments like `...` could be any amount of values, so they can't be put in the replacement
+    fn arguments(&self, arguments: &ast::FunctionArgs) -> Option<Vec<String>> {
+        match arguments {
+            ast::FunctionArgs::Parentheses { arguments, .. } => arguments
+                .iter()
+                .map(|argument| {
+                    if let ast::Expression::Value { value, binop: None } = argument {
+                        if let ast::Value::Symbol(symbol) = &**value {
+                            if *symbol.token_type()
+                                == (TokenType::Symbol {
+                                    symbol: Symbol::Ellipse,
+                                })
+                            {
+                                return None;
+                            }
+                        }
+                    }
+
+                    let (start, end) = range(argument);
+                    self.simple_text(start, end)
+                })
+                .collect(),
+
+            ast::FunctionArgs::String(token) => {
+                let (start, end) = range(token);
+                Some(vec![self.simple_text(start, end)?])
+            }
+
+            ast::FunctionArgs::TableConstructor(table) => {
+                let (start, end) = range(table);
+                Some(vec![self.simple_text(start, end)?])
+            }
+        }
+    }
+}
+
+impl<'a> Visitor<'_> for DeprecatedFunctionVisitor<'a> {
+    fn visit_function_call(&mut self, call: &ast::FunctionCall) {
+        let (names, arguments, method) = match call_path(call) {
+            Some(function) => function,
+            None => return,
+        };
+
+        if let Some(reference) = self.scope_manager.reference_at_byte(range(call).0) {
+            if reference.resolved.is_some() {
+                return;
+            }
+        }
+
+        let standard_library: &'a StandardLibrary = self.standard_library;
+
+        if let Some(Field::Function {
+            deprecated: Some(deprecated),
+            ..
+        }) = standard_library.find_global(&names)
+        {
+            self.calls.push(DeprecatedCall {
+                arguments: self.arguments(arguments),
+                deprecated,
+                name: if method {
+                    format!(
+                        "{}:{}",
+                        names[..names.len() - 1].join("."),
+                        names[names.len() - 1]
+                    )
+                } else {
+                    names.join(".")
+                },
+                range: range(call),
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::*, *};
+    use crate::standard_library::StandardLibrary;
+
+    #[test]
+    fn test_deprecated_global() {
+        test_lint_config(
+            DeprecatedGlobalLint::new(()).unwrap(),
+            "deprecated_global",
+            "deprecated_global",
+            TestUtilConfig {
+                standard_library: StandardLibrary::from_name("lua52").unwrap(),
+                ..TestUtilConfig::default()
+            },
+        );
+    }
+
+    #[test]
+    fn test_deprecated_functions() {
+        test_lint(
+            DeprecatedGlobalLint::new(()).unwrap(),
+            "deprecated_global",
+            "deprecated_functions",
+        );
+    }
+
+    #[test]
+    fn test_lua53() {
+        test_lint_config(
+            DeprecatedGlobalLint::new(()).unwrap(),
+            "deprecated_global",
+            "lua53",
+            TestUtilConfig {
+                standard_library: StandardLibrary::from_name("lua53").unwrap(),
+                ..TestUtilConfig::default()
+            },
+        );
+    }
+
+    #[test]
+    fn test_lua51() {
+        test_lint(
+            DeprecatedGlobalLint::new(()).unwrap(),
+            "deprecated_global",
+            "lua51",
+        );
+    }
+}
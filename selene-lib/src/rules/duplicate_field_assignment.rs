@@ -0,0 +1,213 @@
+use super::*;
+use crate::ast_util::{expression_string_literal, range};
+use std::{collections::HashMap, convert::Infallible};
+
+use full_moon::{
+    ast::{self, Ast},
+    visitors::{Visit, Visitor},
+};
+
+pub struct DuplicateFieldAssignmentLint;
+
+impl Rule for DuplicateFieldAssignmentLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(DuplicateFieldAssignmentLint)
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let mut visitor = DuplicateFieldAssignmentVisitor {
+            duplicates: Vec::new(),
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .duplicates
+            .iter()
+            .map(|duplicate| {
+                Diagnostic::new_complete(
+                    "duplicate_field_assignment",
+                    format!("`{}` is overwritten before it is read", duplicate.name),
+                    Label::new(duplicate.first),
+                    Vec::new(),
+                    vec![Label::new_with_message(
+                        (duplicate.second.0 as u32, duplicate.second.1 as u32),
+                        "overwritten here".to_owned(),
+                    )],
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Correctness
+    }
+}
+
+// An assignment to one field of a table, such as `cfg.timeout = 5`
+struct FieldAssignment<'a, 'ast> {
+    table: String,
+    // None when the key isn't known, such as `cfg[key] = 5`
+    field: Option<String>,
+    key: Option<&'a ast::Expression<'ast>>,
+    value: &'a ast::Expression<'ast>,
+    range: (usize, usize),
+}
+
+fn field_assignment<'a, 'ast>(stmt: &'a ast::Stmt<'ast>) -> Option<FieldAssignment<'a, 'ast>> {
+    let assignment = match stmt {
+        ast::Stmt::Assignment(assignment) => assignment,
+        _ => return None,
+    };
+
+    if assignment.var_list().len() != 1 || assignment.expr_list().len() != 1 {
+        return None;
+    }
+
+    let var_expression = match assignment.var_list().iter().next()? {
+        ast::Var::Expression(var_expression) => var_expression,
+        _ => return None,
+    };
+
+    let table = match var_expression.prefix() {
+        ast::Prefix::Name(name) => name.to_string(),
+        _ => return None,
+    };
+
+    let mut suffixes = var_expression.iter_suffixes();
+    let suffix = suffixes.next()?;
+
+    if suffixes.next().is_some() {
+        return None;
+    }
+
+    let (field, key) = match suffix {
+        ast::Suffix::Index(ast::Index::Dot { name, .. }) => (Some(name.to_string()), None),
+
+        ast::Suffix::Index(ast::Index::Brackets { expression, .. }) => (
+            expression_string_literal(expression).map(|(literal, _)| literal),
+            Some(expression),
+        ),
+
+        _ => return None,
+    };
+
+    Some(FieldAssignment {
+        field: field.map(|field| format!("{}.{}", table, field)),
+        table,
+        key,
+        value: assignment.expr_list().iter().next()?,
+        range: range(var_expression),
+    })
+}
+
+// Whether an expression could read a table's fields, such as by using the table or calling a function
+fn could_read(expression: &ast::Expression, table: &str) -> bool {
+    let mut visitor = ReadVisitor {
+        table,
+        could_read: false,
+    };
+
+    expression.visit(&mut visitor);
+    visitor.could_read
+}
+
+struct ReadVisitor<'a> {
+    table: &'a str,
+    could_read: bool,
+}
+
+impl Visitor<'_> for ReadVisitor<'_> {
+    fn visit_function_call(&mut self, _: &ast::FunctionCall) {
+        self.could_read = true;
+    }
+
+    fn visit_prefix(&mut self, prefix: &ast::Prefix) {
+        if let ast::Prefix::Name(name) = prefix {
+            if name.to_string() == self.table {
+                self.could_read = true;
+            }
+        }
+    }
+
+    fn visit_var(&mut self, var: &ast::Var) {
+        if let ast::Var::Name(name) = var {
+            if name.to_string() == self.table {
+                self.could_read = true;
+            }
+        }
+    }
+}
+
+struct Duplicate {
+    name: String,
+    first: (usize, usize),
+    second: (usize, usize),
+}
+
+struct DuplicateFieldAssignmentVisitor {
+    duplicates: Vec<Duplicate>,
+}
+
+impl Visitor<'_> for DuplicateFieldAssignmentVisitor {
+    fn visit_block(&mut self, block: &ast::Block) {
+        // The table being assigned to by the current run of statements, and where each field was last assigned
+        let mut table = None;
+        let mut assigned = HashMap::new();
+
+        for stmt in block.iter_stmts() {
+            let field_assignment = match field_assignment(stmt) {
+                Some(field_assignment) => field_assignment,
+                None => {
+                    table = None;
+                    continue;
+                }
+            };
+
+            if table.as_ref() != Some(&field_assignment.table) {
+                table = Some(field_assignment.table.to_owned());
+                assigned.clear();
+            }
+
+            if could_read(field_assignment.value, &field_assignment.table)
+                || field_assignment
+                    .key
+                    .map(|key| could_read(key, &field_assignment.table))
+                    .unwrap_or(false)
+            {
+                assigned.clear();
+            }
+
+            if let Some(field) = field_assignment.field {
+                if let Some(first) = assigned.insert(field.to_owned(), field_assignment.range) {
+                    self.duplicates.push(Duplicate {
+                        name: field,
+                        first,
+                        second: field_assignment.range,
+                    });
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_duplicate_field_assignment() {
+        test_lint(
+            DuplicateFieldAssignmentLint::new(()).unwrap(),
+            "duplicate_field_assignment",
+            "duplicate_field_assignment",
+        );
+    }
+}
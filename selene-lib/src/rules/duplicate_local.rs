@@ -0,0 +1,195 @@
+use super::*;
+use crate::ast_util::range;
+use std::collections::HashMap;
+
+use full_moon::{
+    ast::{self, Ast},
+    tokenizer::TokenReference,
+    visitors::{Visit, Visitor},
+};
+use regex::Regex;
+use serde::Deserialize;
+
+#[derive(Clone, Deserialize)]
+#[serde(default)]
+pub struct DuplicateLocalConfig {
+    allow_rebinding: bool,
+    ignore_pattern: String,
+}
+
+impl Default for DuplicateLocalConfig {
+    fn default() -> Self {
+        Self {
+            allow_rebinding: true,
+            ignore_pattern: "^_".to_owned(),
+        }
+    }
+}
+
+pub struct DuplicateLocalLint {
+    allow_rebinding: bool,
+    ignore_pattern: Regex,
+}
+
+impl Rule for DuplicateLocalLint {
+    type Config = DuplicateLocalConfig;
+    type Error = regex::Error;
+
+    fn new(config: Self::Config) -> Result<Self, Self::Error> {
+        Ok(DuplicateLocalLint {
+            allow_rebinding: config.allow_rebinding,
+            ignore_pattern: Regex::new(&config.ignore_pattern)?,
+        })
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let mut visitor = DuplicateLocalVisitor {
+            allow_rebinding: self.allow_rebinding,
+            duplicates: Vec::new(),
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .duplicates
+            .iter()
+            .filter(|duplicate| !self.ignore_pattern.is_match(&duplicate.name))
+            .map(|duplicate| {
+                Diagnostic::new_complete(
+                    "duplicate_local",
+                    format!("`{}` is declared again in the same block", duplicate.name),
+                    Label::new(duplicate.range),
+                    vec![format!(
+                        "help: remove `local` to assign to the existing `{}` instead",
+                        duplicate.name
+                    )],
+                    vec![Label::new_with_message(
+                        (duplicate.first.0 as u32, duplicate.first.1 as u32),
+                        "previously declared here".to_owned(),
+                    )],
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Correctness
+    }
+}
+
+struct Duplicate {
+    name: String,
+    first: (usize, usize),
+    range: (usize, usize),
+}
+
+struct DuplicateLocalVisitor {
+    allow_rebinding: bool,
+    duplicates: Vec<Duplicate>,
+}
+
+// Finds whether a variable is used in an expression, such as `x` in `local x = x + 1`
+struct UsesVariable<'a> {
+    name: &'a str,
+    found: bool,
+}
+
+impl Visitor<'_> for UsesVariable<'_> {
+    fn visit_prefix(&mut self, prefix: &ast::Prefix) {
+        if let ast::Prefix::Name(name) = prefix {
+            self.found = self.found || name.to_string() == self.name;
+        }
+    }
+
+    fn visit_var(&mut self, var: &ast::Var) {
+        if let ast::Var::Name(name) = var {
+            self.found = self.found || name.to_string() == self.name;
+        }
+    }
+}
+
+impl DuplicateLocalVisitor {
+    fn declare(
+        &mut self,
+        declared: &mut HashMap<String, (usize, usize)>,
+        name: &TokenReference,
+        rebinding: bool,
+    ) {
+        let name_string = name.to_string();
+
+        if let Some(first) = declared.get(&name_string) {
+            if !(rebinding && self.allow_rebinding) {
+                self.duplicates.push(Duplicate {
+                    name: name_string.clone(),
+                    first: *first,
+                    range: range(name),
+                });
+            }
+        }
+
+        declared.insert(name_string, range(name));
+    }
+}
+
+impl Visitor<'_> for DuplicateLocalVisitor {
+    fn visit_block(&mut self, block: &ast::Block) {
+        // Only declarations directly inside of this block, nested blocks are checked separately
+        let mut declared = HashMap::new();
+
+        for stmt in block.iter_stmts() {
+            match stmt {
+                ast::Stmt::LocalAssignment(local_assignment) => {
+                    for name in local_assignment.name_list() {
+                        let mut uses_variable = UsesVariable {
+                            name: &name.to_string(),
+                            found: false,
+                        };
+
+                        for expression in local_assignment.expr_list() {
+                            expression.visit(&mut uses_variable);
+                        }
+
+                        self.declare(&mut declared, name, uses_variable.found);
+                    }
+                }
+
+                ast::Stmt::LocalFunction(local_function) => {
+                    self.declare(&mut declared, local_function.name(), false);
+                }
+
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_duplicate_local() {
+        test_lint(
+            DuplicateLocalLint::new(DuplicateLocalConfig::default()).unwrap(),
+            "duplicate_local",
+            "duplicate_local",
+        );
+    }
+
+    #[test]
+    fn test_no_allow_rebinding() {
+        test_lint(
+            DuplicateLocalLint::new(DuplicateLocalConfig {
+                allow_rebinding: false,
+                ..DuplicateLocalConfig::default()
+            })
+            .unwrap(),
+            "duplicate_local",
+            "no_allow_rebinding",
+        );
+    }
+}
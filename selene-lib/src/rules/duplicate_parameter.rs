@@ -0,0 +1,131 @@
+use super::*;
+use crate::ast_util::range;
+use std::{collections::HashMap, convert::Infallible};
+
+use full_moon::{
+    ast::{self, Ast},
+    visitors::Visitor,
+};
+
+pub struct DuplicateParameterLint;
+
+impl Rule for DuplicateParameterLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(DuplicateParameterLint)
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let mut visitor = DuplicateParameterVisitor {
+            duplicates: Vec::new(),
+            method_name: None,
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .duplicates
+            .into_iter()
+            .map(|duplicate| {
+                let (message, label, note) = if duplicate.implicit_self {
+                    (
+                        "parameter `self` shadows the implicit `self` of this method".to_owned(),
+                        "implicit `self` defined here",
+                        "note: the object the method is called on can never be used".to_owned(),
+                    )
+                } else {
+                    (
+                        format!("parameter `{}` is declared more than once", duplicate.name),
+                        "previously declared here",
+                        format!("note: the earlier `{}` can never be used", duplicate.name),
+                    )
+                };
+
+                Diagnostic::new_complete(
+                    "duplicate_parameter",
+                    message,
+                    Label::new(duplicate.range),
+                    vec![note],
+                    vec![Label::new_with_message(
+                        (duplicate.previous.0 as u32, duplicate.previous.1 as u32),
+                        label.to_owned(),
+                    )],
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Correctness
+    }
+}
+
+struct Duplicate {
+    implicit_self: bool,
+    name: String,
+    previous: (usize, usize),
+    range: (usize, usize),
+}
+
+struct DuplicateParameterVisitor {
+    duplicates: Vec<Duplicate>,
+    // The method name of the function being entered, which is where its implicit `self` comes from
+    method_name: Option<(usize, usize)>,
+}
+
+impl Visitor<'_> for DuplicateParameterVisitor {
+    fn visit_function_body(&mut self, body: &ast::FunctionBody) {
+        let mut names = HashMap::new();
+        let mut implicit_self = false;
+
+        if let Some(method_name) = self.method_name.take() {
+            names.insert("self".to_owned(), method_name);
+            implicit_self = true;
+        }
+
+        for parameter in body.iter_parameters() {
+            let name = match parameter {
+                ast::Parameter::Name(name) => name,
+                ast::Parameter::Ellipse(_) => continue,
+            };
+
+            // `_` is used for parameters that are deliberately ignored
+            if name.to_string() == "_" {
+                continue;
+            }
+
+            if let Some(previous) = names.insert(name.to_string(), range(name)) {
+                self.duplicates.push(Duplicate {
+                    implicit_self: implicit_self && name.to_string() == "self",
+                    name: name.to_string(),
+                    previous,
+                    range: range(name),
+                });
+            }
+        }
+    }
+
+    fn visit_function_declaration(&mut self, declaration: &ast::FunctionDeclaration) {
+        self.method_name = declaration.name().method_name().map(range);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_duplicate_parameter() {
+        test_lint(
+            DuplicateParameterLint::new(()).unwrap(),
+            "duplicate_parameter",
+            "duplicate_parameter",
+        );
+    }
+}
@@ -0,0 +1,154 @@
+use super::*;
+use crate::ast_util::{expression_tree::ExpressionTree, range, scopes::ScopeManager};
+use std::convert::Infallible;
+
+use full_moon::{
+    ast::{self, Ast},
+    node::Node,
+    tokenizer::{Symbol, TokenType},
+    visitors::Visitor,
+};
+
+pub struct EagerAssertMessageLint;
+
+impl Rule for EagerAssertMessageLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(EagerAssertMessageLint)
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let mut visitor = EagerAssertMessageVisitor {
+            positions: Vec::new(),
+            scope_manager: ScopeManager::new(ast),
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .positions
+            .iter()
+            .map(|position| {
+                Diagnostic::new_complete(
+                    "eager_assert_message",
+                    "this message is built every time, even when the assertion passes".to_owned(),
+                    Label::new(*position),
+                    vec![
+                        "help: use `if not condition then error(message) end` to only build the message when it is needed, or use a message that doesn't need to be built".to_owned(),
+                    ],
+                    Vec::new(),
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Performance
+    }
+}
+
+// `assert(false, message)` always uses its message
+fn always_fails(condition: &ast::Expression) -> bool {
+    match ExpressionTree::new(condition).value() {
+        Some(ast::Value::Symbol(symbol)) => matches!(
+            *symbol.token_type(),
+            TokenType::Symbol {
+                symbol: Symbol::False,
+            } | TokenType::Symbol {
+                symbol: Symbol::Nil,
+            }
+        ),
+
+        _ => false,
+    }
+}
+
+fn is_format_call(call: &ast::FunctionCall) -> bool {
+    let suffixes = call.iter_suffixes().collect::<Vec<_>>();
+
+    match suffixes.as_slice() {
+        // string.format(...)
+        [ast::Suffix::Index(ast::Index::Dot { name: field, .. }), ast::Suffix::Call(ast::Call::AnonymousCall(_))] => {
+            matches!(call.prefix(), ast::Prefix::Name(name) if name.to_string() == "string")
+                && field.to_string() == "format"
+        }
+
+        // ("%d"):format(...)
+        [.., ast::Suffix::Call(ast::Call::MethodCall(method_call))] => {
+            method_call.name().to_string() == "format"
+        }
+
+        _ => false,
+    }
+}
+
+fn is_built(message: &ast::Expression) -> bool {
+    let tree = ExpressionTree::new(message);
+
+    match &tree {
+        ExpressionTree::Binary {
+            op: ast::BinOp::TwoDots(_),
+            ..
+        } => true,
+
+        ExpressionTree::Parentheses(ast::Expression::Parentheses { expression, .. }) => {
+            is_built(expression)
+        }
+
+        ExpressionTree::Value(ast::Value::ParseExpression(expression)) => is_built(expression),
+
+        _ => matches!(tree.value(), Some(ast::Value::FunctionCall(call)) if is_format_call(call)),
+    }
+}
+
+struct EagerAssertMessageVisitor {
+    positions: Vec<(usize, usize)>,
+    scope_manager: ScopeManager,
+}
+
+impl Visitor<'_> for EagerAssertMessageVisitor {
+    fn visit_function_call(&mut self, call: &ast::FunctionCall) {
+        if_chain::if_chain! {
+            if let ast::Prefix::Name(name) = call.prefix();
+            if name.to_string() == "assert";
+            if let Some(ast::Suffix::Call(ast::Call::AnonymousCall(
+                ast::FunctionArgs::Parentheses { arguments, .. }
+            ))) = call.iter_suffixes().next();
+            let mut arguments = arguments.iter();
+            if let (Some(condition), Some(message)) = (arguments.next(), arguments.next());
+            if !always_fails(condition) && is_built(message);
+            then {
+                if let Some(reference) = self
+                    .scope_manager
+                    .reference_at_byte(call.start_position().unwrap().bytes())
+                {
+                    if reference.resolved.is_some() {
+                        return;
+                    }
+                }
+
+                self.positions.push(range(message));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_eager_assert_message() {
+        test_lint(
+            EagerAssertMessageLint::new(()).unwrap(),
+            "eager_assert_message",
+            "eager_assert_message",
+        );
+    }
+}
@@ -0,0 +1,173 @@
+use super::*;
+use crate::ast_util::{
+    loops::{visit_loops, Loop, LoopStack},
+    range,
+};
+use std::convert::Infallible;
+
+use full_moon::{
+    ast::{self, Ast},
+    visitors::Visitor,
+};
+use serde::Deserialize;
+
+#[derive(Clone, Copy, Deserialize)]
+#[serde(default)]
+pub struct FunctionInLoopConfig {
+    check_closures: bool,
+}
+
+impl Default for FunctionInLoopConfig {
+    fn default() -> Self {
+        Self {
+            check_closures: true,
+        }
+    }
+}
+
+pub struct FunctionInLoopLint {
+    config: FunctionInLoopConfig,
+}
+
+impl Rule for FunctionInLoopLint {
+    type Config = FunctionInLoopConfig;
+    type Error = Infallible;
+
+    fn new(config: Self::Config) -> Result<Self, Self::Error> {
+        Ok(FunctionInLoopLint { config })
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let mut visitor = FunctionInLoopVisitor {
+            functions: Vec::new(),
+            loops: LoopStack::new(),
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .functions
+            .iter()
+            .filter(|function| self.config.check_closures || function.name.is_some())
+            .map(|function| {
+                Diagnostic::new_complete(
+                    "function_in_loop",
+                    match &function.name {
+                        Some(name) => format!("function `{}` is defined inside of a loop", name),
+                        None => "anonymous function is created inside of a loop".to_owned(),
+                    },
+                    Label::new(function.range),
+                    vec![
+                        "note: a new function is created every iteration".to_owned(),
+                        "help: if the function does not use the loop's variables, define it before the loop".to_owned(),
+                    ],
+                    Vec::new(),
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Performance
+    }
+}
+
+struct Function {
+    // None for anonymous functions
+    name: Option<String>,
+    range: (usize, usize),
+}
+
+struct FunctionInLoopVisitor {
+    functions: Vec<Function>,
+    loops: LoopStack<()>,
+}
+
+impl FunctionInLoopVisitor {
+    fn push_function(&mut self, name: Option<String>, range: (usize, usize)) {
+        if self.loops.in_loop() {
+            self.functions.push(Function { name, range });
+        }
+    }
+
+    fn open_loop(&mut self, _: Loop) {
+        self.loops.open_loop(());
+    }
+
+    fn close_loop(&mut self) {
+        self.loops.close_loop();
+    }
+}
+
+impl Visitor<'_> for FunctionInLoopVisitor {
+    fn visit_function_body(&mut self, _: &ast::FunctionBody) {
+        // Functions inside of this one are only created when it is called
+        self.loops.open_function();
+    }
+
+    fn visit_function_body_end(&mut self, _: &ast::FunctionBody) {
+        self.loops.close_function();
+    }
+
+    fn visit_function_declaration(&mut self, declaration: &ast::FunctionDeclaration) {
+        let name = declaration.name();
+        let mut full_name = name
+            .names()
+            .iter()
+            .map(|name| name.to_string())
+            .collect::<Vec<_>>()
+            .join(".");
+
+        if let Some(method_name) = name.method_name() {
+            full_name.push(':');
+            full_name.push_str(&method_name.to_string());
+        }
+
+        self.push_function(Some(full_name), range(name));
+    }
+
+    fn visit_local_function(&mut self, local_function: &ast::LocalFunction) {
+        self.push_function(
+            Some(local_function.name().to_string()),
+            range(local_function.name()),
+        );
+    }
+
+    fn visit_value(&mut self, value: &ast::Value) {
+        if let ast::Value::Function((function_token, _)) = value {
+            self.push_function(None, range(function_token));
+        }
+    }
+
+    visit_loops!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_function_in_loop() {
+        test_lint(
+            FunctionInLoopLint::new(FunctionInLoopConfig::default()).unwrap(),
+            "function_in_loop",
+            "function_in_loop",
+        );
+    }
+
+    #[test]
+    fn test_no_check_closures() {
+        test_lint(
+            FunctionInLoopLint::new(FunctionInLoopConfig {
+                check_closures: false,
+            })
+            .unwrap(),
+            "function_in_loop",
+            "no_check_closures",
+        );
+    }
+}
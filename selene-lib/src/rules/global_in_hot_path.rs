@@ -0,0 +1,231 @@
+use super::*;
+use crate::{
+    ast_util::{
+        loops::{self, visit_loops, LoopStack},
+        range,
+        scopes::ScopeManager,
+    },
+    standard_library::{Field, StandardLibrary},
+};
+use std::convert::Infallible;
+
+use full_moon::{
+    ast::{self, Ast},
+    tokenizer::TokenReference,
+    visitors::Visitor,
+};
+
+pub struct GlobalInHotPathLint;
+
+impl Rule for GlobalInHotPathLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(GlobalInHotPathLint)
+    }
+
+    fn pass(&self, ast: &Ast, context: &Context) -> Vec<Diagnostic> {
+        let mut visitor = GlobalInHotPathVisitor {
+            lookups: Vec::new(),
+            loops: LoopStack::new(),
+            scope_manager: ScopeManager::new(ast),
+            standard_library: context.standard_library,
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .lookups
+            .iter()
+            .map(|lookup| {
+                Diagnostic::new_complete(
+                    "global_in_hot_path",
+                    format!(
+                        "`{}` is looked up every iteration of this loop",
+                        lookup.name.join(".")
+                    ),
+                    Label::new(lookup.range),
+                    vec![format!(
+                        "help: define `local {} = {}` before the loop, and use that instead",
+                        lookup.name.last().unwrap(),
+                        lookup.name.join("."),
+                    )],
+                    Vec::new(),
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Performance
+    }
+
+    fn allow(&self) -> bool {
+        true
+    }
+}
+
+struct Lookup {
+    name: Vec<String>,
+    range: (usize, usize),
+}
+
+#[derive(Default)]
+struct Loop {
+    // The values of a for loop, such as `pairs(t)`, which are only evaluated once
+    header: Option<(usize, usize)>,
+    lookups: Vec<Lookup>,
+}
+
+impl Loop {
+    // Only the first lookup of each name is reported
+    fn push(&mut self, lookup: Lookup) {
+        if !self.lookups.iter().any(|other| other.name == lookup.name) {
+            self.lookups.push(lookup);
+        }
+    }
+}
+
+struct GlobalInHotPathVisitor<'std> {
+    lookups: Vec<Lookup>,
+    loops: LoopStack<Loop>,
+    scope_manager: ScopeManager,
+    standard_library: &'std StandardLibrary,
+}
+
+impl GlobalInHotPathVisitor<'_> {
+    fn open_loop(&mut self, node: loops::Loop) {
+        self.loops.open_loop(Loop {
+            header: node.header(),
+            ..Loop::default()
+        });
+    }
+
+    fn close_loop(&mut self) {
+        let current = self.loops.close_loop();
+
+        // Hoisting out of the outermost loop is what matters, so only it reports its lookups
+        match self.loops.current_mut() {
+            Some(parent) => {
+                for lookup in current.lookups {
+                    parent.push(lookup);
+                }
+            }
+
+            None => self.lookups.extend(current.lookups),
+        }
+    }
+
+    // `math.sin` in `math.sin(x)`, `math.sin`, or `tostring`
+    fn check<'a, 'ast>(
+        &mut self,
+        global: &TokenReference,
+        suffixes: impl Iterator<Item = &'a ast::Suffix<'ast>>,
+    ) where
+        'ast: 'a,
+    {
+        let position = range::<_, usize>(global).0;
+
+        if let Some(reference) = self.scope_manager.reference_at_byte(position) {
+            if reference.resolved.is_some() {
+                return;
+            }
+        }
+
+        let mut name = vec![global.to_string()];
+        let mut end = range::<_, usize>(global).1;
+
+        for suffix in suffixes {
+            match suffix {
+                ast::Suffix::Index(ast::Index::Dot { name: field, .. }) => {
+                    name.push(field.to_string());
+                    end = range::<_, usize>(field).1;
+                }
+
+                _ => break,
+            }
+        }
+
+        // Use the shortest path that is a function, so `string.format` isn't reported for
+        // `string.format.thing`, which errors anyway
+        while !name.is_empty() {
+            if let Some(Field::Function { .. }) = self.standard_library.find_global(&name) {
+                break;
+            }
+
+            name.pop();
+        }
+
+        if name.is_empty() {
+            return;
+        }
+
+        if let Some(current) = self.current_loop(position) {
+            current.push(Lookup {
+                name,
+                range: (position, end),
+            });
+        }
+    }
+
+    // The loop that a position is run every iteration of, if any
+    fn current_loop(&mut self, position: usize) -> Option<&mut Loop> {
+        for current in self.loops.iter_mut().rev() {
+            match current.header {
+                Some((start, end)) if start <= position && position <= end => continue,
+                _ => return Some(current),
+            }
+        }
+
+        None
+    }
+}
+
+impl Visitor<'_> for GlobalInHotPathVisitor<'_> {
+    fn visit_function_body(&mut self, _: &ast::FunctionBody) {
+        self.loops.open_function();
+    }
+
+    fn visit_function_body_end(&mut self, _: &ast::FunctionBody) {
+        self.loops.close_function();
+    }
+
+    fn visit_function_call(&mut self, call: &ast::FunctionCall) {
+        if let ast::Prefix::Name(name) = call.prefix() {
+            self.check(name, call.iter_suffixes());
+        }
+    }
+
+    fn visit_var(&mut self, var: &ast::Var) {
+        match var {
+            ast::Var::Expression(var_expression) => {
+                if let ast::Prefix::Name(name) = var_expression.prefix() {
+                    self.check(name, var_expression.iter_suffixes());
+                }
+            }
+
+            ast::Var::Name(name) => self.check(name, std::iter::empty()),
+        }
+    }
+
+    visit_loops!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_global_in_hot_path() {
+        test_lint(
+            GlobalInHotPathLint::new(()).unwrap(),
+            "global_in_hot_path",
+            "global_in_hot_path",
+        );
+    }
+}
@@ -0,0 +1,290 @@
+use super::*;
+use crate::ast_util::{
+    expression_tree::{ExpressionTree, StaticType},
+    range,
+};
+use std::convert::Infallible;
+
+use full_moon::{
+    ast::{self, Ast},
+    node::Node,
+    tokenizer::{Symbol, TokenType},
+    visitors::Visitor,
+};
+
+pub struct IfReturnBoolLint;
+
+impl Rule for IfReturnBoolLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(IfReturnBoolLint)
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let source = full_moon::print(ast);
+
+        let mut visitor = IfReturnBoolVisitor {
+            simplifications: Vec::new(),
+            source: &source,
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .simplifications
+            .into_iter()
+            .map(|simplification| {
+                let mut notes = vec![format!("help: try `{}`", simplification.suggestion)];
+
+                // `if x then return true else return false end` returns `false` when `x` is `nil`,
+                // but `return x` returns `nil`
+                if let Some(boolean_suggestion) = simplification.boolean_suggestion {
+                    notes.push(
+                        "note: this is only the same when the condition is always a boolean"
+                            .to_owned(),
+                    );
+                    notes.push(format!(
+                        "help: if it could be something else, such as `nil`, try `{}`",
+                        boolean_suggestion
+                    ));
+                }
+
+                Diagnostic::new_complete(
+                    "if_return_bool",
+                    "this if statement can be simplified".to_owned(),
+                    Label::new(simplification.range),
+                    notes,
+                    Vec::new(),
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Style
+    }
+}
+
+fn boolean_literal(expression: &ast::Expression) -> Option<bool> {
+    if let Some(ast::Value::Symbol(symbol)) = ExpressionTree::new(expression).value() {
+        match *symbol.token_type() {
+            TokenType::Symbol {
+                symbol: Symbol::True,
+            } => Some(true),
+            TokenType::Symbol {
+                symbol: Symbol::False,
+            } => Some(false),
+            _ => None,
+        }
+    } else {
+        None
+    }
+}
+
+// The boolean returned by a `return true` or `return false`
+fn returned_boolean(last_stmt: Option<&ast::LastStmt>) -> Option<bool> {
+    if let Some(ast::LastStmt::Return(return_stmt)) = last_stmt {
+        let mut returns = return_stmt.returns().iter();
+
+        match (returns.next(), returns.next()) {
+            (Some(expression), None) => boolean_literal(expression),
+            _ => None,
+        }
+    } else {
+        None
+    }
+}
+
+// The boolean returned by a block that does nothing but `return true` or `return false`
+fn block_returned_boolean(block: &ast::Block) -> Option<bool> {
+    if block.iter_stmts().next().is_some() {
+        return None;
+    }
+
+    returned_boolean(block.last_stmts())
+}
+
+// The variable and the boolean assigned by a block that does nothing but `x = true` or `x = false`
+fn block_assigned_boolean<'a, 'ast>(
+    block: &'a ast::Block<'ast>,
+) -> Option<(&'a ast::Var<'ast>, bool)> {
+    let mut stmts = block.iter_stmts();
+
+    match (stmts.next(), stmts.next(), block.last_stmts()) {
+        (Some(ast::Stmt::Assignment(assignment)), None, None) => {
+            let mut vars = assignment.var_list().iter();
+            let mut expressions = assignment.expr_list().iter();
+
+            match (
+                vars.next(),
+                vars.next(),
+                expressions.next(),
+                expressions.next(),
+            ) {
+                (Some(var), None, Some(expression), None) => {
+                    Some((var, boolean_literal(expression)?))
+                }
+                _ => None,
+            }
+        }
+
+        _ => None,
+    }
+}
+
+struct Simplification {
+    range: (usize, usize),
+    suggestion: String,
+    // Given when the condition might not be a boolean, like `return not not x`
+    boolean_suggestion: Option<String>,
+}
+
+struct IfReturnBoolVisitor<'a> {
+    simplifications: Vec<Simplification>,
+    source: &'a str,
+}
+
+impl IfReturnBoolVisitor<'_> {
+    fn text(&self, tree: &ExpressionTree) -> &str {
+        let (start, end) = tree.range();
+        self.source[start..end].trim()
+    }
+
+    fn var_text(&self, var: &ast::Var) -> &str {
+        let (start, end) = range::<_, usize>(var);
+        self.source[start..end].trim()
+    }
+
+    // `prefix` is what comes before the condition, such as `return ` or `x = `
+    fn push(
+        &mut self,
+        range: (usize, usize),
+        prefix: &str,
+        condition: &ast::Expression,
+        negated: bool,
+    ) {
+        let tree = ExpressionTree::new(condition);
+
+        let not = |tree: &ExpressionTree| match tree {
+            ExpressionTree::Binary { .. } => format!("not ({})", self.text(tree)),
+            _ => format!("not {}", self.text(tree)),
+        };
+
+        // The expression that is the same as the if statement when it is a boolean
+        let simplified = match (&tree, negated) {
+            (_, false) => &tree,
+
+            (
+                ExpressionTree::Unary {
+                    op: ast::UnOp::Not(_),
+                    operand,
+                },
+                true,
+            ) => &**operand,
+
+            (_, true) => {
+                // `not x` is always a boolean
+                self.simplifications.push(Simplification {
+                    range,
+                    suggestion: format!("{}{}", prefix, not(&tree)),
+                    boolean_suggestion: None,
+                });
+
+                return;
+            }
+        };
+
+        let boolean_suggestion = if simplified.static_type(false) == Some(StaticType::Boolean) {
+            None
+        } else {
+            Some(format!("{}not {}", prefix, not(simplified)))
+        };
+
+        self.simplifications.push(Simplification {
+            range,
+            suggestion: format!("{}{}", prefix, self.text(simplified)),
+            boolean_suggestion,
+        });
+    }
+}
+
+impl Visitor<'_> for IfReturnBoolVisitor<'_> {
+    fn visit_block(&mut self, block: &ast::Block) {
+        let stmts = block.iter_stmts().collect::<Vec<_>>();
+
+        for (index, stmt) in stmts.iter().enumerate() {
+            let if_block = match stmt {
+                ast::Stmt::If(if_block) if if_block.else_if().is_none() => if_block,
+                _ => continue,
+            };
+
+            // if x then y = true else y = false end
+            if_chain::if_chain! {
+                if let Some((var, then_assigns)) = block_assigned_boolean(if_block.block());
+                if let Some((else_var, else_assigns)) = if_block.else_block().and_then(block_assigned_boolean);
+                if else_assigns != then_assigns;
+                let var = self.var_text(var);
+                if var == self.var_text(else_var);
+                then {
+                    let prefix = format!("{} = ", var);
+                    self.push(range(if_block), &prefix, if_block.condition(), !then_assigns);
+                    continue;
+                }
+            }
+
+            let then_returns = match block_returned_boolean(if_block.block()) {
+                Some(then_returns) => then_returns,
+                None => continue,
+            };
+
+            let end = match if_block.else_block() {
+                // if x then return true else return false end
+                Some(else_block) => {
+                    if block_returned_boolean(else_block) != Some(!then_returns) {
+                        continue;
+                    }
+
+                    range(if_block).1
+                }
+
+                // if x then return true end return false
+                None => {
+                    if index + 1 != stmts.len()
+                        || returned_boolean(block.last_stmts()) != Some(!then_returns)
+                    {
+                        continue;
+                    }
+
+                    block.last_stmts().unwrap().end_position().unwrap().bytes()
+                }
+            };
+
+            self.push(
+                (range(if_block).0, end),
+                "return ",
+                if_block.condition(),
+                !then_returns,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_if_return_bool() {
+        test_lint(
+            IfReturnBoolLint::new(()).unwrap(),
+            "if_return_bool",
+            "if_return_bool",
+        );
+    }
+}
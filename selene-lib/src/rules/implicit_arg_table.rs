@@ -0,0 +1,124 @@
+use super::*;
+use crate::ast_util::{range, scopes::ScopeManager};
+use std::convert::Infallible;
+
+use full_moon::{
+    ast::{self, Ast},
+    tokenizer::TokenReference,
+    visitors::Visitor,
+};
+
+pub struct ImplicitArgTableLint;
+
+impl Rule for ImplicitArgTableLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(ImplicitArgTableLint)
+    }
+
+    fn pass(&self, ast: &Ast, context: &Context) -> Vec<Diagnostic> {
+        // Roblox has never had the implicit `arg` table
+        if context.is_roblox() {
+            return Vec::new();
+        }
+
+        let mut visitor = ImplicitArgTableVisitor {
+            functions: Vec::new(),
+            positions: Vec::new(),
+            scope_manager: ScopeManager::new(ast),
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .positions
+            .iter()
+            .map(|position| {
+                Diagnostic::new_complete(
+                    "implicit_arg_table",
+                    "the implicit `arg` table was removed after Lua 5.0".to_owned(),
+                    Label::new(*position),
+                    vec![
+                        "help: use `{...}` to get the arguments as a table, and `select(\"#\", ...)` to get how many there are".to_owned(),
+                    ],
+                    Vec::new(),
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Correctness
+    }
+}
+
+struct ImplicitArgTableVisitor {
+    // Whether each function body takes varargs
+    functions: Vec<bool>,
+    positions: Vec<(usize, usize)>,
+    scope_manager: ScopeManager,
+}
+
+impl ImplicitArgTableVisitor {
+    fn check(&mut self, name: &TokenReference) {
+        // `arg` outside of a function is the command line arguments given to the script
+        if name.to_string() != "arg" || self.functions.last() != Some(&true) {
+            return;
+        }
+
+        let position = range(name);
+
+        if let Some(reference) = self.scope_manager.reference_at_byte(position.0) {
+            if reference.resolved.is_some() {
+                return;
+            }
+        }
+
+        self.positions.push(position);
+    }
+}
+
+impl Visitor<'_> for ImplicitArgTableVisitor {
+    fn visit_function_body(&mut self, body: &ast::FunctionBody) {
+        self.functions.push(
+            body.iter_parameters()
+                .any(|parameter| matches!(parameter, ast::Parameter::Ellipse(_))),
+        );
+    }
+
+    fn visit_function_body_end(&mut self, _: &ast::FunctionBody) {
+        self.functions.pop();
+    }
+
+    fn visit_prefix(&mut self, prefix: &ast::Prefix) {
+        if let ast::Prefix::Name(name) = prefix {
+            self.check(name);
+        }
+    }
+
+    fn visit_var(&mut self, var: &ast::Var) {
+        if let ast::Var::Name(name) = var {
+            self.check(name);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_implicit_arg_table() {
+        test_lint(
+            ImplicitArgTableLint::new(()).unwrap(),
+            "implicit_arg_table",
+            "implicit_arg_table",
+        );
+    }
+}
@@ -0,0 +1,162 @@
+use super::*;
+use crate::ast_util::range;
+use std::convert::Infallible;
+
+use full_moon::{
+    ast::{self, Ast},
+    tokenizer::Symbol,
+    visitors::Visitor,
+};
+
+use super::infinite_loop::is_symbol;
+
+pub struct ImplicitNilReturnLint;
+
+impl Rule for ImplicitNilReturnLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(ImplicitNilReturnLint)
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let mut visitor = ImplicitNilReturnVisitor {
+            positions: Vec::new(),
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .positions
+            .iter()
+            .map(|position| {
+                Diagnostic::new_complete(
+                    "implicit_nil_return",
+                    "this function can reach its end without returning".to_owned(),
+                    Label::new(*position),
+                    vec!["help: add a `return` to the end of the function".to_owned()],
+                    Vec::new(),
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Style
+    }
+
+    fn allow(&self) -> bool {
+        true
+    }
+}
+
+// `error(...)`, which never lets the code after it run
+pub(crate) fn is_error_call(call: &ast::FunctionCall) -> bool {
+    let mut suffixes = call.iter_suffixes();
+
+    matches!(call.prefix(), ast::Prefix::Name(name) if name.to_string() == "error")
+        && matches!(
+            suffixes.next(),
+            Some(ast::Suffix::Call(ast::Call::AnonymousCall(_)))
+        )
+        && suffixes.next().is_none()
+}
+
+// The blocks of the `if`, every `elseif`, and the `else`
+fn if_branches<'a, 'ast>(
+    if_block: &'a ast::If<'ast>,
+) -> impl Iterator<Item = &'a ast::Block<'ast>> {
+    std::iter::once(if_block.block())
+        .chain(
+            if_block
+                .else_if()
+                .into_iter()
+                .flatten()
+                .map(ast::ElseIf::block),
+        )
+        .chain(if_block.else_block())
+}
+
+// Whether a `break` inside of this block would leave the loop the block belongs to.
+// Loops and functions inside of it are skipped, as a `break` in them can't.
+fn breaks(block: &ast::Block) -> bool {
+    if let Some(ast::LastStmt::Break(_)) = block.last_stmts() {
+        return true;
+    }
+
+    block.iter_stmts().any(|stmt| match stmt {
+        ast::Stmt::Do(do_block) => breaks(do_block.block()),
+
+        ast::Stmt::If(if_block) => if_branches(if_block).any(breaks),
+
+        _ => false,
+    })
+}
+
+// Whether a statement never lets the code after it run, by returning, erroring, or looping forever
+fn stmt_exits(stmt: &ast::Stmt) -> bool {
+    match stmt {
+        ast::Stmt::Do(do_block) => block_exits(do_block.block()),
+
+        ast::Stmt::FunctionCall(call) => is_error_call(call),
+
+        // Every branch has to exit, including the `else`
+        ast::Stmt::If(if_block) => {
+            if_block.else_block().is_some() && if_branches(if_block).all(block_exits)
+        }
+
+        // The block of a `repeat` always runs at least once
+        ast::Stmt::Repeat(repeat) => {
+            !breaks(repeat.block())
+                && (block_exits(repeat.block())
+                    || is_symbol(repeat.until(), Symbol::False)
+                    || is_symbol(repeat.until(), Symbol::Nil))
+        }
+
+        ast::Stmt::While(while_loop) => {
+            is_symbol(while_loop.condition(), Symbol::True) && !breaks(while_loop.block())
+        }
+
+        // Loops that can run zero times, and everything else
+        _ => false,
+    }
+}
+
+// Whether the end of a block can never be reached
+fn block_exits(block: &ast::Block) -> bool {
+    match block.last_stmts() {
+        Some(ast::LastStmt::Return(_)) => true,
+        _ => block.iter_stmts().any(stmt_exits),
+    }
+}
+
+struct ImplicitNilReturnVisitor {
+    positions: Vec<(usize, usize)>,
+}
+
+impl Visitor<'_> for ImplicitNilReturnVisitor {
+    fn visit_function_body(&mut self, body: &ast::FunctionBody) {
+        if !block_exits(body.block()) {
+            self.positions.push(range(body.end_token()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_implicit_nil_return() {
+        test_lint(
+            ImplicitNilReturnLint::new(()).unwrap(),
+            "implicit_nil_return",
+            "implicit_nil_return",
+        );
+    }
+}
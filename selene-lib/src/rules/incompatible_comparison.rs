@@ -0,0 +1,173 @@
+use super::*;
+use crate::ast_util::{
+    expression_tree::{
+        chained_expressions, is_comparison, is_equality, operator_text, ExpressionTree, StaticType,
+    },
+    literal_locals::LiteralLocals,
+    range,
+};
+use std::{collections::HashSet, convert::Infallible};
+
+use full_moon::{
+    ast::{self, Ast},
+    visitors::Visitor,
+};
+
+pub struct IncompatibleComparisonLint;
+
+impl Rule for IncompatibleComparisonLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(IncompatibleComparisonLint)
+    }
+
+    fn pass(&self, ast: &Ast, context: &Context) -> Vec<Diagnostic> {
+        let mut visitor = IncompatibleComparisonVisitor {
+            chained: HashSet::new(),
+            comparisons: Vec::new(),
+            literal_locals: LiteralLocals::new(),
+            roblox: context.is_roblox(),
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .comparisons
+            .into_iter()
+            .map(|comparison| {
+                Diagnostic::new_complete(
+                    "incompatible_comparison",
+                    format!(
+                        "comparing `{}` to `{}` with `{}` will error",
+                        comparison.types.0, comparison.types.1, comparison.operator,
+                    ),
+                    Label::new(comparison.range),
+                    vec!["note: Lua does not convert values when comparing them".to_owned()],
+                    Vec::new(),
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Correctness
+    }
+}
+
+struct IncompatibleComparisonVisitor {
+    chained: HashSet<(usize, usize)>,
+    comparisons: Vec<Comparison>,
+    literal_locals: LiteralLocals,
+    roblox: bool,
+}
+
+struct Comparison {
+    operator: &'static str,
+    range: (usize, usize),
+    types: (String, String),
+}
+
+impl IncompatibleComparisonVisitor {
+    // The type of a literal, or of a local assigned a literal in the same block, like `local x = "10"`
+    fn literal_type(&self, operand: &ExpressionTree) -> Option<StaticType> {
+        if let Some(literal_type) = operand.literal_type() {
+            return Some(literal_type);
+        }
+
+        match operand.value() {
+            Some(ast::Value::Var(ast::Var::Name(name))) => {
+                self.literal_locals.get(&name.to_string())
+            }
+            _ => None,
+        }
+    }
+
+    fn static_type(&self, operand: &ExpressionTree) -> Option<StaticType> {
+        self.literal_type(operand)
+            .or_else(|| operand.static_type(self.roblox))
+    }
+}
+
+impl Visitor<'_> for IncompatibleComparisonVisitor {
+    fn visit_assignment(&mut self, assignment: &ast::Assignment) {
+        self.literal_locals.visit_assignment(assignment);
+    }
+
+    fn visit_block(&mut self, _: &ast::Block) {
+        self.literal_locals.visit_block();
+    }
+
+    fn visit_block_end(&mut self, _: &ast::Block) {
+        self.literal_locals.visit_block_end();
+    }
+
+    fn visit_expression(&mut self, expression: &ast::Expression) {
+        if self.chained.contains(&range(expression)) {
+            return;
+        }
+
+        self.chained.extend(chained_expressions(expression));
+
+        let mut comparisons = Vec::new();
+
+        ExpressionTree::new(expression).for_each_binary(&mut |lhs, op, rhs| {
+            if !is_comparison(op) || is_equality(op) {
+                return;
+            }
+
+            // One side must be a literal, so we don't lint things like `#x < type(y)`
+            if self.literal_type(lhs).is_none() && self.literal_type(rhs).is_none() {
+                return;
+            }
+
+            if let (Some(lhs_type), Some(rhs_type)) = (self.static_type(lhs), self.static_type(rhs))
+            {
+                // Tables can define __lt and __le
+                if lhs_type != rhs_type
+                    && lhs_type != StaticType::Table
+                    && rhs_type != StaticType::Table
+                {
+                    comparisons.push(Comparison {
+                        operator: operator_text(op),
+                        range: (lhs.range().0, rhs.range().1),
+                        types: (lhs_type.to_string(), rhs_type.to_string()),
+                    });
+                }
+            }
+        });
+
+        self.comparisons.extend(comparisons);
+    }
+
+    fn visit_local_assignment(&mut self, local_assignment: &ast::LocalAssignment) {
+        self.literal_locals.visit_local_assignment(local_assignment);
+    }
+
+    fn visit_local_assignment_end(&mut self, _: &ast::LocalAssignment) {
+        self.literal_locals.visit_local_assignment_end();
+    }
+
+    fn visit_local_function(&mut self, local_function: &ast::LocalFunction) {
+        self.literal_locals.visit_local_function(local_function);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_incompatible_comparison() {
+        test_lint(
+            IncompatibleComparisonLint::new(()).unwrap(),
+            "incompatible_comparison",
+            "incompatible_comparison",
+        );
+    }
+}
@@ -0,0 +1,124 @@
+use super::*;
+use crate::ast_util::{
+    expression_tree::{chained_expressions, is_equality, operator_text, ExpressionTree},
+    range,
+};
+use std::{collections::HashSet, convert::Infallible};
+
+use full_moon::{
+    ast::{self, Ast},
+    visitors::Visitor,
+};
+
+pub struct IncompatibleEqualityLint;
+
+impl Rule for IncompatibleEqualityLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(IncompatibleEqualityLint)
+    }
+
+    fn pass(&self, ast: &Ast, context: &Context) -> Vec<Diagnostic> {
+        let mut visitor = IncompatibleEqualityVisitor {
+            chained: HashSet::new(),
+            comparisons: Vec::new(),
+            roblox: context.is_roblox(),
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .comparisons
+            .into_iter()
+            .map(|comparison| {
+                Diagnostic::new_complete(
+                    "incompatible_equality",
+                    format!(
+                        "comparing `{}` to `{}` with `{}` is always {}",
+                        comparison.types.0,
+                        comparison.types.1,
+                        comparison.operator,
+                        comparison.result,
+                    ),
+                    Label::new(comparison.range),
+                    vec!["note: values of different types are never equal in Lua".to_owned()],
+                    Vec::new(),
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Correctness
+    }
+}
+
+struct IncompatibleEqualityVisitor {
+    chained: HashSet<(usize, usize)>,
+    comparisons: Vec<Comparison>,
+    roblox: bool,
+}
+
+struct Comparison {
+    operator: &'static str,
+    range: (usize, usize),
+    result: bool,
+    types: (String, String),
+}
+
+impl Visitor<'_> for IncompatibleEqualityVisitor {
+    fn visit_expression(&mut self, expression: &ast::Expression) {
+        if self.chained.contains(&range(expression)) {
+            return;
+        }
+
+        self.chained.extend(chained_expressions(expression));
+
+        let roblox = self.roblox;
+        let comparisons = &mut self.comparisons;
+
+        ExpressionTree::new(expression).for_each_binary(&mut |lhs, op, rhs| {
+            if !is_equality(op) {
+                return;
+            }
+
+            // One side must be a literal, so we don't lint things like `#x == type(y)`
+            if lhs.literal_type().is_none() && rhs.literal_type().is_none() {
+                return;
+            }
+
+            if let (Some(lhs_type), Some(rhs_type)) =
+                (lhs.static_type(roblox), rhs.static_type(roblox))
+            {
+                if lhs_type != rhs_type {
+                    comparisons.push(Comparison {
+                        operator: operator_text(op),
+                        range: (lhs.range().0, rhs.range().1),
+                        result: matches!(op, ast::BinOp::TildeEqual(_)),
+                        types: (lhs_type.to_string(), rhs_type.to_string()),
+                    });
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_incompatible_equality() {
+        test_lint(
+            IncompatibleEqualityLint::new(()).unwrap(),
+            "incompatible_equality",
+            "incompatible_equality",
+        );
+    }
+}
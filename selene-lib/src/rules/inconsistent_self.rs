@@ -0,0 +1,218 @@
+use super::*;
+use crate::ast_util::range;
+use std::convert::Infallible;
+
+use full_moon::{
+    ast::{self, Ast},
+    visitors::Visitor,
+};
+use serde::Deserialize;
+
+#[derive(Clone, Deserialize)]
+#[serde(default)]
+pub struct InconsistentSelfConfig {
+    // The name methods should use for their object
+    name: String,
+    // Names that suggest a parameter is the method's object
+    self_names: Vec<String>,
+}
+
+impl Default for InconsistentSelfConfig {
+    fn default() -> Self {
+        Self {
+            name: "self".to_owned(),
+            self_names: vec!["self".to_owned(), "this".to_owned(), "me".to_owned()],
+        }
+    }
+}
+
+pub struct InconsistentSelfLint {
+    config: InconsistentSelfConfig,
+}
+
+impl Rule for InconsistentSelfLint {
+    type Config = InconsistentSelfConfig;
+    type Error = Infallible;
+
+    fn new(config: Self::Config) -> Result<Self, Self::Error> {
+        Ok(InconsistentSelfLint { config })
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let mut visitor = InconsistentSelfVisitor {
+            methods: Vec::new(),
+        };
+
+        visitor.visit_ast(ast);
+
+        let mut diagnostics = Vec::new();
+
+        for method in visitor.methods {
+            if !self.config.self_names.contains(&method.parameter) {
+                continue;
+            }
+
+            let (table, field) = method.name.split_at(method.name.len() - 1);
+            let full_name = method.name.join(".");
+
+            let colon_syntax = format!(
+                "function {}:{}({})",
+                table.join("."),
+                field[0],
+                method.other_parameters.join(", ")
+            );
+
+            if method.parameter == self.config.name && self.config.name == "self" {
+                diagnostics.push(Diagnostic::new_complete(
+                    "inconsistent_self",
+                    format!(
+                        "`{}` takes `self`, but is not declared as a method",
+                        full_name
+                    ),
+                    Label::new(method.range),
+                    vec![format!(
+                        "help: try `{}`, which defines `self` for you",
+                        colon_syntax
+                    )],
+                    Vec::new(),
+                ));
+            } else if method.parameter != self.config.name {
+                diagnostics.push(Diagnostic::new_complete(
+                    "inconsistent_self",
+                    format!(
+                        "the object of `{}` is named `{}`, rather than `{}`",
+                        full_name, method.parameter, self.config.name
+                    ),
+                    Label::new(method.range),
+                    vec![if self.config.name == "self" {
+                        format!("help: try `{}`, and use `self` instead", colon_syntax)
+                    } else {
+                        format!("help: rename it to `{}`", self.config.name)
+                    }],
+                    Vec::new(),
+                ));
+            }
+        }
+
+        diagnostics
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Style
+    }
+}
+
+// A function stored in a table that was not defined with `:`, such as `function Car.drive(this)`
+struct Method {
+    // `Car.drive` is ["Car", "drive"]
+    name: Vec<String>,
+    parameter: String,
+    other_parameters: Vec<String>,
+    range: (usize, usize),
+}
+
+struct InconsistentSelfVisitor {
+    methods: Vec<Method>,
+}
+
+impl InconsistentSelfVisitor {
+    fn push(&mut self, name: Vec<String>, body: &ast::FunctionBody) {
+        let mut parameters = body.iter_parameters();
+
+        if let Some(ast::Parameter::Name(parameter)) = parameters.next() {
+            self.methods.push(Method {
+                name,
+                parameter: parameter.to_string(),
+                other_parameters: parameters
+                    .map(|parameter| match parameter {
+                        ast::Parameter::Name(name) => name.to_string(),
+                        ast::Parameter::Ellipse(ellipse) => ellipse.to_string(),
+                    })
+                    .collect(),
+                range: range(parameter),
+            });
+        }
+    }
+}
+
+// `Car.drive` in `Car.drive = function(this) end`
+fn var_name(var: &ast::Var) -> Option<Vec<String>> {
+    let var_expression = match var {
+        ast::Var::Expression(var_expression) => var_expression,
+        ast::Var::Name(_) => return None,
+    };
+
+    let mut name = match var_expression.prefix() {
+        ast::Prefix::Name(prefix) => vec![prefix.to_string()],
+        _ => return None,
+    };
+
+    for suffix in var_expression.iter_suffixes() {
+        match suffix {
+            ast::Suffix::Index(ast::Index::Dot { name: field, .. }) => name.push(field.to_string()),
+            _ => return None,
+        }
+    }
+
+    Some(name)
+}
+
+impl Visitor<'_> for InconsistentSelfVisitor {
+    fn visit_assignment(&mut self, assignment: &ast::Assignment) {
+        for (var, expression) in assignment.var_list().iter().zip(assignment.expr_list()) {
+            if_chain::if_chain! {
+                if let ast::Expression::Value { value, binop: None } = expression;
+                if let ast::Value::Function((_, body)) = &**value;
+                if let Some(name) = var_name(var);
+                then {
+                    self.push(name, body);
+                }
+            }
+        }
+    }
+
+    fn visit_function_declaration(&mut self, declaration: &ast::FunctionDeclaration) {
+        let name = declaration.name();
+
+        // Methods defined with `:` can't name their object
+        if name.method_name().is_some() || name.names().len() < 2 {
+            return;
+        }
+
+        self.push(
+            name.names().iter().map(ToString::to_string).collect(),
+            declaration.body(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_inconsistent_self() {
+        test_lint(
+            InconsistentSelfLint::new(InconsistentSelfConfig::default()).unwrap(),
+            "inconsistent_self",
+            "inconsistent_self",
+        );
+    }
+
+    #[test]
+    fn test_name() {
+        test_lint(
+            InconsistentSelfLint::new(InconsistentSelfConfig {
+                name: "this".to_owned(),
+                ..InconsistentSelfConfig::default()
+            })
+            .unwrap(),
+            "inconsistent_self",
+            "name",
+        );
+    }
+}
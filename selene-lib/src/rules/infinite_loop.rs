@@ -0,0 +1,213 @@
+use super::*;
+use crate::ast_util::{
+    call_name,
+    expression_tree::ExpressionTree,
+    loops::{visit_loops, Loop, LoopStack},
+    range,
+};
+use std::convert::Infallible;
+
+use full_moon::{
+    ast::{self, Ast},
+    tokenizer::{Symbol, TokenType},
+    visitors::{Visit, Visitor},
+};
+use serde::Deserialize;
+
+#[derive(Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct InfiniteLoopConfig {
+    yielding_functions: Option<Vec<String>>,
+}
+
+pub struct InfiniteLoopLint {
+    config: InfiniteLoopConfig,
+}
+
+impl Rule for InfiniteLoopLint {
+    type Config = InfiniteLoopConfig;
+    type Error = Infallible;
+
+    fn new(config: Self::Config) -> Result<Self, Self::Error> {
+        Ok(InfiniteLoopLint { config })
+    }
+
+    fn pass(&self, ast: &Ast, context: &Context) -> Vec<Diagnostic> {
+        let yielding_functions = match &self.config.yielding_functions {
+            Some(yielding_functions) => yielding_functions.clone(),
+            None if context.is_roblox() => vec![
+                "wait".to_owned(),
+                "task.wait".to_owned(),
+                "RunService.Heartbeat:Wait".to_owned(),
+                "coroutine.yield".to_owned(),
+            ],
+            None => vec!["coroutine.yield".to_owned()],
+        };
+
+        let mut visitor = InfiniteLoopVisitor {
+            positions: Vec::new(),
+            yielding_functions,
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .positions
+            .iter()
+            .map(|position| {
+                Diagnostic::new_complete(
+                    "infinite_loop",
+                    "this loop never ends".to_owned(),
+                    Label::new(*position),
+                    vec![
+                        "note: the condition never changes, and nothing inside of the loop leaves it with `break`, `return`, or `error`".to_owned(),
+                        "help: if this loop is meant to run forever, add the function that it waits on to `yielding_functions`".to_owned(),
+                    ],
+                    Vec::new(),
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Correctness
+    }
+}
+
+pub(crate) fn is_symbol(expression: &ast::Expression, expected: Symbol) -> bool {
+    if let Some(ast::Value::Symbol(symbol)) = ExpressionTree::new(expression).value() {
+        *symbol.token_type() == TokenType::Symbol { symbol: expected }
+    } else {
+        false
+    }
+}
+
+struct InfiniteLoopVisitor {
+    positions: Vec<(usize, usize)>,
+    yielding_functions: Vec<String>,
+}
+
+impl InfiniteLoopVisitor {
+    fn check(&mut self, block: &ast::Block, position: (usize, usize)) {
+        let mut exit_visitor = ExitVisitor {
+            exits: false,
+            loops: LoopStack::new(),
+            yielding_functions: &self.yielding_functions,
+        };
+
+        block.visit(&mut exit_visitor);
+
+        if !exit_visitor.exits {
+            self.positions.push(position);
+        }
+    }
+}
+
+impl Visitor<'_> for InfiniteLoopVisitor {
+    fn visit_repeat(&mut self, repeat: &ast::Repeat) {
+        if is_symbol(repeat.until(), Symbol::False) || is_symbol(repeat.until(), Symbol::Nil) {
+            self.check(repeat.block(), range(repeat.repeat_token()));
+        }
+    }
+
+    fn visit_while(&mut self, while_loop: &ast::While) {
+        if is_symbol(while_loop.condition(), Symbol::True) {
+            self.check(while_loop.block(), range(while_loop.while_token()));
+        }
+    }
+}
+
+// Looks for anything that leaves the loop, or makes it wait
+struct ExitVisitor<'a> {
+    exits: bool,
+    // Nothing inside of functions runs as part of the loop, and `break` inside of another loop
+    // only leaves that loop
+    loops: LoopStack<()>,
+    yielding_functions: &'a [String],
+}
+
+impl ExitVisitor<'_> {
+    fn open_loop(&mut self, _: Loop) {
+        self.loops.open_loop(());
+    }
+
+    fn close_loop(&mut self) {
+        self.loops.close_loop();
+    }
+}
+
+impl Visitor<'_> for ExitVisitor<'_> {
+    fn visit_function_body(&mut self, _: &ast::FunctionBody) {
+        self.loops.open_function();
+    }
+
+    fn visit_function_body_end(&mut self, _: &ast::FunctionBody) {
+        self.loops.close_function();
+    }
+
+    fn visit_function_call(&mut self, call: &ast::FunctionCall) {
+        if self.loops.in_function() {
+            return;
+        }
+
+        if let Some(name) = call_name(call) {
+            if name == "error" || self.yielding_functions.contains(&name) {
+                self.exits = true;
+            }
+        }
+    }
+
+    fn visit_last_stmt(&mut self, last_stmt: &ast::LastStmt) {
+        if self.loops.in_function() {
+            return;
+        }
+
+        match last_stmt {
+            ast::LastStmt::Break(_) if !self.loops.in_loop() => self.exits = true,
+            ast::LastStmt::Return(_) => self.exits = true,
+            _ => {}
+        }
+    }
+
+    visit_loops!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_infinite_loop() {
+        test_lint(
+            InfiniteLoopLint::new(InfiniteLoopConfig::default()).unwrap(),
+            "infinite_loop",
+            "infinite_loop",
+        );
+    }
+
+    #[cfg(feature = "roblox")]
+    #[test]
+    fn test_roblox() {
+        test_lint(
+            InfiniteLoopLint::new(InfiniteLoopConfig::default()).unwrap(),
+            "infinite_loop",
+            "roblox",
+        );
+    }
+
+    #[test]
+    fn test_yielding_functions() {
+        test_lint(
+            InfiniteLoopLint::new(InfiniteLoopConfig {
+                yielding_functions: Some(vec!["socket:receive".to_owned()]),
+            })
+            .unwrap(),
+            "infinite_loop",
+            "yielding_functions",
+        );
+    }
+}
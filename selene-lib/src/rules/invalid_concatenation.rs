@@ -0,0 +1,145 @@
+use super::*;
+use crate::ast_util::{
+    expression_tree::{chained_expressions, ExpressionTree, StaticType},
+    literal_locals::LiteralLocals,
+    range,
+};
+use std::{collections::HashSet, convert::Infallible};
+
+use full_moon::{
+    ast::{self, Ast},
+    visitors::Visitor,
+};
+
+pub struct InvalidConcatenationLint;
+
+impl Rule for InvalidConcatenationLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(InvalidConcatenationLint)
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let mut visitor = InvalidConcatenationVisitor {
+            chained: HashSet::new(),
+            literal_locals: LiteralLocals::new(),
+            operands: Vec::new(),
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .operands
+            .iter()
+            .map(|(range, operand_type)| {
+                Diagnostic::new_complete(
+                    "invalid_concatenation",
+                    format!("{} can't be concatenated", operand_type.describe()),
+                    Label::new(*range),
+                    vec![
+                        "note: only strings and numbers can be concatenated, this will error when it is run".to_owned(),
+                        "help: if you meant to concatenate it as text, use `tostring`".to_owned(),
+                    ],
+                    Vec::new(),
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Correctness
+    }
+}
+
+struct InvalidConcatenationVisitor {
+    chained: HashSet<(usize, usize)>,
+    literal_locals: LiteralLocals,
+    operands: Vec<((usize, usize), StaticType)>,
+}
+
+impl InvalidConcatenationVisitor {
+    fn operand_type(&self, operand: &ExpressionTree) -> Option<StaticType> {
+        if let Some(literal_type) = operand.literal_type() {
+            return Some(literal_type);
+        }
+
+        match operand.value() {
+            Some(ast::Value::Var(ast::Var::Name(name))) => {
+                self.literal_locals.get(&name.to_string())
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Visitor<'_> for InvalidConcatenationVisitor {
+    fn visit_assignment(&mut self, assignment: &ast::Assignment) {
+        self.literal_locals.visit_assignment(assignment);
+    }
+
+    fn visit_block(&mut self, _: &ast::Block) {
+        self.literal_locals.visit_block();
+    }
+
+    fn visit_block_end(&mut self, _: &ast::Block) {
+        self.literal_locals.visit_block_end();
+    }
+
+    fn visit_expression(&mut self, expression: &ast::Expression) {
+        if self.chained.contains(&range(expression)) {
+            return;
+        }
+
+        self.chained.extend(chained_expressions(expression));
+
+        let mut operands = Vec::new();
+
+        ExpressionTree::new(expression).for_each_binary(&mut |lhs, op, rhs| {
+            if !matches!(op, ast::BinOp::TwoDots(_)) {
+                return;
+            }
+
+            for operand in &[lhs, rhs] {
+                if let Some(operand_type) = self.operand_type(operand) {
+                    if !matches!(operand_type, StaticType::Number | StaticType::String) {
+                        operands.push((operand.range(), operand_type));
+                    }
+                }
+            }
+        });
+
+        self.operands.extend(operands);
+    }
+
+    fn visit_local_assignment(&mut self, local_assignment: &ast::LocalAssignment) {
+        self.literal_locals.visit_local_assignment(local_assignment);
+    }
+
+    fn visit_local_assignment_end(&mut self, _: &ast::LocalAssignment) {
+        self.literal_locals.visit_local_assignment_end();
+    }
+
+    fn visit_local_function(&mut self, local_function: &ast::LocalFunction) {
+        self.literal_locals.visit_local_function(local_function);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_invalid_concatenation() {
+        test_lint(
+            InvalidConcatenationLint::new(()).unwrap(),
+            "invalid_concatenation",
+            "invalid_concatenation",
+        );
+    }
+}
@@ -0,0 +1,310 @@
+use super::*;
+use crate::ast_util::{expression_string_literal, range, scopes::ScopeManager};
+use std::{convert::Infallible, iter::Peekable, str::Chars};
+
+use full_moon::{
+    ast::{self, Ast},
+    node::Node,
+    visitors::Visitor,
+};
+
+pub struct InvalidGsubReplacementLint;
+
+impl Rule for InvalidGsubReplacementLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(InvalidGsubReplacementLint)
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let mut visitor = InvalidGsubReplacementVisitor {
+            problems: Vec::new(),
+            scope_manager: ScopeManager::new(ast),
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .problems
+            .into_iter()
+            .map(|problem| match problem.kind {
+                ProblemKind::MissingCapture { capture, captures } => Diagnostic::new_complete(
+                    "invalid_gsub_replacement",
+                    format!(
+                        "`%{}` refers to a capture that doesn't exist, and will error",
+                        capture
+                    ),
+                    Label::new(problem.replacement),
+                    Vec::new(),
+                    vec![Label::new_with_message(
+                        (problem.pattern.0 as u32, problem.pattern.1 as u32),
+                        format!(
+                            "this pattern has {} {}",
+                            captures,
+                            if captures == 1 { "capture" } else { "captures" }
+                        ),
+                    )],
+                ),
+
+                ProblemKind::TrailingPercent => Diagnostic::new_complete(
+                    "invalid_gsub_replacement",
+                    "replacement string ends with a `%`, and will error".to_owned(),
+                    Label::new(problem.replacement),
+                    vec!["help: use `%%` for a literal percent sign".to_owned()],
+                    Vec::new(),
+                ),
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Correctness
+    }
+}
+
+// Skips over a set such as `[%a_]`, starting after the `[`
+fn skip_set(chars: &mut Peekable<Chars>) -> Option<()> {
+    if chars.peek() == Some(&'^') {
+        chars.next();
+    }
+
+    // `[]]` is a set containing `]`
+    if chars.peek() == Some(&']') {
+        chars.next();
+    }
+
+    loop {
+        match chars.next()? {
+            '%' => {
+                chars.next()?;
+            }
+
+            ']' => return Some(()),
+            _ => {}
+        }
+    }
+}
+
+// Counts the captures in a pattern, including position captures like `()`.
+// Returns None if the pattern is not valid, as Lua will error for a different reason.
+fn count_captures(pattern: &str) -> Option<usize> {
+    let mut chars = pattern.chars().peekable();
+    let mut count = 0;
+    let mut open = 0;
+
+    while let Some(character) = chars.next() {
+        match character {
+            '%' => match chars.next()? {
+                // %bxy
+                'b' => {
+                    chars.next()?;
+                    chars.next()?;
+                }
+
+                // %f[set]
+                'f' => {
+                    if chars.next()? != '[' {
+                        return None;
+                    }
+
+                    skip_set(&mut chars)?;
+                }
+
+                _ => {}
+            },
+
+            '[' => skip_set(&mut chars)?,
+
+            '(' => {
+                count += 1;
+                open += 1;
+            }
+
+            ')' => {
+                if open == 0 {
+                    return None;
+                }
+
+                open -= 1;
+            }
+
+            _ => {}
+        }
+    }
+
+    if open == 0 {
+        Some(count)
+    } else {
+        None
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum ProblemKind {
+    MissingCapture { capture: u32, captures: usize },
+    TrailingPercent,
+}
+
+fn check_replacement(replacement: &str, captures: usize) -> Vec<ProblemKind> {
+    let mut chars = replacement.chars();
+    let mut problems = Vec::new();
+
+    while let Some(character) = chars.next() {
+        if character != '%' {
+            continue;
+        }
+
+        match chars.next() {
+            Some(next) => {
+                if let Some(capture) = next.to_digit(10) {
+                    // `%0` is the whole match, and so is `%1` when there are no captures
+                    if capture as usize > captures.max(1) {
+                        problems.push(ProblemKind::MissingCapture { capture, captures });
+                    }
+                }
+            }
+
+            None => problems.push(ProblemKind::TrailingPercent),
+        }
+    }
+
+    problems
+}
+
+struct Problem {
+    kind: ProblemKind,
+    pattern: (usize, usize),
+    replacement: (usize, usize),
+}
+
+struct InvalidGsubReplacementVisitor {
+    problems: Vec<Problem>,
+    scope_manager: ScopeManager,
+}
+
+impl InvalidGsubReplacementVisitor {
+    fn check<'a, 'ast>(&mut self, mut arguments: impl Iterator<Item = &'a ast::Expression<'ast>>)
+    where
+        'ast: 'a,
+    {
+        if_chain::if_chain! {
+            if let Some((pattern, pattern_token)) = arguments.next().and_then(expression_string_literal);
+            if let Some((replacement, replacement_token)) = arguments.next().and_then(expression_string_literal);
+            if let Some(captures) = count_captures(&pattern);
+            then {
+                for kind in check_replacement(&replacement, captures) {
+                    self.problems.push(Problem {
+                        kind,
+                        pattern: range(pattern_token),
+                        replacement: range(replacement_token),
+                    });
+                }
+            }
+        }
+    }
+}
+
+impl Visitor<'_> for InvalidGsubReplacementVisitor {
+    fn visit_function_call(&mut self, call: &ast::FunctionCall) {
+        let mut suffixes = call.iter_suffixes();
+
+        match (call.prefix(), suffixes.next()) {
+            // string.gsub(s, "(%w+)", "%1")
+            (
+                ast::Prefix::Name(name),
+                Some(ast::Suffix::Index(ast::Index::Dot { name: field, .. })),
+            ) if name.to_string() == "string" && field.to_string() == "gsub" => {
+                if let Some(reference) = self
+                    .scope_manager
+                    .reference_at_byte(call.start_position().unwrap().bytes())
+                {
+                    if reference.resolved.is_some() {
+                        return;
+                    }
+                }
+
+                if let Some(ast::Suffix::Call(ast::Call::AnonymousCall(
+                    ast::FunctionArgs::Parentheses { arguments, .. },
+                ))) = suffixes.next()
+                {
+                    self.check(arguments.iter().skip(1));
+                }
+            }
+
+            // s:gsub("(%w+)", "%1")
+            _ => {
+                for suffix in call.iter_suffixes() {
+                    if_chain::if_chain! {
+                        if let ast::Suffix::Call(ast::Call::MethodCall(method_call)) = suffix;
+                        if method_call.name().to_string() == "gsub";
+                        if let ast::FunctionArgs::Parentheses { arguments, .. } = method_call.args();
+                        then {
+                            self.check(arguments.iter());
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_count_captures() {
+        assert_eq!(count_captures("hello"), Some(0));
+        assert_eq!(count_captures("(%w+)=(%w+)"), Some(2));
+        assert_eq!(count_captures("((a)b)()"), Some(3));
+        assert_eq!(count_captures("%(not a capture%)"), Some(0));
+        assert_eq!(count_captures("[()]"), Some(0));
+        assert_eq!(count_captures("[]()]"), Some(0));
+        assert_eq!(count_captures("%b()(x)"), Some(1));
+        assert_eq!(count_captures("%f[%w](%w+)"), Some(1));
+        assert_eq!(count_captures("(a"), None);
+        assert_eq!(count_captures("a)"), None);
+        assert_eq!(count_captures("[a"), None);
+        assert_eq!(count_captures("%"), None);
+    }
+
+    #[test]
+    fn test_check_replacement() {
+        assert!(check_replacement("%0 %1 %2", 2).is_empty());
+        assert!(check_replacement("<%1>", 0).is_empty());
+        assert!(check_replacement("100%%", 0).is_empty());
+        assert_eq!(
+            check_replacement("%1 %3", 2),
+            vec![ProblemKind::MissingCapture {
+                capture: 3,
+                captures: 2
+            }]
+        );
+        assert_eq!(
+            check_replacement("%2", 0),
+            vec![ProblemKind::MissingCapture {
+                capture: 2,
+                captures: 0
+            }]
+        );
+        assert_eq!(
+            check_replacement("50%", 1),
+            vec![ProblemKind::TrailingPercent]
+        );
+    }
+
+    #[test]
+    fn test_invalid_gsub_replacement() {
+        test_lint(
+            InvalidGsubReplacementLint::new(()).unwrap(),
+            "invalid_gsub_replacement",
+            "invalid_gsub_replacement",
+        );
+    }
+}
@@ -0,0 +1,156 @@
+use super::*;
+use crate::ast_util::{
+    expression_tree::{chained_expressions, ExpressionTree, StaticType},
+    literal_locals::LiteralLocals,
+    range,
+};
+use std::{collections::HashSet, convert::Infallible};
+
+use full_moon::{
+    ast::{self, Ast},
+    visitors::Visitor,
+};
+
+pub struct InvalidLengthOperandLint;
+
+impl Rule for InvalidLengthOperandLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(InvalidLengthOperandLint)
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let mut visitor = InvalidLengthOperandVisitor {
+            chained: HashSet::new(),
+            lengths: Vec::new(),
+            literal_locals: LiteralLocals::new(),
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .lengths
+            .iter()
+            .map(|(range, operand_type)| {
+                Diagnostic::new_complete(
+                    "invalid_length_operand",
+                    format!(
+                        "the length operator requires a string or table, but was given {}",
+                        operand_type.describe()
+                    ),
+                    Label::new(*range),
+                    vec!["note: this will error when it is run".to_owned()],
+                    Vec::new(),
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Correctness
+    }
+}
+
+fn is_invalid(operand_type: StaticType) -> bool {
+    !matches!(operand_type, StaticType::String | StaticType::Table)
+}
+
+struct InvalidLengthOperandVisitor {
+    chained: HashSet<(usize, usize)>,
+    lengths: Vec<((usize, usize), StaticType)>,
+    literal_locals: LiteralLocals,
+}
+
+impl InvalidLengthOperandVisitor {
+    fn operand_type(&self, operand: &ExpressionTree) -> Option<StaticType> {
+        if let Some(literal_type) = operand.literal_type() {
+            return Some(literal_type);
+        }
+
+        match operand.value() {
+            Some(ast::Value::Var(ast::Var::Name(name))) => {
+                self.literal_locals.get(&name.to_string())
+            }
+            _ => None,
+        }
+    }
+
+    fn check_tree(&mut self, tree: &ExpressionTree) {
+        match tree {
+            ExpressionTree::Binary { lhs, rhs, .. } => {
+                self.check_tree(lhs);
+                self.check_tree(rhs);
+            }
+
+            ExpressionTree::Unary { op, operand } => {
+                if let ast::UnOp::Hash(_) = op {
+                    if let Some(operand_type) = self.operand_type(operand) {
+                        if is_invalid(operand_type) {
+                            self.lengths.push((tree.range(), operand_type));
+                        }
+                    }
+                }
+
+                self.check_tree(operand);
+            }
+
+            // Expressions inside of parentheses are visited on their own
+            ExpressionTree::Parentheses(_) | ExpressionTree::Value(_) => {}
+        }
+    }
+}
+
+impl Visitor<'_> for InvalidLengthOperandVisitor {
+    fn visit_assignment(&mut self, assignment: &ast::Assignment) {
+        self.literal_locals.visit_assignment(assignment);
+    }
+
+    fn visit_block(&mut self, _: &ast::Block) {
+        self.literal_locals.visit_block();
+    }
+
+    fn visit_block_end(&mut self, _: &ast::Block) {
+        self.literal_locals.visit_block_end();
+    }
+
+    fn visit_expression(&mut self, expression: &ast::Expression) {
+        if self.chained.contains(&range(expression)) {
+            return;
+        }
+
+        self.chained.extend(chained_expressions(expression));
+        self.check_tree(&ExpressionTree::new(expression));
+    }
+
+    fn visit_local_assignment(&mut self, local_assignment: &ast::LocalAssignment) {
+        self.literal_locals.visit_local_assignment(local_assignment);
+    }
+
+    fn visit_local_assignment_end(&mut self, _: &ast::LocalAssignment) {
+        self.literal_locals.visit_local_assignment_end();
+    }
+
+    fn visit_local_function(&mut self, local_function: &ast::LocalFunction) {
+        self.literal_locals.visit_local_function(local_function);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_invalid_length_operand() {
+        test_lint(
+            InvalidLengthOperandLint::new(()).unwrap(),
+            "invalid_length_operand",
+            "invalid_length_operand",
+        );
+    }
+}
@@ -0,0 +1,237 @@
+use super::*;
+use crate::ast_util::{expression_string_literal, range, scopes::ScopeManager, string_literal};
+use std::convert::Infallible;
+
+use full_moon::{
+    ast::{self, Ast},
+    node::Node,
+    visitors::Visitor,
+};
+use serde::Deserialize;
+
+// The specifiers from C89, which every platform supports
+const PORTABLE_SPECIFIERS: &str = "aAbBcdHIjmMpSUwWxXyYZ%";
+
+// Added by C99 and POSIX, which not every platform supports
+const NONPORTABLE_SPECIFIERS: &str = "CDeFgGhnrRtTuVz";
+
+#[derive(Clone, Copy, Default, Deserialize)]
+#[serde(default)]
+pub struct InvalidOsDateFormatConfig {
+    allow_nonportable: bool,
+}
+
+pub struct InvalidOsDateFormatLint {
+    config: InvalidOsDateFormatConfig,
+}
+
+impl Rule for InvalidOsDateFormatLint {
+    type Config = InvalidOsDateFormatConfig;
+    type Error = Infallible;
+
+    fn new(config: Self::Config) -> Result<Self, Self::Error> {
+        Ok(InvalidOsDateFormatLint { config })
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let mut visitor = InvalidOsDateFormatVisitor {
+            formats: Vec::new(),
+            scope_manager: ScopeManager::new(ast),
+        };
+
+        visitor.visit_ast(ast);
+
+        let mut diagnostics = Vec::new();
+
+        for (format, range) in visitor.formats {
+            for problem in self.problems(&format) {
+                diagnostics.push(match problem {
+                    Problem::TrailingPercent => Diagnostic::new(
+                        "invalid_os_date_format",
+                        "this format string ends with a lone `%`".to_owned(),
+                        Label::new(range),
+                    ),
+
+                    Problem::UnknownSpecifier(specifier) => Diagnostic::new_complete(
+                        "invalid_os_date_format",
+                        format!("`{}` is not a valid os.date specifier", specifier),
+                        Label::new(range),
+                        if !self.config.allow_nonportable && is_nonportable(&specifier) {
+                            vec![
+                                "note: this specifier is not supported on every platform, set `allow_nonportable` to allow it".to_owned(),
+                            ]
+                        } else {
+                            Vec::new()
+                        },
+                        Vec::new(),
+                    ),
+
+                    Problem::TableWithText => Diagnostic::new_complete(
+                        "invalid_os_date_format",
+                        "`*t` can't be combined with other text".to_owned(),
+                        Label::new(range),
+                        vec![
+                            "note: os.date only returns a table when the format is exactly `*t` or `!*t`".to_owned(),
+                        ],
+                        Vec::new(),
+                    ),
+                });
+            }
+        }
+
+        diagnostics
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Correctness
+    }
+}
+
+enum Problem {
+    TrailingPercent,
+    // The full specifier, such as `%Q`
+    UnknownSpecifier(String),
+    TableWithText,
+}
+
+fn is_nonportable(specifier: &str) -> bool {
+    let mut chars = specifier.chars().skip(1);
+
+    match chars.next() {
+        Some('E') | Some('O') => true,
+        Some(conversion) => NONPORTABLE_SPECIFIERS.contains(conversion),
+        None => false,
+    }
+}
+
+impl InvalidOsDateFormatLint {
+    fn problems(&self, format: &str) -> Vec<Problem> {
+        // A leading `!` formats in UTC
+        let format = format.strip_prefix('!').unwrap_or(format);
+
+        if let Some(rest) = format.strip_prefix("*t") {
+            return if rest.is_empty() {
+                Vec::new()
+            } else {
+                vec![Problem::TableWithText]
+            };
+        }
+
+        let is_valid = |conversion: char| {
+            PORTABLE_SPECIFIERS.contains(conversion)
+                || (self.config.allow_nonportable && NONPORTABLE_SPECIFIERS.contains(conversion))
+        };
+
+        let mut problems = Vec::new();
+        let mut chars = format.chars();
+
+        while let Some(character) = chars.next() {
+            if character != '%' {
+                continue;
+            }
+
+            let conversion = match chars.next() {
+                Some(conversion) => conversion,
+                None => {
+                    problems.push(Problem::TrailingPercent);
+                    break;
+                }
+            };
+
+            // `%E` and `%O` modify the specifier after them, such as `%Ec`
+            if self.config.allow_nonportable && (conversion == 'E' || conversion == 'O') {
+                match chars.next() {
+                    Some(modified) if is_valid(modified) => {}
+                    Some(modified) => problems.push(Problem::UnknownSpecifier(format!(
+                        "%{}{}",
+                        conversion, modified
+                    ))),
+                    None => problems.push(Problem::TrailingPercent),
+                }
+
+                continue;
+            }
+
+            if !is_valid(conversion) {
+                problems.push(Problem::UnknownSpecifier(format!("%{}", conversion)));
+            }
+        }
+
+        problems
+    }
+}
+
+struct InvalidOsDateFormatVisitor {
+    formats: Vec<(String, (usize, usize))>,
+    scope_manager: ScopeManager,
+}
+
+impl Visitor<'_> for InvalidOsDateFormatVisitor {
+    fn visit_function_call(&mut self, call: &ast::FunctionCall) {
+        if_chain::if_chain! {
+            if let ast::Prefix::Name(name) = call.prefix();
+            if name.to_string() == "os";
+            let mut suffixes = call.iter_suffixes();
+            if let Some(ast::Suffix::Index(ast::Index::Dot { name: field, .. })) = suffixes.next();
+            if field.to_string() == "date";
+            if let Some(ast::Suffix::Call(ast::Call::AnonymousCall(arguments))) = suffixes.next();
+            then {
+                if let Some(reference) = self
+                    .scope_manager
+                    .reference_at_byte(call.start_position().unwrap().bytes())
+                {
+                    if reference.resolved.is_some() {
+                        return;
+                    }
+                }
+
+                let format = match arguments {
+                    ast::FunctionArgs::Parentheses { arguments, .. } => arguments
+                        .iter()
+                        .next()
+                        .and_then(expression_string_literal)
+                        .map(|(literal, token)| (literal, range(token))),
+
+                    ast::FunctionArgs::String(token) => {
+                        string_literal(token).map(|literal| (literal, range(token)))
+                    }
+                    _ => None,
+                };
+
+                if let Some(format) = format {
+                    self.formats.push(format);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_invalid_os_date_format() {
+        test_lint(
+            InvalidOsDateFormatLint::new(InvalidOsDateFormatConfig::default()).unwrap(),
+            "invalid_os_date_format",
+            "invalid_os_date_format",
+        );
+    }
+
+    #[test]
+    fn test_allow_nonportable() {
+        test_lint(
+            InvalidOsDateFormatLint::new(InvalidOsDateFormatConfig {
+                allow_nonportable: true,
+            })
+            .unwrap(),
+            "invalid_os_date_format",
+            "allow_nonportable",
+        );
+    }
+}
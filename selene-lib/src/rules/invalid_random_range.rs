@@ -0,0 +1,261 @@
+use super::*;
+use crate::ast_util::{number_literal, range, scopes::ScopeManager};
+use std::{collections::HashSet, convert::Infallible};
+
+use full_moon::{
+    ast::{self, Ast},
+    node::Node,
+    visitors::Visitor,
+};
+use serde::Deserialize;
+
+#[derive(Clone, Copy, Default, Deserialize)]
+#[serde(default)]
+pub struct InvalidRandomRangeConfig {
+    require_integers: bool,
+}
+
+pub struct InvalidRandomRangeLint {
+    config: InvalidRandomRangeConfig,
+}
+
+impl Rule for InvalidRandomRangeLint {
+    type Config = InvalidRandomRangeConfig;
+    type Error = Infallible;
+
+    fn new(config: Self::Config) -> Result<Self, Self::Error> {
+        Ok(InvalidRandomRangeLint { config })
+    }
+
+    fn pass(&self, ast: &Ast, context: &Context) -> Vec<Diagnostic> {
+        let mut visitor = InvalidRandomRangeVisitor {
+            config: self.config,
+            invalid_calls: Vec::new(),
+            random_locals: HashSet::new(),
+            roblox: context.is_roblox(),
+            scope_manager: ScopeManager::new(ast),
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .invalid_calls
+            .iter()
+            .map(|invalid_call| {
+                let message = match invalid_call.problem {
+                    Problem::Empty => format!(
+                        "`{}` is given an empty range, and will error",
+                        invalid_call.function
+                    ),
+                    Problem::NotInteger => format!(
+                        "`{}` requires integers, and will error",
+                        invalid_call.function
+                    ),
+                };
+
+                Diagnostic::new(
+                    "invalid_random_range",
+                    message,
+                    Label::new(invalid_call.range),
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Correctness
+    }
+}
+
+enum Problem {
+    Empty,
+    NotInteger,
+}
+
+struct InvalidCall {
+    function: &'static str,
+    problem: Problem,
+    range: (usize, usize),
+}
+
+struct InvalidRandomRangeVisitor {
+    config: InvalidRandomRangeConfig,
+    invalid_calls: Vec<InvalidCall>,
+    // Names of locals assigned `Random.new()`
+    random_locals: HashSet<String>,
+    roblox: bool,
+    scope_manager: ScopeManager,
+}
+
+impl InvalidRandomRangeVisitor {
+    fn is_global(&self, token: &impl Node) -> bool {
+        match self
+            .scope_manager
+            .reference_at_byte(token.start_position().unwrap().bytes())
+        {
+            Some(reference) => reference.resolved.is_none(),
+            None => true,
+        }
+    }
+
+    // `lower_bound` is given when the function can be called with only an upper bound, such as `math.random(n)`
+    fn check(
+        &mut self,
+        function: &'static str,
+        arguments: &ast::FunctionArgs,
+        lower_bound: Option<f64>,
+        call: &ast::FunctionCall,
+    ) {
+        let arguments = match arguments {
+            ast::FunctionArgs::Parentheses { arguments, .. } => arguments,
+            _ => return,
+        };
+
+        // Anything that isn't a literal can't be checked
+        let numbers = match arguments
+            .iter()
+            .map(number_literal)
+            .collect::<Option<Vec<_>>>()
+        {
+            Some(numbers) => numbers,
+            None => return,
+        };
+
+        let (low, high) = match (numbers.as_slice(), lower_bound) {
+            ([high], Some(low)) => (low, *high),
+            ([low, high], _) => (*low, *high),
+            _ => return,
+        };
+
+        let problem =
+            if self.config.require_integers && numbers.iter().any(|number| number.fract() != 0.0) {
+                Problem::NotInteger
+            // Bounds are truncated to integers before they are compared
+            } else if low.trunc() > high.trunc() {
+                Problem::Empty
+            } else {
+                return;
+            };
+
+        self.invalid_calls.push(InvalidCall {
+            function,
+            problem,
+            range: range(call),
+        });
+    }
+}
+
+fn is_random_new(expression: &ast::Expression) -> bool {
+    if_chain::if_chain! {
+        if let ast::Expression::Value { value, binop: None } = expression;
+        if let ast::Value::FunctionCall(call) = &**value;
+        if let ast::Prefix::Name(name) = call.prefix();
+        if name.to_string() == "Random";
+        let mut suffixes = call.iter_suffixes();
+        if let Some(ast::Suffix::Index(ast::Index::Dot { name, .. })) = suffixes.next();
+        if name.to_string() == "new";
+        if let Some(ast::Suffix::Call(ast::Call::AnonymousCall(_))) = suffixes.next();
+        if suffixes.next().is_none();
+        then {
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl Visitor<'_> for InvalidRandomRangeVisitor {
+    fn visit_function_call(&mut self, call: &ast::FunctionCall) {
+        let suffixes = call.iter_suffixes().collect::<Vec<_>>();
+
+        match (call.prefix(), suffixes.as_slice()) {
+            // math.random(10, 1)
+            (
+                ast::Prefix::Name(name),
+                [ast::Suffix::Index(ast::Index::Dot { name: field, .. }), ast::Suffix::Call(ast::Call::AnonymousCall(arguments))],
+            ) if name.to_string() == "math"
+                && field.to_string() == "random"
+                && self.is_global(name) =>
+            {
+                self.check("math.random", arguments, Some(1.0), call);
+            }
+
+            // rng:NextInteger(10, 1)
+            (ast::Prefix::Name(name), [ast::Suffix::Call(ast::Call::MethodCall(method_call))])
+                if self.roblox
+                    && self.random_locals.contains(&name.to_string())
+                    && method_call.name().to_string() == "NextInteger" =>
+            {
+                self.check("NextInteger", method_call.args(), None, call);
+            }
+
+            // Random.new():NextInteger(10, 1)
+            (
+                ast::Prefix::Name(name),
+                [ast::Suffix::Index(ast::Index::Dot { name: field, .. }), ast::Suffix::Call(ast::Call::AnonymousCall(_)), ast::Suffix::Call(ast::Call::MethodCall(method_call))],
+            ) if self.roblox
+                && name.to_string() == "Random"
+                && field.to_string() == "new"
+                && method_call.name().to_string() == "NextInteger" =>
+            {
+                self.check("NextInteger", method_call.args(), None, call);
+            }
+
+            _ => {}
+        }
+    }
+
+    fn visit_local_assignment(&mut self, local_assignment: &ast::LocalAssignment) {
+        for (name, expression) in local_assignment
+            .name_list()
+            .iter()
+            .zip(local_assignment.expr_list().iter())
+        {
+            if is_random_new(expression) {
+                self.random_locals.insert(name.to_string());
+            } else {
+                self.random_locals.remove(&name.to_string());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_invalid_random_range() {
+        test_lint(
+            InvalidRandomRangeLint::new(InvalidRandomRangeConfig::default()).unwrap(),
+            "invalid_random_range",
+            "invalid_random_range",
+        );
+    }
+
+    #[test]
+    fn test_require_integers() {
+        test_lint(
+            InvalidRandomRangeLint::new(InvalidRandomRangeConfig {
+                require_integers: true,
+            })
+            .unwrap(),
+            "invalid_random_range",
+            "require_integers",
+        );
+    }
+
+    #[cfg(feature = "roblox")]
+    #[test]
+    fn test_roblox() {
+        test_lint(
+            InvalidRandomRangeLint::new(InvalidRandomRangeConfig::default()).unwrap(),
+            "invalid_random_range",
+            "roblox",
+        );
+    }
+}
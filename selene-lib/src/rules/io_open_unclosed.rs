@@ -0,0 +1,261 @@
+use super::*;
+use crate::ast_util::{range, scopes::ScopeManager};
+use std::{collections::HashSet, convert::Infallible};
+
+use full_moon::{
+    ast::{self, Ast},
+    tokenizer::TokenReference,
+    visitors::Visitor,
+};
+
+pub struct IoOpenUnclosedLint;
+
+impl Rule for IoOpenUnclosedLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(IoOpenUnclosedLint)
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let scope_manager = ScopeManager::new(ast);
+
+        let mut visitor = IoOpenUnclosedVisitor {
+            closes: HashSet::new(),
+            opens: Vec::new(),
+            other_uses: HashSet::new(),
+            scope_manager: &scope_manager,
+        };
+
+        visitor.visit_ast(ast);
+
+        let mut diagnostics = Vec::new();
+
+        for (name, name_range) in &visitor.opens {
+            let variable = match scope_manager
+                .variables
+                .iter()
+                .find(|(_, variable)| variable.identifiers.contains(name_range))
+            {
+                Some((_, variable)) => variable,
+                None => continue,
+            };
+
+            // The file is fine if it's closed, or if it's used in a way we can't follow, such as
+            // being returned or passed to another function that might close it
+            let handled = variable
+                .references
+                .iter()
+                .map(|reference| &scope_manager.references[*reference])
+                .filter(|reference| reference.read)
+                .any(|reference| {
+                    visitor.closes.contains(&reference.identifier.0)
+                        || !visitor.other_uses.contains(&reference.identifier.0)
+                });
+
+            if !handled {
+                diagnostics.push(Diagnostic::new_complete(
+                    "io_open_unclosed",
+                    format!("`{}` is opened, but never closed", name),
+                    Label::new(*name_range),
+                    vec![format!(
+                        "help: call `{}:close()` once you are done with it",
+                        name
+                    )],
+                    Vec::new(),
+                ));
+            }
+        }
+
+        diagnostics
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Correctness
+    }
+}
+
+// The name of a call like `io.open(path)`, alongside its arguments
+fn call<'a, 'ast>(
+    expression: &'a ast::Expression<'ast>,
+) -> Option<(String, Vec<&'a ast::Expression<'ast>>)> {
+    let call = match expression {
+        ast::Expression::Value { value, binop: None } => match &**value {
+            ast::Value::FunctionCall(call) => call,
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    let mut name = match call.prefix() {
+        ast::Prefix::Name(name) => name.to_string(),
+        _ => return None,
+    };
+
+    let suffixes = call.iter_suffixes().collect::<Vec<_>>();
+
+    for suffix in &suffixes[..suffixes.len() - 1] {
+        match suffix {
+            ast::Suffix::Index(ast::Index::Dot { name: field, .. }) => {
+                name.push('.');
+                name.push_str(&field.to_string());
+            }
+            _ => return None,
+        }
+    }
+
+    match suffixes.last() {
+        Some(ast::Suffix::Call(ast::Call::AnonymousCall(ast::FunctionArgs::Parentheses {
+            arguments,
+            ..
+        }))) => Some((name, arguments.iter().collect())),
+        _ => None,
+    }
+}
+
+fn is_name(expression: &ast::Expression, expected: &str) -> bool {
+    if let ast::Expression::Value { value, binop: None } = expression {
+        if let ast::Value::Var(ast::Var::Expression(var_expression)) = &**value {
+            let mut names = Vec::new();
+
+            if let ast::Prefix::Name(name) = var_expression.prefix() {
+                names.push(name.to_string());
+            }
+
+            for suffix in var_expression.iter_suffixes() {
+                if let ast::Suffix::Index(ast::Index::Dot { name, .. }) = suffix {
+                    names.push(name.to_string());
+                } else {
+                    return false;
+                }
+            }
+
+            return names.join(".") == expected;
+        }
+    }
+
+    false
+}
+
+// Which of the names the file handle from `expression` is assigned to, if any.
+// `io.open(path)` and `assert(io.open(path))` give it to the first name, while
+// `pcall(io.open, path)` gives it to the second.
+fn file_index(expression: &ast::Expression) -> Option<usize> {
+    let (name, arguments) = call(expression)?;
+
+    match name.as_str() {
+        "io.open" => Some(0),
+        "assert" => match arguments.first() {
+            Some(argument) if file_index(argument) == Some(0) => Some(0),
+            _ => None,
+        },
+        "pcall" => match arguments.first() {
+            Some(argument) if is_name(argument, "io.open") => Some(1),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+struct IoOpenUnclosedVisitor<'a> {
+    // Where a local is closed, with either `file:close()` or `io.close(file)`
+    closes: HashSet<usize>,
+    opens: Vec<(String, (usize, usize))>,
+    // Where a local is used without letting it escape, like `file:read()` or `file.x`
+    other_uses: HashSet<usize>,
+    scope_manager: &'a ScopeManager,
+}
+
+impl IoOpenUnclosedVisitor<'_> {
+    fn is_global(&self, token: &TokenReference) -> bool {
+        match self.scope_manager.reference_at_byte(range(token).0) {
+            Some(reference) => reference.resolved.is_none(),
+            None => true,
+        }
+    }
+}
+
+impl Visitor<'_> for IoOpenUnclosedVisitor<'_> {
+    fn visit_function_call(&mut self, call: &ast::FunctionCall) {
+        let name = match call.prefix() {
+            ast::Prefix::Name(name) => name,
+            _ => return,
+        };
+
+        match call.iter_suffixes().next() {
+            Some(ast::Suffix::Call(ast::Call::MethodCall(method_call))) => {
+                if method_call.name().to_string() == "close" {
+                    self.closes.insert(range(name).0);
+                } else {
+                    self.other_uses.insert(range(name).0);
+                }
+            }
+
+            Some(ast::Suffix::Index(_)) => {
+                self.other_uses.insert(range(name).0);
+            }
+
+            _ => {}
+        }
+
+        // io.close(file)
+        if_chain::if_chain! {
+            if name.to_string() == "io" && self.is_global(name);
+            let suffixes = call.iter_suffixes().collect::<Vec<_>>();
+            if suffixes.len() == 2;
+            if let ast::Suffix::Index(ast::Index::Dot { name: field, .. }) = suffixes[0];
+            if field.to_string() == "close";
+            if let ast::Suffix::Call(ast::Call::AnonymousCall(ast::FunctionArgs::Parentheses {
+                arguments,
+                ..
+            })) = suffixes[1];
+            if let Some(ast::Expression::Value { value, binop: None }) = arguments.iter().next();
+            if let ast::Value::Var(ast::Var::Name(file)) = &**value;
+            then {
+                self.closes.insert(range(file).0);
+            }
+        }
+    }
+
+    fn visit_local_assignment(&mut self, local_assignment: &ast::LocalAssignment) {
+        let names = local_assignment.name_list().iter().collect::<Vec<_>>();
+        let expressions = local_assignment.expr_list().iter().collect::<Vec<_>>();
+
+        // Only the last expression can give more than one value
+        for (index, expression) in expressions.iter().enumerate() {
+            let file_index = match file_index(expression) {
+                Some(file_index) if file_index == 0 || index + 1 == expressions.len() => file_index,
+                _ => continue,
+            };
+
+            if let Some(name) = names.get(index + file_index) {
+                self.opens.push((name.to_string(), range(*name)));
+            }
+        }
+    }
+
+    fn visit_var_expression(&mut self, var_expression: &ast::VarExpression) {
+        if let ast::Prefix::Name(name) = var_expression.prefix() {
+            self.other_uses.insert(range(name).0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_io_open_unclosed() {
+        test_lint(
+            IoOpenUnclosedLint::new(()).unwrap(),
+            "io_open_unclosed",
+            "io_open_unclosed",
+        );
+    }
+}
@@ -0,0 +1,43 @@
+use super::*;
+use std::convert::Infallible;
+
+use full_moon::ast::Ast;
+
+use super::os_execute_security::shell_diagnostics;
+
+pub struct IoPopenSecurityLint;
+
+impl Rule for IoPopenSecurityLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(IoPopenSecurityLint)
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        shell_diagnostics("io_popen_security", "io.popen", ast)
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Correctness
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_io_popen_security() {
+        test_lint(
+            IoPopenSecurityLint::new(()).unwrap(),
+            "io_popen_security",
+            "io_popen_security",
+        );
+    }
+}
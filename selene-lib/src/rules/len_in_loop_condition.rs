@@ -0,0 +1,210 @@
+use super::*;
+use crate::ast_util::{
+    expression_tree::ExpressionTree,
+    loops::{visit_loops, Loop},
+    range,
+};
+use std::convert::Infallible;
+
+use full_moon::{
+    ast::{self, Ast},
+    node::Node,
+    visitors::Visitor,
+};
+
+pub struct LenInLoopConditionLint;
+
+impl Rule for LenInLoopConditionLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(LenInLoopConditionLint)
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let mut visitor = LenInLoopConditionVisitor {
+            ast_as_string: full_moon::print(ast),
+            lengths: Vec::new(),
+            loops: Vec::new(),
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .lengths
+            .iter()
+            .map(|length| {
+                let mut notes = Vec::new();
+                let mut secondary_labels = Vec::new();
+
+                // Storing the length of a table that changes, such as in
+                // `while #queue > 0 do table.remove(queue, 1) end`, would change what the loop does
+                if let Some(modification) = length.modification {
+                    notes.push(format!(
+                        "note: `{}` is modified inside of the loop, so its length can change between iterations",
+                        length.table
+                    ));
+
+                    secondary_labels.push(Label::new_with_message(
+                        (modification.0 as u32, modification.1 as u32),
+                        format!("`{}` is modified here", length.table),
+                    ));
+                } else {
+                    notes.push("help: store the length in a local variable before the loop".to_owned());
+                }
+
+                Diagnostic::new_complete(
+                    "len_in_loop_condition",
+                    format!(
+                        "`#{}` is evaluated again on every iteration of this loop",
+                        length.table
+                    ),
+                    Label::new(length.range),
+                    notes,
+                    secondary_labels,
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Performance
+    }
+}
+
+struct Length {
+    table: String,
+    range: (usize, usize),
+    modification: Option<(usize, usize)>,
+}
+
+struct LenInLoopConditionVisitor {
+    // Compared by source text, see almost_swapped
+    ast_as_string: String,
+    lengths: Vec<Length>,
+    loops: Vec<Vec<Length>>,
+}
+
+impl LenInLoopConditionVisitor {
+    fn text(&self, range: (usize, usize)) -> &str {
+        &self.ast_as_string[range.0..range.1]
+    }
+
+    fn find_lengths(&self, tree: &ExpressionTree, lengths: &mut Vec<Length>) {
+        match tree {
+            ExpressionTree::Binary { lhs, rhs, .. } => {
+                self.find_lengths(lhs, lengths);
+                self.find_lengths(rhs, lengths);
+            }
+
+            ExpressionTree::Unary { op, operand } => {
+                if let ast::UnOp::Hash(_) = op {
+                    lengths.push(Length {
+                        table: self.text(operand.range()).to_owned(),
+                        range: tree.range(),
+                        modification: None,
+                    });
+                }
+
+                self.find_lengths(operand, lengths);
+            }
+
+            ExpressionTree::Parentheses(ast::Expression::Parentheses { expression, .. }) => {
+                self.find_lengths(&ExpressionTree::new(expression), lengths);
+            }
+
+            ExpressionTree::Value(ast::Value::ParseExpression(expression)) => {
+                self.find_lengths(&ExpressionTree::new(expression), lengths);
+            }
+
+            _ => {}
+        }
+    }
+
+    fn open_loop(&mut self, node: Loop) {
+        let mut lengths = Vec::new();
+
+        if let Some(condition) = node.condition() {
+            self.find_lengths(&ExpressionTree::new(condition), &mut lengths);
+        }
+
+        self.loops.push(lengths);
+    }
+
+    fn close_loop(&mut self) {
+        let lengths = self.loops.pop().unwrap();
+        self.lengths.extend(lengths);
+    }
+
+    fn modify(&mut self, table: (usize, usize), modification: (usize, usize)) {
+        let table = self.text(table).to_owned();
+
+        for length in self.loops.iter_mut().flatten() {
+            if length.modification.is_none() && length.table == table {
+                length.modification = Some(modification);
+            }
+        }
+    }
+}
+
+impl Visitor<'_> for LenInLoopConditionVisitor {
+    fn visit_assignment(&mut self, assignment: &ast::Assignment) {
+        for var in assignment.var_list() {
+            // `t = {}` replaces the table entirely
+            self.modify(range(var), range(var));
+
+            // `t[index] = value` and `t.field = value` add to the table
+            if let ast::Var::Expression(var_expr) = var {
+                let suffixes = var_expr.iter_suffixes().collect::<Vec<_>>();
+                let table_end = match suffixes.len() {
+                    0 => continue,
+                    1 => var_expr.prefix().end_position(),
+                    count => suffixes[count - 2].end_position(),
+                };
+
+                if let Some(table_end) = table_end {
+                    self.modify((range::<_, usize>(var).0, table_end.bytes()), range(var));
+                }
+            }
+        }
+    }
+
+    fn visit_function_call(&mut self, call: &ast::FunctionCall) {
+        let mut suffixes = call.iter_suffixes();
+
+        if_chain::if_chain! {
+            if let ast::Prefix::Name(name) = call.prefix();
+            if name.to_string() == "table";
+            if let Some(ast::Suffix::Index(ast::Index::Dot { name, .. })) = suffixes.next();
+            if ["insert", "remove"].contains(&name.to_string().as_str());
+            if let Some(ast::Suffix::Call(ast::Call::AnonymousCall(
+                ast::FunctionArgs::Parentheses { arguments, .. }
+            ))) = suffixes.next();
+            if let Some(table) = arguments.iter().next();
+            then {
+                self.modify(range(table), range(call));
+            }
+        }
+    }
+
+    visit_loops!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_len_in_loop_condition() {
+        test_lint(
+            LenInLoopConditionLint::new(()).unwrap(),
+            "len_in_loop_condition",
+            "len_in_loop_condition",
+        );
+    }
+}
@@ -0,0 +1,245 @@
+use super::*;
+use crate::ast_util::range;
+use std::convert::Infallible;
+
+use full_moon::{ast::Ast, tokenizer::TokenReference, visitors::Visitor};
+use serde::Deserialize;
+
+#[derive(Clone, Copy, Deserialize)]
+#[serde(default)]
+pub struct LineLengthConfig {
+    max_length: usize,
+    max_comment_length: Option<usize>,
+    count_bytes: bool,
+    ignore_strings: bool,
+}
+
+impl Default for LineLengthConfig {
+    fn default() -> Self {
+        Self {
+            max_length: 120,
+            max_comment_length: None,
+            count_bytes: false,
+            ignore_strings: false,
+        }
+    }
+}
+
+pub struct LineLengthLint {
+    config: LineLengthConfig,
+}
+
+impl Rule for LineLengthLint {
+    type Config = LineLengthConfig;
+    type Error = Infallible;
+
+    fn new(config: Self::Config) -> Result<Self, Self::Error> {
+        Ok(LineLengthLint { config })
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let source = full_moon::print(ast);
+
+        let mut visitor = LineLengthVisitor {
+            comments: Vec::new(),
+            ignored: Vec::new(),
+        };
+
+        visitor.visit_ast(ast);
+
+        if !self.config.ignore_strings {
+            visitor.ignored.clear();
+        }
+
+        let unit = if self.config.count_bytes {
+            "bytes"
+        } else {
+            "characters"
+        };
+
+        let mut diagnostics = Vec::new();
+        let mut line_start = 0;
+
+        for line in source.split('\n') {
+            let start = line_start;
+            line_start += line.len() + 1;
+
+            let line = line.trim_end_matches('\r');
+
+            let max_length = match self.config.max_comment_length {
+                Some(max_comment_length) if visitor.is_only_comment(start, line) => {
+                    max_comment_length
+                }
+                _ => self.config.max_length,
+            };
+
+            // Measured in characters rather than bytes by default, so that non-ASCII text isn't penalized
+            let (length, overflow_start) = if self.config.count_bytes {
+                (
+                    line.len(),
+                    // The start of the character that goes over, as the maximum can be in the middle of one
+                    line.char_indices()
+                        .take_while(|(index, _)| *index <= max_length)
+                        .last()
+                        .map(|(index, _)| index),
+                )
+            } else {
+                (
+                    line.chars().count(),
+                    line.char_indices().nth(max_length).map(|(index, _)| index),
+                )
+            };
+
+            if length <= max_length {
+                continue;
+            }
+
+            let overflow = (start + overflow_start.unwrap(), start + line.len());
+
+            if visitor
+                .ignored
+                .iter()
+                .any(|ignored| ignored.0 <= overflow.0 && overflow.1 <= ignored.1)
+            {
+                continue;
+            }
+
+            diagnostics.push(Diagnostic::new(
+                "line_length",
+                format!(
+                    "this line is {} {} long, but the maximum allowed is {}",
+                    length, unit, max_length,
+                ),
+                Label::new(overflow),
+            ));
+        }
+
+        diagnostics
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Style
+    }
+}
+
+struct LineLengthVisitor {
+    comments: Vec<(usize, usize)>,
+    // Ranges of strings and URLs in comments, which can't be split up
+    ignored: Vec<(usize, usize)>,
+}
+
+impl LineLengthVisitor {
+    // Whether everything on a line, besides whitespace, is inside of one comment
+    fn is_only_comment(&self, start: usize, line: &str) -> bool {
+        let trimmed = line.trim_start();
+
+        if trimmed.is_empty() {
+            return false;
+        }
+
+        let text_start = start + line.len() - trimmed.len();
+        let text_end = start + line.trim_end().len();
+
+        self.comments
+            .iter()
+            .any(|comment| comment.0 <= text_start && text_end <= comment.1)
+    }
+
+    fn visit_comment(&mut self, token: &TokenReference) {
+        let (start, end) = range::<_, usize>(token);
+        self.comments.push((start, end));
+        let text = token.to_string();
+
+        for (index, _) in text.match_indices("://") {
+            let url_start = text[..index]
+                .rfind(char::is_whitespace)
+                .map(|whitespace| whitespace + 1)
+                .unwrap_or(0);
+
+            let url_end = text[index..]
+                .find(char::is_whitespace)
+                .map(|whitespace| index + whitespace)
+                .unwrap_or_else(|| text.len());
+
+            self.ignored.push((start + url_start, start + url_end));
+        }
+    }
+}
+
+impl Visitor<'_> for LineLengthVisitor {
+    fn visit_multi_line_comment(&mut self, token: &TokenReference) {
+        self.visit_comment(token);
+    }
+
+    fn visit_single_line_comment(&mut self, token: &TokenReference) {
+        self.visit_comment(token);
+    }
+
+    fn visit_string_literal(&mut self, token: &TokenReference) {
+        self.ignored.push(range(token));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_line_length() {
+        test_lint(
+            LineLengthLint::new(LineLengthConfig {
+                max_length: 40,
+                ..LineLengthConfig::default()
+            })
+            .unwrap(),
+            "line_length",
+            "line_length",
+        );
+    }
+
+    #[test]
+    fn test_ignore_strings() {
+        test_lint(
+            LineLengthLint::new(LineLengthConfig {
+                max_length: 40,
+                ignore_strings: true,
+                ..LineLengthConfig::default()
+            })
+            .unwrap(),
+            "line_length",
+            "ignore_strings",
+        );
+    }
+
+    #[test]
+    fn test_max_comment_length() {
+        test_lint(
+            LineLengthLint::new(LineLengthConfig {
+                max_length: 40,
+                max_comment_length: Some(60),
+                ..LineLengthConfig::default()
+            })
+            .unwrap(),
+            "line_length",
+            "max_comment_length",
+        );
+    }
+
+    #[test]
+    fn test_count_bytes() {
+        test_lint(
+            LineLengthLint::new(LineLengthConfig {
+                max_length: 40,
+                count_bytes: true,
+                ..LineLengthConfig::default()
+            })
+            .unwrap(),
+            "line_length",
+            "count_bytes",
+        );
+    }
+}
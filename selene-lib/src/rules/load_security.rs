@@ -0,0 +1,139 @@
+use super::*;
+use crate::ast_util::{range, scopes::ScopeManager};
+use std::convert::Infallible;
+
+use full_moon::{
+    ast::{self, Ast},
+    node::Node,
+    visitors::Visitor,
+};
+use serde::Deserialize;
+
+#[derive(Clone, Copy, Deserialize)]
+#[serde(default)]
+pub struct LoadSecurityConfig {
+    check_dofile: bool,
+    check_loadfile: bool,
+}
+
+impl Default for LoadSecurityConfig {
+    fn default() -> Self {
+        Self {
+            check_dofile: true,
+            check_loadfile: true,
+        }
+    }
+}
+
+pub struct LoadSecurityLint {
+    config: LoadSecurityConfig,
+}
+
+impl Rule for LoadSecurityLint {
+    type Config = LoadSecurityConfig;
+    type Error = Infallible;
+
+    fn new(config: Self::Config) -> Result<Self, Self::Error> {
+        Ok(LoadSecurityLint { config })
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let mut visitor = LoadSecurityVisitor {
+            calls: Vec::new(),
+            scope_manager: ScopeManager::new(ast),
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .calls
+            .iter()
+            .filter(|(name, _)| match name.as_str() {
+                "dofile" => self.config.check_dofile,
+                "loadfile" => self.config.check_loadfile,
+                _ => true,
+            })
+            .map(|(name, range)| {
+                let loads = if name.ends_with("file") {
+                    "file"
+                } else {
+                    "string"
+                };
+
+                Diagnostic::new_complete(
+                    "load_security",
+                    format!("`{}` runs the code inside of a {}", name, loads),
+                    Label::new(*range),
+                    vec![format!(
+                        "note: if the {} can be controlled by a user, they can run any code they want",
+                        loads
+                    )],
+                    Vec::new(),
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Correctness
+    }
+}
+
+struct LoadSecurityVisitor {
+    calls: Vec<(String, (usize, usize))>,
+    scope_manager: ScopeManager,
+}
+
+impl Visitor<'_> for LoadSecurityVisitor {
+    fn visit_function_call(&mut self, call: &ast::FunctionCall) {
+        if_chain::if_chain! {
+            if let ast::Prefix::Name(name) = call.prefix();
+            let name = name.to_string();
+            if ["dofile", "load", "loadfile", "loadstring"].contains(&name.as_str());
+            if let Some(ast::Suffix::Call(_)) = call.iter_suffixes().next();
+            then {
+                if let Some(reference) = self
+                    .scope_manager
+                    .reference_at_byte(call.start_position().unwrap().bytes())
+                {
+                    if reference.resolved.is_some() {
+                        return;
+                    }
+                }
+
+                self.calls.push((name, range(call)));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_load_security() {
+        test_lint(
+            LoadSecurityLint::new(LoadSecurityConfig::default()).unwrap(),
+            "load_security",
+            "load_security",
+        );
+    }
+
+    #[test]
+    fn test_no_check_files() {
+        test_lint(
+            LoadSecurityLint::new(LoadSecurityConfig {
+                check_dofile: false,
+                check_loadfile: false,
+            })
+            .unwrap(),
+            "load_security",
+            "no_check_files",
+        );
+    }
+}
@@ -0,0 +1,324 @@
+use super::{super::standard_library::Field, *};
+use crate::ast_util::{parse_number, range, scopes::ScopeManager};
+use std::{
+    collections::{HashMap, HashSet},
+    convert::Infallible,
+};
+
+use full_moon::{
+    ast::{self, Ast},
+    node::Node,
+    tokenizer::TokenReference,
+    visitors::Visitor,
+};
+use serde::Deserialize;
+
+#[derive(Clone, Deserialize)]
+#[serde(default)]
+pub struct MagicNumberConfig {
+    allow: Vec<f64>,
+    allow_in_assignments: bool,
+    allow_in_constants: bool,
+    allow_in_for_loops: bool,
+    allow_in_tables: bool,
+}
+
+impl Default for MagicNumberConfig {
+    fn default() -> Self {
+        Self {
+            allow: vec![0.0, 1.0, -1.0, 2.0],
+            allow_in_assignments: true,
+            allow_in_constants: true,
+            allow_in_for_loops: true,
+            allow_in_tables: true,
+        }
+    }
+}
+
+pub struct MagicNumberLint {
+    config: MagicNumberConfig,
+}
+
+impl Rule for MagicNumberLint {
+    type Config = MagicNumberConfig;
+    type Error = Infallible;
+
+    fn new(config: Self::Config) -> Result<Self, Self::Error> {
+        Ok(MagicNumberLint { config })
+    }
+
+    fn pass(&self, ast: &Ast, context: &Context) -> Vec<Diagnostic> {
+        let mut visitor = MagicNumberVisitor {
+            config: &self.config,
+            exempt: HashSet::new(),
+            negated: HashMap::new(),
+            numbers: Vec::new(),
+            scope_manager: ScopeManager::new(ast),
+            standard_library: context.standard_library,
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .numbers
+            .iter()
+            .filter(|(number_range, _)| !visitor.exempt.contains(number_range))
+            .filter_map(|(number_range, text)| {
+                let (range, text, negated) = match visitor.negated.get(number_range) {
+                    Some(range) => (*range, format!("-{}", text), true),
+                    None => (*number_range, text.to_owned(), false),
+                };
+
+                // Numbers we can't parse are still reported, since they can't be in the allow list
+                if let Some(value) = parse_number(text.trim_start_matches('-')) {
+                    let value = if negated { -value } else { value };
+
+                    if self.config.allow.contains(&value) {
+                        return None;
+                    }
+                }
+
+                Some(Diagnostic::new_complete(
+                    "magic_number",
+                    format!("magic number `{}`", text),
+                    Label::new(range),
+                    vec!["help: assign it to a named constant instead".to_owned()],
+                    Vec::new(),
+                ))
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Style
+    }
+
+    fn allow(&self) -> bool {
+        true
+    }
+}
+
+// `UPPER_CASE` names, which are used for constants
+fn is_constant_name(name: &str) -> bool {
+    name.starts_with(|character: char| character.is_ascii_uppercase())
+        && name.chars().all(|character| {
+            character.is_ascii_uppercase() || character.is_ascii_digit() || character == '_'
+        })
+}
+
+// Returns the number token of `5` or `-5`, but not `5 + x`
+fn literal_number<'a, 'ast>(
+    expression: &'a ast::Expression<'ast>,
+) -> Option<&'a TokenReference<'ast>> {
+    match expression {
+        ast::Expression::UnaryOperator {
+            unop: ast::UnOp::Minus(_),
+            expression,
+        } => literal_number(expression),
+
+        ast::Expression::Value { value, binop: None } => match &**value {
+            ast::Value::Number(token) => Some(token),
+            _ => None,
+        },
+
+        _ => None,
+    }
+}
+
+struct MagicNumberVisitor<'a> {
+    config: &'a MagicNumberConfig,
+    exempt: HashSet<(usize, usize)>,
+    // Maps the range of the number in `-5` to the range of the entire expression
+    negated: HashMap<(usize, usize), (usize, usize)>,
+    numbers: Vec<((usize, usize), String)>,
+    scope_manager: ScopeManager,
+    standard_library: &'a StandardLibrary,
+}
+
+impl MagicNumberVisitor<'_> {
+    fn exempt_expression(&mut self, expression: &ast::Expression) {
+        if let Some(token) = literal_number(expression) {
+            self.exempt.insert(range(token));
+        }
+    }
+
+    fn is_standard_library_function(&self, call: &ast::FunctionCall) -> bool {
+        let mut names = match call.prefix() {
+            ast::Prefix::Name(name) => vec![name.to_string()],
+            _ => return false,
+        };
+
+        if let Some(reference) = self
+            .scope_manager
+            .reference_at_byte(call.start_position().unwrap().bytes())
+        {
+            if reference.resolved.is_some() {
+                return false;
+            }
+        }
+
+        for suffix in call.iter_suffixes() {
+            match suffix {
+                ast::Suffix::Index(ast::Index::Dot { name, .. }) => names.push(name.to_string()),
+
+                ast::Suffix::Call(ast::Call::MethodCall(method_call)) => {
+                    names.push(method_call.name().to_string());
+                    break;
+                }
+
+                ast::Suffix::Call(ast::Call::AnonymousCall(_)) => break,
+
+                _ => return false,
+            }
+        }
+
+        matches!(
+            self.standard_library.find_global(&names),
+            Some(Field::Function { .. })
+        )
+    }
+}
+
+impl Visitor<'_> for MagicNumberVisitor<'_> {
+    fn visit_assignment(&mut self, assignment: &ast::Assignment) {
+        if !self.config.allow_in_assignments {
+            return;
+        }
+
+        for expression in assignment.expr_list() {
+            self.exempt_expression(expression);
+        }
+    }
+
+    fn visit_expression(&mut self, expression: &ast::Expression) {
+        if let ast::Expression::UnaryOperator {
+            unop: ast::UnOp::Minus(_),
+            expression: operand,
+        } = expression
+        {
+            // `-5 + x` is parsed as `-(5 + x)`, but is really `(-5) + x`
+            if let ast::Expression::Value { value, .. } = &**operand {
+                if let ast::Value::Number(token) = &**value {
+                    let end = token.end_position().unwrap().bytes();
+                    self.negated
+                        .insert(range(token), (range::<_, usize>(expression).0, end));
+                }
+            }
+        }
+    }
+
+    fn visit_field(&mut self, field: &ast::Field) {
+        if !self.config.allow_in_tables {
+            return;
+        }
+
+        match field {
+            ast::Field::ExpressionKey { key, value, .. } => {
+                self.exempt_expression(key);
+                self.exempt_expression(value);
+            }
+
+            ast::Field::NameKey { value, .. } | ast::Field::NoKey(value) => {
+                self.exempt_expression(value);
+            }
+        }
+    }
+
+    fn visit_function_call(&mut self, call: &ast::FunctionCall) {
+        if !self.is_standard_library_function(call) {
+            return;
+        }
+
+        // Only the arguments of the first call, `math.floor(1.5)` and not `math.floor(1.5)(2.5)`
+        let arguments = call.iter_suffixes().find_map(|suffix| match suffix {
+            ast::Suffix::Call(ast::Call::AnonymousCall(arguments)) => Some(arguments),
+            ast::Suffix::Call(ast::Call::MethodCall(method_call)) => Some(method_call.args()),
+            _ => None,
+        });
+
+        if let Some(ast::FunctionArgs::Parentheses { arguments, .. }) = arguments {
+            for argument in arguments {
+                self.exempt_expression(argument);
+            }
+        }
+    }
+
+    fn visit_local_assignment(&mut self, local_assignment: &ast::LocalAssignment) {
+        for (name, expression) in local_assignment
+            .name_list()
+            .iter()
+            .zip(local_assignment.expr_list().iter())
+        {
+            if self.config.allow_in_assignments
+                || (self.config.allow_in_constants && is_constant_name(&name.to_string()))
+            {
+                self.exempt_expression(expression);
+            }
+        }
+    }
+
+    fn visit_numeric_for(&mut self, numeric_for: &ast::NumericFor) {
+        if !self.config.allow_in_for_loops {
+            return;
+        }
+
+        self.exempt_expression(numeric_for.start());
+        self.exempt_expression(numeric_for.end());
+
+        if let Some(step) = numeric_for.step() {
+            self.exempt_expression(step);
+        }
+    }
+
+    fn visit_value(&mut self, value: &ast::Value) {
+        if let ast::Value::Number(token) = value {
+            self.numbers.push((range(token), token.to_string()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_magic_number() {
+        test_lint(
+            MagicNumberLint::new(MagicNumberConfig::default()).unwrap(),
+            "magic_number",
+            "magic_number",
+        );
+    }
+
+    #[test]
+    fn test_magic_number_allow() {
+        test_lint(
+            MagicNumberLint::new(MagicNumberConfig {
+                allow: vec![60.0, 255.0],
+                ..MagicNumberConfig::default()
+            })
+            .unwrap(),
+            "magic_number",
+            "magic_number_allow",
+        );
+    }
+
+    #[test]
+    fn test_magic_number_no_exemptions() {
+        test_lint(
+            MagicNumberLint::new(MagicNumberConfig {
+                allow_in_assignments: false,
+                allow_in_for_loops: false,
+                allow_in_tables: false,
+                ..MagicNumberConfig::default()
+            })
+            .unwrap(),
+            "magic_number",
+            "magic_number_no_exemptions",
+        );
+    }
+}
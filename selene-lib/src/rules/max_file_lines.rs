@@ -0,0 +1,104 @@
+use super::*;
+use std::convert::Infallible;
+
+use full_moon::ast::Ast;
+use serde::Deserialize;
+
+#[derive(Clone, Copy, Deserialize)]
+#[serde(default)]
+pub struct MaxFileLinesConfig {
+    max_lines: usize,
+}
+
+impl Default for MaxFileLinesConfig {
+    fn default() -> Self {
+        Self { max_lines: 500 }
+    }
+}
+
+pub struct MaxFileLinesLint {
+    config: MaxFileLinesConfig,
+}
+
+impl Rule for MaxFileLinesLint {
+    type Config = MaxFileLinesConfig;
+    type Error = Infallible;
+
+    fn new(config: Self::Config) -> Result<Self, Self::Error> {
+        Ok(MaxFileLinesLint { config })
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let source = full_moon::print(ast);
+        // A trailing newline ends the last line rather than starting another one
+        let lines = source.lines().count();
+
+        if lines <= self.config.max_lines {
+            return Vec::new();
+        }
+
+        // The first line over the maximum, which always exists as there are more lines than it
+        let start = match self.config.max_lines {
+            0 => 0,
+            max_lines => source
+                .match_indices('\n')
+                .nth(max_lines - 1)
+                .map(|(index, _)| index + 1)
+                .unwrap(),
+        };
+
+        let line = source[start..].split('\n').next().unwrap();
+        let end = start + line.trim_end_matches('\r').len();
+
+        vec![Diagnostic::new_complete(
+            "max_file_lines",
+            format!(
+                "this file is {} lines long, but the maximum allowed is {}",
+                lines, self.config.max_lines,
+            ),
+            Label::new((start, end)),
+            vec!["help: split this file into smaller modules".to_owned()],
+            Vec::new(),
+        )]
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Style
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_max_file_lines() {
+        test_lint(
+            MaxFileLinesLint::new(MaxFileLinesConfig { max_lines: 10 }).unwrap(),
+            "max_file_lines",
+            "max_file_lines",
+        );
+    }
+
+    #[test]
+    fn test_at_limit() {
+        test_lint(
+            MaxFileLinesLint::new(MaxFileLinesConfig { max_lines: 10 }).unwrap(),
+            "max_file_lines",
+            "at_limit",
+        );
+    }
+
+    #[test]
+    fn test_under_limit() {
+        test_lint(
+            MaxFileLinesLint::new(MaxFileLinesConfig { max_lines: 10 }).unwrap(),
+            "max_file_lines",
+            "under_limit",
+        );
+    }
+}
@@ -0,0 +1,216 @@
+use super::*;
+use crate::ast_util::range;
+use std::convert::Infallible;
+
+use full_moon::{
+    ast::{self, Ast},
+    tokenizer::TokenReference,
+    visitors::Visitor,
+};
+use serde::Deserialize;
+
+#[derive(Clone, Copy, Deserialize)]
+#[serde(default)]
+pub struct MaxNestingDepthConfig {
+    max_depth: usize,
+    reset_on_function: bool,
+}
+
+impl Default for MaxNestingDepthConfig {
+    fn default() -> Self {
+        Self {
+            max_depth: 6,
+            reset_on_function: true,
+        }
+    }
+}
+
+pub struct MaxNestingDepthLint {
+    config: MaxNestingDepthConfig,
+}
+
+impl Rule for MaxNestingDepthLint {
+    type Config = MaxNestingDepthConfig;
+    type Error = Infallible;
+
+    fn new(config: Self::Config) -> Result<Self, Self::Error> {
+        Ok(MaxNestingDepthLint { config })
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let mut visitor = MaxNestingDepthVisitor {
+            config: self.config,
+            depth: 0,
+            function_depths: Vec::new(),
+            function_range: None,
+            positions: Vec::new(),
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .positions
+            .iter()
+            .map(|(range, depth)| {
+                Diagnostic::new(
+                    "max_nesting_depth",
+                    format!(
+                        "this block is nested {} levels deep, but the maximum allowed is {}",
+                        depth, self.config.max_depth,
+                    ),
+                    Label::new(*range),
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Complexity
+    }
+}
+
+struct MaxNestingDepthVisitor {
+    config: MaxNestingDepthConfig,
+    depth: usize,
+    // The depths outside of each function, when `reset_on_function` is enabled
+    function_depths: Vec<usize>,
+    // The range of the function currently being entered, as function bodies don't have one
+    function_range: Option<(usize, usize)>,
+    positions: Vec<((usize, usize), usize)>,
+}
+
+impl MaxNestingDepthVisitor {
+    fn open(&mut self, range: (usize, usize)) {
+        self.depth += 1;
+
+        // Only the first block over the maximum is reported, rather than every one inside of it
+        if self.depth == self.config.max_depth + 1 {
+            self.positions.push((range, self.depth));
+        }
+    }
+
+    fn open_token(&mut self, token: &TokenReference) {
+        self.open(range(token));
+    }
+
+    fn close(&mut self) {
+        self.depth -= 1;
+    }
+}
+
+impl Visitor<'_> for MaxNestingDepthVisitor {
+    fn visit_do(&mut self, do_block: &ast::Do) {
+        self.open_token(do_block.do_token());
+    }
+
+    fn visit_do_end(&mut self, _: &ast::Do) {
+        self.close();
+    }
+
+    fn visit_function_body(&mut self, body: &ast::FunctionBody) {
+        if self.config.reset_on_function {
+            self.function_depths.push(self.depth);
+            self.depth = 0;
+        }
+
+        let function_range = self.function_range.take().unwrap_or_else(|| range(body));
+        self.open(function_range);
+    }
+
+    fn visit_function_body_end(&mut self, _: &ast::FunctionBody) {
+        self.close();
+
+        if self.config.reset_on_function {
+            self.depth = self.function_depths.pop().unwrap();
+        }
+    }
+
+    fn visit_function_declaration(&mut self, declaration: &ast::FunctionDeclaration) {
+        self.function_range = Some(range(declaration.name()));
+    }
+
+    fn visit_generic_for(&mut self, generic_for: &ast::GenericFor) {
+        self.open_token(generic_for.for_token());
+    }
+
+    fn visit_generic_for_end(&mut self, _: &ast::GenericFor) {
+        self.close();
+    }
+
+    fn visit_if(&mut self, if_block: &ast::If) {
+        self.open_token(if_block.if_token());
+    }
+
+    fn visit_if_end(&mut self, _: &ast::If) {
+        self.close();
+    }
+
+    fn visit_local_function(&mut self, local_function: &ast::LocalFunction) {
+        self.function_range = Some(range(local_function.name()));
+    }
+
+    fn visit_numeric_for(&mut self, numeric_for: &ast::NumericFor) {
+        self.open_token(numeric_for.for_token());
+    }
+
+    fn visit_numeric_for_end(&mut self, _: &ast::NumericFor) {
+        self.close();
+    }
+
+    fn visit_repeat(&mut self, repeat: &ast::Repeat) {
+        self.open_token(repeat.repeat_token());
+    }
+
+    fn visit_repeat_end(&mut self, _: &ast::Repeat) {
+        self.close();
+    }
+
+    fn visit_value(&mut self, value: &ast::Value) {
+        if let ast::Value::Function((function_token, _)) = value {
+            self.function_range = Some(range(function_token));
+        }
+    }
+
+    fn visit_while(&mut self, while_loop: &ast::While) {
+        self.open_token(while_loop.while_token());
+    }
+
+    fn visit_while_end(&mut self, _: &ast::While) {
+        self.close();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_max_nesting_depth() {
+        test_lint(
+            MaxNestingDepthLint::new(MaxNestingDepthConfig {
+                max_depth: 3,
+                reset_on_function: true,
+            })
+            .unwrap(),
+            "max_nesting_depth",
+            "max_nesting_depth",
+        );
+    }
+
+    #[test]
+    fn test_no_reset_on_function() {
+        test_lint(
+            MaxNestingDepthLint::new(MaxNestingDepthConfig {
+                max_depth: 3,
+                reset_on_function: false,
+            })
+            .unwrap(),
+            "max_nesting_depth",
+            "no_reset_on_function",
+        );
+    }
+}
@@ -0,0 +1,176 @@
+use super::*;
+use crate::ast_util::range;
+use std::convert::Infallible;
+
+use full_moon::{
+    ast::{self, Ast},
+    node::Node,
+    visitors::Visitor,
+};
+use serde::Deserialize;
+
+#[derive(Clone, Copy, Deserialize)]
+#[serde(default)]
+pub struct MaxParametersConfig {
+    max_params: usize,
+    max_method_params: Option<usize>,
+}
+
+impl Default for MaxParametersConfig {
+    fn default() -> Self {
+        Self {
+            max_params: 8,
+            max_method_params: None,
+        }
+    }
+}
+
+pub struct MaxParametersLint {
+    max_params: usize,
+    max_method_params: usize,
+}
+
+impl Rule for MaxParametersLint {
+    type Config = MaxParametersConfig;
+    type Error = Infallible;
+
+    fn new(config: Self::Config) -> Result<Self, Self::Error> {
+        Ok(MaxParametersLint {
+            max_params: config.max_params,
+            // Methods get an extra parameter to account for `self`
+            max_method_params: config.max_method_params.unwrap_or(config.max_params + 1),
+        })
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let mut visitor = MaxParametersVisitor {
+            functions: Vec::new(),
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .functions
+            .iter()
+            .filter_map(|function| {
+                let maximum = if function.method {
+                    self.max_method_params
+                } else {
+                    self.max_params
+                };
+
+                if function.parameters <= maximum {
+                    return None;
+                }
+
+                Some(Diagnostic::new(
+                    "max_parameters",
+                    format!(
+                        "this {} has {} parameters, but the maximum allowed is {}",
+                        if function.method {
+                            "method"
+                        } else {
+                            "function"
+                        },
+                        function.parameters,
+                        maximum,
+                    ),
+                    Label::new(function.range),
+                ))
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Complexity
+    }
+}
+
+struct MaxParametersVisitor {
+    functions: Vec<Function>,
+}
+
+struct Function {
+    method: bool,
+    parameters: usize,
+    range: (usize, usize),
+}
+
+impl MaxParametersVisitor {
+    fn push_function<N: Node>(&mut self, name: N, body: &ast::FunctionBody, implicit_self: bool) {
+        let mut parameters = 0;
+        let mut method = implicit_self;
+
+        for (index, parameter) in body.iter_parameters().enumerate() {
+            // The vararg is not counted
+            if let ast::Parameter::Name(name) = parameter {
+                if index == 0 && name.to_string() == "self" {
+                    method = true;
+                }
+
+                parameters += 1;
+            }
+        }
+
+        if implicit_self {
+            parameters += 1;
+        }
+
+        self.functions.push(Function {
+            method,
+            parameters,
+            range: range(name),
+        });
+    }
+}
+
+impl Visitor<'_> for MaxParametersVisitor {
+    fn visit_function_declaration(&mut self, declaration: &ast::FunctionDeclaration) {
+        self.push_function(
+            declaration.name(),
+            declaration.body(),
+            declaration.name().method_name().is_some(),
+        );
+    }
+
+    fn visit_local_function(&mut self, local_function: &ast::LocalFunction) {
+        self.push_function(local_function.name(), local_function.func_body(), false);
+    }
+
+    fn visit_value(&mut self, value: &ast::Value) {
+        if let ast::Value::Function((function_token, body)) = value {
+            self.push_function(function_token, body, false);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_max_parameters() {
+        test_lint(
+            MaxParametersLint::new(MaxParametersConfig::default()).unwrap(),
+            "max_parameters",
+            "max_parameters",
+        );
+    }
+
+    #[test]
+    fn test_max_parameters_config() {
+        test_lint(
+            MaxParametersLint::new(MaxParametersConfig {
+                max_params: 2,
+                max_method_params: Some(2),
+            })
+            .unwrap(),
+            "max_parameters",
+            "max_parameters_config",
+        );
+    }
+}
@@ -0,0 +1,192 @@
+use super::*;
+use crate::ast_util::range;
+use std::convert::Infallible;
+
+use full_moon::{
+    ast::{self, Ast},
+    visitors::Visitor,
+};
+
+// Every metamethod from Lua 5.1 onwards, as well as Luau's
+const METAMETHODS: &[&str] = &[
+    "__add",
+    "__band",
+    "__bnot",
+    "__bor",
+    "__bxor",
+    "__call",
+    "__close",
+    "__concat",
+    "__div",
+    "__eq",
+    "__gc",
+    "__idiv",
+    "__index",
+    "__ipairs",
+    "__iter",
+    "__le",
+    "__len",
+    "__lt",
+    "__metatable",
+    "__mod",
+    "__mode",
+    "__mul",
+    "__name",
+    "__namecall",
+    "__newindex",
+    "__pairs",
+    "__pow",
+    "__shl",
+    "__shr",
+    "__sub",
+    "__tostring",
+    "__unm",
+];
+
+// How many edits a key can be from a metamethod to be considered a typo of it
+const MAX_DISTANCE: usize = 2;
+
+pub struct MetamethodTypoLint;
+
+impl Rule for MetamethodTypoLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(MetamethodTypoLint)
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let mut visitor = MetamethodTypoVisitor { typos: Vec::new() };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .typos
+            .into_iter()
+            .map(|typo| {
+                Diagnostic::new_complete(
+                    "metamethod_typo",
+                    format!("`{}` is not a metamethod", typo.key),
+                    Label::new(typo.range),
+                    vec![format!("help: did you mean `{}`?", typo.suggestion)],
+                    Vec::new(),
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Correctness
+    }
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+
+    for (index, a_char) in a.chars().enumerate() {
+        let mut current = vec![index + 1];
+
+        for (b_index, b_char) in b.iter().enumerate() {
+            let substitution = previous[b_index] + if a_char == *b_char { 0 } else { 1 };
+            current.push(
+                substitution
+                    .min(previous[b_index + 1] + 1)
+                    .min(current[b_index] + 1),
+            );
+        }
+
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+// The metamethod a key was probably meant to be, if it isn't one already
+fn suggestion(key: &str) -> Option<&'static str> {
+    if !key.starts_with("__") || METAMETHODS.contains(&key) {
+        return None;
+    }
+
+    let lowercase = key.to_lowercase();
+
+    METAMETHODS
+        .iter()
+        .map(|metamethod| {
+            // Shortened names, like `__tostr`, are treated as close
+            let distance = if lowercase.len() > 4 && metamethod.starts_with(&lowercase) {
+                1
+            } else {
+                edit_distance(&lowercase, metamethod)
+            };
+
+            (distance, *metamethod)
+        })
+        .filter(|(distance, _)| *distance <= MAX_DISTANCE)
+        .min()
+        .map(|(_, metamethod)| metamethod)
+}
+
+struct Typo {
+    key: String,
+    range: (usize, usize),
+    suggestion: &'static str,
+}
+
+struct MetamethodTypoVisitor {
+    typos: Vec<Typo>,
+}
+
+impl Visitor<'_> for MetamethodTypoVisitor {
+    fn visit_function_call(&mut self, call: &ast::FunctionCall) {
+        if_chain::if_chain! {
+            if let ast::Prefix::Name(name) = call.prefix();
+            if name.to_string() == "setmetatable";
+            if let Some(ast::Suffix::Call(ast::Call::AnonymousCall(
+                ast::FunctionArgs::Parentheses { arguments, .. }
+            ))) = call.iter_suffixes().next();
+            if let Some(ast::Expression::Value { value, binop: None }) = arguments.iter().nth(1);
+            if let ast::Value::TableConstructor(table) = &**value;
+            then {
+                for (field, _) in table.iter_fields() {
+                    if let ast::Field::NameKey { key, .. } = field {
+                        if let Some(suggestion) = suggestion(&key.to_string()) {
+                            self.typos.push(Typo {
+                                key: key.to_string(),
+                                range: range(key),
+                                suggestion,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("__index", "__index"), 0);
+        assert_eq!(edit_distance("__indx", "__index"), 1);
+        assert_eq!(edit_distance("__tostr", "__tostring"), 3);
+        assert_eq!(edit_distance("", "abc"), 3);
+    }
+
+    #[test]
+    fn test_metamethod_typo() {
+        test_lint(
+            MetamethodTypoLint::new(()).unwrap(),
+            "metamethod_typo",
+            "metamethod_typo",
+        );
+    }
+}
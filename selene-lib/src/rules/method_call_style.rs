@@ -0,0 +1,187 @@
+use super::*;
+use crate::ast_util::range;
+use std::convert::Infallible;
+
+use full_moon::{
+    ast::{self, Ast},
+    node::Node,
+    visitors::{Visit, Visitor},
+};
+
+pub struct MethodCallStyleLint;
+
+impl Rule for MethodCallStyleLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(MethodCallStyleLint)
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let mut visitor = MethodCallStyleVisitor {
+            calls: Vec::new(),
+            source: full_moon::print(ast),
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .calls
+            .iter()
+            .map(|call| {
+                Diagnostic::new_complete(
+                    "method_call_style",
+                    format!(
+                        "`{object}.{method}` is called with `{object}` as its first argument",
+                        object = call.object,
+                        method = call.method,
+                    ),
+                    Label::new(call.range),
+                    vec![format!(
+                        "try: `{}:{}({})`",
+                        call.object, call.method, call.arguments
+                    )],
+                    Vec::new(),
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Style
+    }
+}
+
+struct MethodCall {
+    object: String,
+    method: String,
+    // The arguments after the object, as they are written
+    arguments: String,
+    range: (usize, usize),
+}
+
+struct MethodCallStyleVisitor {
+    calls: Vec<MethodCall>,
+    source: String,
+}
+
+#[derive(Default)]
+struct CallVisitor {
+    calls: bool,
+}
+
+impl Visitor<'_> for CallVisitor {
+    fn visit_function_call(&mut self, _: &ast::FunctionCall) {
+        self.calls = true;
+    }
+}
+
+// Whether `expression` is written the same as the object made of `prefix` and `suffixes`.
+// Objects with calls, such as `get().method(get())`, aren't the same, as each call could
+// give something different.
+fn is_object<'ast>(
+    expression: &ast::Expression<'ast>,
+    prefix: &ast::Prefix<'ast>,
+    suffixes: &[&ast::Suffix<'ast>],
+) -> bool {
+    let var = match expression {
+        ast::Expression::Value { value, binop: None } => match &**value {
+            ast::Value::Var(var) => var,
+            _ => return false,
+        },
+
+        _ => return false,
+    };
+
+    let mut call_visitor = CallVisitor::default();
+
+    for suffix in suffixes {
+        suffix.visit(&mut call_visitor);
+    }
+
+    if call_visitor.calls {
+        return false;
+    }
+
+    match (var, prefix) {
+        (ast::Var::Name(name), ast::Prefix::Name(prefix_name)) => {
+            suffixes.is_empty() && name.similar(prefix_name)
+        }
+
+        (ast::Var::Expression(var_expression), prefix) => {
+            let var_suffixes = var_expression.iter_suffixes().collect::<Vec<_>>();
+
+            var_expression.prefix().similar(prefix)
+                && var_suffixes.len() == suffixes.len()
+                && var_suffixes
+                    .iter()
+                    .zip(suffixes)
+                    .all(|(var_suffix, suffix)| var_suffix.similar(suffix))
+        }
+
+        _ => false,
+    }
+}
+
+impl Visitor<'_> for MethodCallStyleVisitor {
+    fn visit_function_call(&mut self, call: &ast::FunctionCall) {
+        let suffixes = call.iter_suffixes().collect::<Vec<_>>();
+        let start = range(call).0;
+
+        for (index, pair) in suffixes.windows(2).enumerate() {
+            let (dot, name, parentheses, arguments) = match (pair[0], pair[1]) {
+                (
+                    ast::Suffix::Index(ast::Index::Dot { dot, name }),
+                    ast::Suffix::Call(ast::Call::AnonymousCall(ast::FunctionArgs::Parentheses {
+                        parentheses,
+                        arguments,
+                    })),
+                ) => (dot, name, parentheses, arguments),
+
+                _ => continue,
+            };
+
+            let first = match arguments.pairs().next() {
+                Some(first) => first,
+                None => continue,
+            };
+
+            if !is_object(first.value(), call.prefix(), &suffixes[..index]) {
+                continue;
+            }
+
+            let close = parentheses.tokens().1;
+
+            let arguments = match first.punctuation() {
+                Some(comma) => &self.source[range(comma).1..range(close).0],
+                None => "",
+            };
+
+            self.calls.push(MethodCall {
+                object: self.source[start..range(dot).0].trim().to_owned(),
+                method: name.to_string(),
+                arguments: arguments.trim().to_owned(),
+                range: (start, range(close).1),
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_method_call_style() {
+        test_lint(
+            MethodCallStyleLint::new(()).unwrap(),
+            "method_call_style",
+            "method_call_style",
+        );
+    }
+}
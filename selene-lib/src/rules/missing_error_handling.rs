@@ -0,0 +1,154 @@
+use super::*;
+use crate::{
+    ast_util::{call_path, range, scopes::ScopeManager},
+    standard_library::Field,
+};
+use std::convert::Infallible;
+
+use full_moon::{
+    ast::{self, Ast},
+    visitors::Visitor,
+};
+
+pub struct MissingErrorHandlingLint;
+
+impl Rule for MissingErrorHandlingLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(MissingErrorHandlingLint)
+    }
+
+    fn pass(&self, ast: &Ast, context: &Context) -> Vec<Diagnostic> {
+        let mut visitor = MissingErrorHandlingVisitor {
+            calls: Vec::new(),
+            scope_manager: ScopeManager::new(ast),
+            standard_library: context.standard_library,
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .calls
+            .into_iter()
+            .map(|call| {
+                Diagnostic::new_complete(
+                    "missing_error_handling",
+                    format!("the error returned by `{}` is ignored", call.function),
+                    Label::new(call.range),
+                    vec![
+                        format!(
+                            "note: `{}` returns nil and an error message when it fails",
+                            call.function
+                        ),
+                        "help: assign the second value as well, and check it".to_owned(),
+                    ],
+                    Vec::new(),
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Correctness
+    }
+}
+
+struct FailableCall {
+    function: String,
+    range: (usize, usize),
+}
+
+struct MissingErrorHandlingVisitor<'a> {
+    calls: Vec<FailableCall>,
+    scope_manager: ScopeManager,
+    standard_library: &'a StandardLibrary,
+}
+
+impl MissingErrorHandlingVisitor<'_> {
+    // The name of the standard library function being called, if it can fail
+    fn failable(&self, call: &ast::FunctionCall) -> Option<String> {
+        if let Some(reference) = self.scope_manager.reference_at_byte(range(call).0) {
+            if reference.resolved.is_some() {
+                return None;
+            }
+        }
+
+        // Method calls, such as `x:open()`, aren't standard library functions
+        let names = match call_path(call)? {
+            (names, _, false) => names,
+            _ => return None,
+        };
+
+        match self.standard_library.find_global(&names)? {
+            Field::Function { failable: true, .. } => Some(names.join(".")),
+            _ => None,
+        }
+    }
+
+    fn check(&mut self, names: usize, expressions: Vec<&ast::Expression>) {
+        for (index, expression) in expressions.iter().enumerate() {
+            let call = match expression {
+                ast::Expression::Value { value, binop: None } => match &**value {
+                    ast::Value::FunctionCall(call) => call,
+                    _ => continue,
+                },
+                _ => continue,
+            };
+
+            // Only the last expression can give more than one value
+            let values = if index + 1 == expressions.len() {
+                names.saturating_sub(index)
+            } else {
+                1
+            };
+
+            // Values that aren't assigned at all are already reported by unbalanced_assignments
+            if values != 1 {
+                continue;
+            }
+
+            if let Some(function) = self.failable(call) {
+                self.calls.push(FailableCall {
+                    function,
+                    range: range(call),
+                });
+            }
+        }
+    }
+}
+
+impl Visitor<'_> for MissingErrorHandlingVisitor<'_> {
+    fn visit_assignment(&mut self, assignment: &ast::Assignment) {
+        self.check(
+            assignment.var_list().len(),
+            assignment.expr_list().iter().collect(),
+        );
+    }
+
+    fn visit_local_assignment(&mut self, local_assignment: &ast::LocalAssignment) {
+        self.check(
+            local_assignment.name_list().len(),
+            local_assignment.expr_list().iter().collect(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_missing_error_handling() {
+        test_lint(
+            MissingErrorHandlingLint::new(()).unwrap(),
+            "missing_error_handling",
+            "missing_error_handling",
+        );
+    }
+}
@@ -0,0 +1,126 @@
+use super::*;
+use crate::ast_util::range;
+use std::convert::Infallible;
+
+use full_moon::{ast::Ast, tokenizer::TokenReference, visitors::Visitor};
+use serde::Deserialize;
+
+#[derive(Clone, Copy, Default, Deserialize)]
+#[serde(default)]
+pub struct MixedIndentationConfig {
+    allow_alignment_spaces: bool,
+}
+
+pub struct MixedIndentationLint {
+    config: MixedIndentationConfig,
+}
+
+impl Rule for MixedIndentationLint {
+    type Config = MixedIndentationConfig;
+    type Error = Infallible;
+
+    fn new(config: Self::Config) -> Result<Self, Self::Error> {
+        Ok(MixedIndentationLint { config })
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let source = full_moon::print(ast);
+
+        let mut visitor = MixedIndentationVisitor {
+            ignored: Vec::new(),
+        };
+
+        visitor.visit_ast(ast);
+
+        let mut diagnostics = Vec::new();
+        let mut line_start = 0;
+
+        for line in source.split('\n') {
+            let start = line_start;
+            line_start += line.len() + 1;
+
+            let indentation = &line[..line.len() - line.trim_start_matches(&[' ', '\t'][..]).len()];
+
+            if !indentation.contains(' ') || !indentation.contains('\t') {
+                continue;
+            }
+
+            // Tabs for indentation, followed by spaces for alignment
+            if self.config.allow_alignment_spaces
+                && indentation
+                    .trim_start_matches('\t')
+                    .chars()
+                    .all(|character| character == ' ')
+            {
+                continue;
+            }
+
+            let indentation_range = (start, start + indentation.len());
+
+            if visitor
+                .ignored
+                .iter()
+                .any(|ignored| ignored.0 <= indentation_range.0 && indentation_range.1 <= ignored.1)
+            {
+                continue;
+            }
+
+            diagnostics.push(Diagnostic::new(
+                "mixed_indentation",
+                "this line is indented with both tabs and spaces".to_owned(),
+                Label::new(indentation_range),
+            ));
+        }
+
+        diagnostics
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Style
+    }
+}
+
+struct MixedIndentationVisitor {
+    // Multi-line strings and comments, where whitespace is a part of the text
+    ignored: Vec<(usize, usize)>,
+}
+
+impl Visitor<'_> for MixedIndentationVisitor {
+    fn visit_multi_line_comment(&mut self, token: &TokenReference) {
+        self.ignored.push(range(token));
+    }
+
+    fn visit_string_literal(&mut self, token: &TokenReference) {
+        self.ignored.push(range(token));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_mixed_indentation() {
+        test_lint(
+            MixedIndentationLint::new(MixedIndentationConfig::default()).unwrap(),
+            "mixed_indentation",
+            "mixed_indentation",
+        );
+    }
+
+    #[test]
+    fn test_allow_alignment_spaces() {
+        test_lint(
+            MixedIndentationLint::new(MixedIndentationConfig {
+                allow_alignment_spaces: true,
+            })
+            .unwrap(),
+            "mixed_indentation",
+            "allow_alignment_spaces",
+        );
+    }
+}
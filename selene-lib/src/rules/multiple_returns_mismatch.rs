@@ -0,0 +1,193 @@
+use super::*;
+use crate::{
+    ast_util::{call_path, range, scopes::ScopeManager},
+    standard_library::Field,
+};
+use std::convert::Infallible;
+
+use full_moon::{
+    ast::{self, Ast},
+    node::Node,
+    tokenizer::TokenReference,
+    visitors::Visitor,
+};
+
+pub struct MultipleReturnsMismatchLint;
+
+impl Rule for MultipleReturnsMismatchLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(MultipleReturnsMismatchLint)
+    }
+
+    fn pass(&self, ast: &Ast, context: &Context) -> Vec<Diagnostic> {
+        let mut visitor = MultipleReturnsMismatchVisitor {
+            mismatches: Vec::new(),
+            scope_manager: ScopeManager::new(ast),
+            standard_library: context.standard_library,
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .mismatches
+            .into_iter()
+            .map(|mismatch| {
+                Diagnostic::new_complete(
+                    "multiple_returns_mismatch",
+                    format!(
+                        "`{}` returns {} {}, but more are being assigned",
+                        mismatch.function,
+                        mismatch.returns,
+                        if mismatch.returns == 1 {
+                            "value"
+                        } else {
+                            "values"
+                        },
+                    ),
+                    Label::new(mismatch.range),
+                    vec![format!(
+                        "note: {} will always be nil",
+                        mismatch
+                            .nil_names
+                            .iter()
+                            .map(|name| format!("`{}`", name))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    )],
+                    Vec::new(),
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Correctness
+    }
+}
+
+struct Mismatch {
+    function: String,
+    nil_names: Vec<String>,
+    range: (usize, usize),
+    returns: usize,
+}
+
+struct MultipleReturnsMismatchVisitor<'a> {
+    mismatches: Vec<Mismatch>,
+    scope_manager: ScopeManager,
+    standard_library: &'a StandardLibrary,
+}
+
+impl MultipleReturnsMismatchVisitor<'_> {
+    // The amount of values a standard library function call is known to return
+    fn returns(&self, expression: &ast::Expression) -> Option<(String, usize)> {
+        let call = match expression {
+            ast::Expression::Value { value, binop: None } => match &**value {
+                ast::Value::FunctionCall(call) => call,
+                _ => return None,
+            },
+            _ => return None,
+        };
+
+        if let Some(reference) = self
+            .scope_manager
+            .reference_at_byte(call.start_position().unwrap().bytes())
+        {
+            if reference.resolved.is_some() {
+                return None;
+            }
+        }
+
+        // Method calls, such as `x:open()`, aren't standard library functions
+        let names = match call_path(call)? {
+            (names, _, false) => names,
+            _ => return None,
+        };
+
+        match self.standard_library.find_global(&names)? {
+            Field::Function {
+                returns: Some(returns),
+                ..
+            } => Some((names.join("."), *returns)),
+            _ => None,
+        }
+    }
+
+    fn check<'a, 'ast: 'a>(
+        &mut self,
+        names: Vec<&'a TokenReference<'ast>>,
+        expressions: Vec<&ast::Expression>,
+    ) {
+        let (function, returns) = match expressions.last() {
+            Some(last) => match self.returns(last) {
+                Some(returns) => returns,
+                None => return,
+            },
+            None => return,
+        };
+
+        let values = expressions.len() - 1 + returns;
+
+        if names.len() <= values {
+            return;
+        }
+
+        let nil_names = &names[values..];
+
+        self.mismatches.push(Mismatch {
+            function,
+            nil_names: nil_names.iter().map(|name| name.to_string()).collect(),
+            range: (
+                range(nil_names[0]).0,
+                range(nil_names[nil_names.len() - 1]).1,
+            ),
+            returns,
+        });
+    }
+}
+
+impl Visitor<'_> for MultipleReturnsMismatchVisitor<'_> {
+    fn visit_assignment(&mut self, assignment: &ast::Assignment) {
+        // Only plain names are checked, as there is no one token to point at for `a.b`
+        let names = assignment
+            .var_list()
+            .iter()
+            .map(|var| match var {
+                ast::Var::Name(name) => Some(name),
+                ast::Var::Expression(_) => None,
+            })
+            .collect::<Option<Vec<_>>>();
+
+        if let Some(names) = names {
+            self.check(names, assignment.expr_list().iter().collect());
+        }
+    }
+
+    fn visit_local_assignment(&mut self, local_assignment: &ast::LocalAssignment) {
+        self.check(
+            local_assignment.name_list().iter().collect(),
+            local_assignment.expr_list().iter().collect(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_multiple_returns_mismatch() {
+        test_lint(
+            MultipleReturnsMismatchLint::new(()).unwrap(),
+            "multiple_returns_mismatch",
+            "multiple_returns_mismatch",
+        );
+    }
+}
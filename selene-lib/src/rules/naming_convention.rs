@@ -0,0 +1,472 @@
+use super::*;
+use crate::ast_util::range;
+use std::fmt;
+
+use full_moon::{
+    ast::{self, Ast},
+    tokenizer::{Symbol, TokenReference, TokenType},
+    visitors::Visitor,
+};
+use regex::Regex;
+use serde::Deserialize;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+pub enum Case {
+    #[serde(rename = "any")]
+    Any,
+    #[serde(rename = "camelCase")]
+    CamelCase,
+    #[serde(rename = "PascalCase")]
+    PascalCase,
+    #[serde(rename = "SCREAMING_SNAKE_CASE")]
+    ScreamingSnakeCase,
+    #[serde(rename = "snake_case")]
+    SnakeCase,
+}
+
+impl Case {
+    fn matches(self, name: &str) -> bool {
+        let mut chars = name.chars();
+        let first = chars.next().unwrap();
+
+        match self {
+            Case::Any => true,
+            Case::CamelCase => first.is_ascii_lowercase() && chars.all(char::is_alphanumeric),
+            Case::PascalCase => first.is_ascii_uppercase() && chars.all(char::is_alphanumeric),
+            Case::ScreamingSnakeCase => !name.chars().any(char::is_lowercase),
+            Case::SnakeCase => !name.chars().any(char::is_uppercase),
+        }
+    }
+
+    fn convert(self, name: &str) -> String {
+        let words = words(name);
+
+        let capitalize = |word: &String| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        };
+
+        match self {
+            Case::Any => name.to_owned(),
+            Case::CamelCase => words
+                .iter()
+                .enumerate()
+                .map(|(index, word)| {
+                    if index == 0 {
+                        word.to_owned()
+                    } else {
+                        capitalize(word)
+                    }
+                })
+                .collect(),
+            Case::PascalCase => words.iter().map(capitalize).collect(),
+            Case::ScreamingSnakeCase => words.join("_").to_uppercase(),
+            Case::SnakeCase => words.join("_"),
+        }
+    }
+}
+
+impl fmt::Display for Case {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "{}",
+            match self {
+                Case::Any => "any",
+                Case::CamelCase => "camelCase",
+                Case::PascalCase => "PascalCase",
+                Case::ScreamingSnakeCase => "SCREAMING_SNAKE_CASE",
+                Case::SnakeCase => "snake_case",
+            }
+        )
+    }
+}
+
+// Splits a name into lowercase words, so `getHTTPServer_2` becomes `get`, `http`, `server`, `2`
+fn words(name: &str) -> Vec<String> {
+    let chars = name.chars().collect::<Vec<_>>();
+    let mut words = Vec::new();
+    let mut current = String::new();
+
+    for (index, &character) in chars.iter().enumerate() {
+        if character == '_' {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+
+            continue;
+        }
+
+        if character.is_uppercase() && !current.is_empty() {
+            let previous = chars[index - 1];
+            let next_is_lowercase =
+                matches!(chars.get(index + 1), Some(next) if next.is_lowercase());
+
+            // `myValue` and the `S` in `HTTPServer`
+            if !previous.is_uppercase() || next_is_lowercase {
+                words.push(std::mem::take(&mut current));
+            }
+        }
+
+        current.extend(character.to_lowercase());
+    }
+
+    if !current.is_empty() {
+        words.push(current);
+    }
+
+    words
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(default)]
+pub struct NamingConventionConfig {
+    locals: Case,
+    functions: Case,
+    constants: Case,
+    classes: Case,
+    local_pattern: Option<String>,
+    function_pattern: Option<String>,
+    constant_pattern: Option<String>,
+    class_pattern: Option<String>,
+    ignore_pattern: String,
+    ignore_loop_variables: bool,
+}
+
+impl Default for NamingConventionConfig {
+    fn default() -> Self {
+        Self {
+            locals: Case::Any,
+            functions: Case::Any,
+            constants: Case::Any,
+            classes: Case::Any,
+            local_pattern: None,
+            function_pattern: None,
+            constant_pattern: None,
+            class_pattern: None,
+            ignore_pattern: "^_".to_owned(),
+            ignore_loop_variables: true,
+        }
+    }
+}
+
+pub struct NamingConventionLint {
+    config: NamingConventionConfig,
+    ignore_pattern: Regex,
+    local_pattern: Option<Regex>,
+    function_pattern: Option<Regex>,
+    constant_pattern: Option<Regex>,
+    class_pattern: Option<Regex>,
+}
+
+fn optional_regex(pattern: &Option<String>) -> Result<Option<Regex>, regex::Error> {
+    pattern.as_deref().map(Regex::new).transpose()
+}
+
+impl Rule for NamingConventionLint {
+    type Config = NamingConventionConfig;
+    type Error = regex::Error;
+
+    fn new(config: Self::Config) -> Result<Self, Self::Error> {
+        Ok(NamingConventionLint {
+            ignore_pattern: Regex::new(&config.ignore_pattern)?,
+            local_pattern: optional_regex(&config.local_pattern)?,
+            function_pattern: optional_regex(&config.function_pattern)?,
+            constant_pattern: optional_regex(&config.constant_pattern)?,
+            class_pattern: optional_regex(&config.class_pattern)?,
+            config,
+        })
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let mut visitor = NamingConventionVisitor {
+            depth: 0,
+            names: Vec::new(),
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .names
+            .iter()
+            .filter_map(|name| {
+                let (case, pattern) = match name.kind {
+                    NameKind::Class => (self.config.classes, &self.class_pattern),
+                    NameKind::Constant => (self.config.constants, &self.constant_pattern),
+                    NameKind::Function => (self.config.functions, &self.function_pattern),
+                    NameKind::Local => (self.config.locals, &self.local_pattern),
+                    NameKind::LoopVariable if self.config.ignore_loop_variables => return None,
+                    NameKind::LoopVariable => (self.config.locals, &self.local_pattern),
+                };
+
+                if self.ignore_pattern.is_match(&name.name) {
+                    return None;
+                }
+
+                // Single characters, like `i`, can't be written in any other case
+                if name.name.chars().count() > 1 && !case.matches(&name.name) {
+                    return Some(Diagnostic::new_complete(
+                        "naming_convention",
+                        format!("{} `{}` should be {}", name.kind, name.name, case),
+                        Label::new(name.range),
+                        vec![format!("help: try `{}`", case.convert(&name.name))],
+                        Vec::new(),
+                    ));
+                }
+
+                match pattern {
+                    Some(pattern) if !pattern.is_match(&name.name) => Some(Diagnostic::new(
+                        "naming_convention",
+                        format!(
+                            "{} `{}` should match the pattern `{}`",
+                            name.kind,
+                            name.name,
+                            pattern.as_str()
+                        ),
+                        Label::new(name.range),
+                    )),
+
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Style
+    }
+}
+
+#[derive(Clone, Copy)]
+enum NameKind {
+    Class,
+    Constant,
+    Function,
+    Local,
+    LoopVariable,
+}
+
+impl fmt::Display for NameKind {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "{}",
+            match self {
+                NameKind::Class => "class",
+                NameKind::Constant => "constant",
+                NameKind::Function => "function",
+                NameKind::Local => "local",
+                NameKind::LoopVariable => "loop variable",
+            }
+        )
+    }
+}
+
+struct Name {
+    kind: NameKind,
+    name: String,
+    range: (usize, usize),
+}
+
+struct NamingConventionVisitor {
+    depth: usize,
+    names: Vec<Name>,
+}
+
+impl NamingConventionVisitor {
+    fn push_name(&mut self, token: &TokenReference, kind: NameKind) {
+        self.names.push(Name {
+            kind,
+            name: token.to_string(),
+            range: range(token),
+        });
+    }
+}
+
+fn is_literal(expression: &ast::Expression) -> bool {
+    match expression {
+        ast::Expression::UnaryOperator {
+            unop: ast::UnOp::Minus(_),
+            expression,
+        } => is_literal(expression),
+
+        ast::Expression::Value { value, binop: None } => match &**value {
+            ast::Value::Number(_) | ast::Value::String(_) => true,
+            ast::Value::Symbol(symbol) => {
+                *symbol.token_type()
+                    == (TokenType::Symbol {
+                        symbol: Symbol::True,
+                    })
+                    || *symbol.token_type()
+                        == (TokenType::Symbol {
+                            symbol: Symbol::False,
+                        })
+            }
+            _ => false,
+        },
+
+        _ => false,
+    }
+}
+
+// `{}` or `setmetatable({}, Parent)`
+fn is_class_table(expression: &ast::Expression) -> bool {
+    if let ast::Expression::Value { value, binop: None } = expression {
+        match &**value {
+            ast::Value::TableConstructor(_) => true,
+            ast::Value::FunctionCall(call) => matches!(
+                call.prefix(),
+                ast::Prefix::Name(name) if name.to_string() == "setmetatable"
+            ),
+            _ => false,
+        }
+    } else {
+        false
+    }
+}
+
+fn is_function(expression: &ast::Expression) -> bool {
+    if let ast::Expression::Value { value, binop: None } = expression {
+        matches!(&**value, ast::Value::Function(_))
+    } else {
+        false
+    }
+}
+
+impl Visitor<'_> for NamingConventionVisitor {
+    fn visit_block(&mut self, _: &ast::Block) {
+        self.depth += 1;
+    }
+
+    fn visit_block_end(&mut self, _: &ast::Block) {
+        self.depth -= 1;
+    }
+
+    fn visit_function_body(&mut self, body: &ast::FunctionBody) {
+        for parameter in body.iter_parameters() {
+            if let ast::Parameter::Name(name) = parameter {
+                self.push_name(name, NameKind::Local);
+            }
+        }
+    }
+
+    fn visit_function_declaration(&mut self, declaration: &ast::FunctionDeclaration) {
+        let name = declaration.name();
+        let last_name = name
+            .method_name()
+            .or_else(|| name.names().iter().last())
+            .unwrap();
+
+        self.push_name(last_name, NameKind::Function);
+    }
+
+    fn visit_generic_for(&mut self, generic_for: &ast::GenericFor) {
+        for name in generic_for.names() {
+            self.push_name(name, NameKind::LoopVariable);
+        }
+    }
+
+    fn visit_local_assignment(&mut self, local_assignment: &ast::LocalAssignment) {
+        let mut expressions = local_assignment.expr_list().iter();
+
+        for name in local_assignment.name_list() {
+            let kind = match expressions.next() {
+                Some(expression) if is_function(expression) => NameKind::Function,
+                // `local Player = {}` at the top of the file
+                Some(expression)
+                    if self.depth == 1
+                        && is_class_table(expression)
+                        && name.to_string().starts_with(char::is_uppercase) =>
+                {
+                    NameKind::Class
+                }
+                // `local MAX_HEALTH = 100` at the top of the file
+                Some(expression)
+                    if self.depth == 1
+                        && is_literal(expression)
+                        && !name.to_string().chars().any(char::is_lowercase) =>
+                {
+                    NameKind::Constant
+                }
+                _ => NameKind::Local,
+            };
+
+            self.push_name(name, kind);
+        }
+    }
+
+    fn visit_local_function(&mut self, local_function: &ast::LocalFunction) {
+        self.push_name(local_function.name(), NameKind::Function);
+    }
+
+    fn visit_numeric_for(&mut self, numeric_for: &ast::NumericFor) {
+        self.push_name(numeric_for.index_variable(), NameKind::LoopVariable);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_words() {
+        assert_eq!(words("myValue"), vec!["my", "value"]);
+        assert_eq!(words("getHTTPServer"), vec!["get", "http", "server"]);
+        assert_eq!(words("MAX_HEALTH"), vec!["max", "health"]);
+        assert_eq!(words("player_2"), vec!["player", "2"]);
+    }
+
+    #[test]
+    fn test_naming_convention() {
+        test_lint(
+            NamingConventionLint::new(NamingConventionConfig {
+                locals: Case::CamelCase,
+                functions: Case::PascalCase,
+                constants: Case::ScreamingSnakeCase,
+                ..NamingConventionConfig::default()
+            })
+            .unwrap(),
+            "naming_convention",
+            "naming_convention",
+        );
+    }
+
+    #[test]
+    fn test_snake_case() {
+        test_lint(
+            NamingConventionLint::new(NamingConventionConfig {
+                locals: Case::SnakeCase,
+                functions: Case::SnakeCase,
+                constants: Case::SnakeCase,
+                ignore_loop_variables: false,
+                ..NamingConventionConfig::default()
+            })
+            .unwrap(),
+            "naming_convention",
+            "snake_case",
+        );
+    }
+
+    #[test]
+    fn test_patterns() {
+        test_lint(
+            NamingConventionLint::new(NamingConventionConfig {
+                local_pattern: Some("^[a-z_]+$".to_owned()),
+                function_pattern: Some("^[a-z_]+$".to_owned()),
+                constant_pattern: Some("^[A-Z_]+$".to_owned()),
+                class_pattern: Some("^[A-Z][a-zA-Z]*$".to_owned()),
+                ..NamingConventionConfig::default()
+            })
+            .unwrap(),
+            "naming_convention",
+            "patterns",
+        );
+    }
+}
@@ -0,0 +1,216 @@
+use super::*;
+use crate::ast_util::{
+    expression_tree::{
+        chained_expressions, is_comparison, is_equality, operator_text, ExpressionTree,
+    },
+    range,
+};
+use std::{collections::HashSet, convert::Infallible};
+
+use full_moon::{
+    ast::{self, Ast},
+    visitors::Visitor,
+};
+
+pub struct NanComparisonLint;
+
+impl Rule for NanComparisonLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(NanComparisonLint)
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let source = full_moon::print(ast);
+
+        let mut visitor = NanComparisonVisitor {
+            chained: HashSet::new(),
+            comparisons: Vec::new(),
+            source: &source,
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .comparisons
+            .into_iter()
+            .map(|comparison| {
+                let mut notes =
+                    vec!["note: NaN is not equal to anything, including itself".to_owned()];
+
+                match comparison.suggestion {
+                    Some(suggestion) => notes.push(format!("help: try `{}`", suggestion)),
+                    None => notes.push(
+                        "help: to check if a value is NaN, compare it to itself with `~=`"
+                            .to_owned(),
+                    ),
+                }
+
+                Diagnostic::new_complete(
+                    "nan_comparison",
+                    format!(
+                        "comparing to NaN with `{}` is always {}",
+                        comparison.operator, comparison.result
+                    ),
+                    Label::new(comparison.range),
+                    notes,
+                    Vec::new(),
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Correctness
+    }
+}
+
+fn is_zero(tree: &ExpressionTree) -> bool {
+    match tree.value() {
+        Some(ast::Value::Number(token)) => token.to_string().parse::<f64>() == Ok(0.0),
+        _ => false,
+    }
+}
+
+fn is_math_huge(tree: &ExpressionTree) -> bool {
+    if let ExpressionTree::Unary {
+        op: ast::UnOp::Minus(_),
+        operand,
+    } = tree
+    {
+        return is_math_huge(operand);
+    }
+
+    if_chain::if_chain! {
+        if let Some(ast::Value::Var(ast::Var::Expression(var_expression))) = tree.value();
+        if let ast::Prefix::Name(name) = var_expression.prefix();
+        if name.to_string() == "math";
+        let mut suffixes = var_expression.iter_suffixes();
+        if let Some(ast::Suffix::Index(ast::Index::Dot { name, .. })) = suffixes.next();
+        if name.to_string() == "huge";
+        if suffixes.next().is_none();
+        then {
+            true
+        } else {
+            false
+        }
+    }
+}
+
+// Whether an expression always results in NaN, such as `0/0` or `math.huge - math.huge`
+fn is_nan(tree: &ExpressionTree) -> bool {
+    match tree {
+        ExpressionTree::Binary { lhs, op, rhs } => match op {
+            ast::BinOp::Slash(_) => is_zero(lhs) && is_zero(rhs),
+            ast::BinOp::Minus(_) => is_math_huge(lhs) && is_math_huge(rhs),
+            ast::BinOp::Star(_) => {
+                (is_zero(lhs) && is_math_huge(rhs)) || (is_math_huge(lhs) && is_zero(rhs))
+            }
+            _ => false,
+        },
+
+        ExpressionTree::Unary {
+            op: ast::UnOp::Minus(_),
+            operand,
+        } => is_nan(operand),
+
+        ExpressionTree::Parentheses(ast::Expression::Parentheses { expression, .. }) => {
+            is_nan(&ExpressionTree::new(expression))
+        }
+
+        ExpressionTree::Value(ast::Value::ParseExpression(expression)) => {
+            is_nan(&ExpressionTree::new(expression))
+        }
+
+        _ => false,
+    }
+}
+
+struct Comparison {
+    operator: &'static str,
+    range: (usize, usize),
+    result: bool,
+    suggestion: Option<String>,
+}
+
+struct NanComparisonVisitor<'a> {
+    chained: HashSet<(usize, usize)>,
+    comparisons: Vec<Comparison>,
+    source: &'a str,
+}
+
+impl NanComparisonVisitor<'_> {
+    fn text(&self, tree: &ExpressionTree) -> &str {
+        let (start, end) = tree.range();
+        self.source[start..end].trim()
+    }
+}
+
+impl Visitor<'_> for NanComparisonVisitor<'_> {
+    fn visit_expression(&mut self, expression: &ast::Expression) {
+        if self.chained.contains(&range(expression)) {
+            return;
+        }
+
+        self.chained.extend(chained_expressions(expression));
+
+        let mut comparisons = Vec::new();
+
+        ExpressionTree::new(expression).for_each_binary(&mut |lhs, op, rhs| {
+            if !is_comparison(op) {
+                return;
+            }
+
+            let other = match (is_nan(lhs), is_nan(rhs)) {
+                (true, false) => Some(rhs),
+                (false, true) => Some(lhs),
+                (true, true) => None,
+                (false, false) => return,
+            };
+
+            let not_equal = matches!(op, ast::BinOp::TildeEqual(_));
+
+            // Only variables are suggested to be compared to themselves, as repeating a call could change what it does
+            let suggestion = match other {
+                Some(other @ ExpressionTree::Value(ast::Value::Var(_))) if is_equality(op) => {
+                    Some(format!(
+                        "{} {} {}",
+                        self.text(other),
+                        if not_equal { "==" } else { "~=" },
+                        self.text(other)
+                    ))
+                }
+                _ => None,
+            };
+
+            comparisons.push(Comparison {
+                operator: operator_text(op),
+                range: (lhs.range().0, rhs.range().1),
+                result: not_equal,
+                suggestion,
+            });
+        });
+
+        self.comparisons.extend(comparisons);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_nan_comparison() {
+        test_lint(
+            NanComparisonLint::new(()).unwrap(),
+            "nan_comparison",
+            "nan_comparison",
+        );
+    }
+}
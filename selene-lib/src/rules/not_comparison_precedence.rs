@@ -0,0 +1,154 @@
+use super::*;
+use crate::ast_util::{
+    expression_tree::{
+        chained_expressions, is_equality, operator_text, ExpressionTree, StaticType,
+    },
+    range,
+};
+use std::{collections::HashSet, convert::Infallible};
+
+use full_moon::{
+    ast::{self, Ast},
+    visitors::Visitor,
+};
+
+pub struct NotComparisonPrecedenceLint;
+
+impl Rule for NotComparisonPrecedenceLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(NotComparisonPrecedenceLint)
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let source = full_moon::print(ast);
+
+        let mut visitor = NotComparisonPrecedenceVisitor {
+            chained: HashSet::new(),
+            comparisons: Vec::new(),
+            source: &source,
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .comparisons
+            .into_iter()
+            .map(|comparison| {
+                Diagnostic::new_complete(
+                    "not_comparison_precedence",
+                    "`not` applies before the comparison".to_owned(),
+                    Label::new(comparison.range),
+                    vec![
+                        format!("note: this is parsed as `{}`", comparison.actual),
+                        format!("help: did you mean `{}`?", comparison.intended),
+                    ],
+                    Vec::new(),
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Correctness
+    }
+}
+
+// `x`, `x.y`, or `x[y]`, but not calls
+fn is_simple_reference(tree: &ExpressionTree) -> bool {
+    match tree.value() {
+        Some(ast::Value::Var(ast::Var::Name(_))) => true,
+        Some(ast::Value::Var(ast::Var::Expression(var_expression))) => var_expression
+            .iter_suffixes()
+            .all(|suffix| matches!(suffix, ast::Suffix::Index(_))),
+        _ => false,
+    }
+}
+
+struct Comparison {
+    actual: String,
+    intended: String,
+    range: (usize, usize),
+}
+
+struct NotComparisonPrecedenceVisitor<'a> {
+    chained: HashSet<(usize, usize)>,
+    comparisons: Vec<Comparison>,
+    source: &'a str,
+}
+
+impl NotComparisonPrecedenceVisitor<'_> {
+    fn text(&self, tree: &ExpressionTree) -> &str {
+        let (start, end) = tree.range();
+        self.source[start..end].trim()
+    }
+}
+
+impl Visitor<'_> for NotComparisonPrecedenceVisitor<'_> {
+    fn visit_expression(&mut self, expression: &ast::Expression) {
+        if self.chained.contains(&range(expression)) {
+            return;
+        }
+
+        self.chained.extend(chained_expressions(expression));
+
+        let mut comparisons = Vec::new();
+
+        ExpressionTree::new(expression).for_each_binary(&mut |lhs, op, rhs| {
+            if !is_equality(op) {
+                return;
+            }
+
+            let operand = match lhs {
+                ExpressionTree::Unary {
+                    op: ast::UnOp::Not(_),
+                    operand,
+                } if is_simple_reference(operand) => operand,
+                _ => return,
+            };
+
+            // `not x == true` is comparing a boolean on purpose
+            if rhs.literal_type() == Some(StaticType::Boolean) {
+                return;
+            }
+
+            let opposite = match op {
+                ast::BinOp::TwoEqual(_) => "~=",
+                _ => "==",
+            };
+
+            comparisons.push(Comparison {
+                actual: format!(
+                    "(not {}) {} {}",
+                    self.text(operand),
+                    operator_text(op),
+                    self.text(rhs)
+                ),
+                intended: format!("{} {} {}", self.text(operand), opposite, self.text(rhs)),
+                range: (lhs.range().0, rhs.range().1),
+            });
+        });
+
+        self.comparisons.extend(comparisons);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_not_comparison_precedence() {
+        test_lint(
+            NotComparisonPrecedenceLint::new(()).unwrap(),
+            "not_comparison_precedence",
+            "not_comparison_precedence",
+        );
+    }
+}
@@ -0,0 +1,150 @@
+use super::*;
+use crate::ast_util::{
+    expression_tree::{chained_expressions, is_equality, ExpressionTree},
+    range,
+};
+use std::{collections::HashSet, convert::Infallible};
+
+use full_moon::{
+    ast::{self, Ast},
+    visitors::Visitor,
+};
+
+pub struct NotEqualsSimplificationLint;
+
+impl Rule for NotEqualsSimplificationLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(NotEqualsSimplificationLint)
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let source = full_moon::print(ast);
+
+        let mut visitor = NotEqualsSimplificationVisitor {
+            chained: HashSet::new(),
+            simplifications: Vec::new(),
+            source: &source,
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .simplifications
+            .into_iter()
+            .map(|(range, suggestion)| {
+                Diagnostic::new_complete(
+                    "not_equals_simplification",
+                    "this comparison can be simplified".to_owned(),
+                    Label::new(range),
+                    vec![format!("help: try `{}`", suggestion)],
+                    Vec::new(),
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Style
+    }
+}
+
+// The expression inside of `(expression)`, which full-moon can parse as a value as well
+fn parenthesized<'a, 'ast>(tree: &ExpressionTree<'a, 'ast>) -> Option<&'a ast::Expression<'ast>> {
+    match tree {
+        ExpressionTree::Parentheses(ast::Expression::Parentheses { expression, .. })
+        | ExpressionTree::Value(ast::Value::ParseExpression(ast::Expression::Parentheses {
+            expression,
+            ..
+        })) => Some(expression),
+
+        _ => None,
+    }
+}
+
+struct NotEqualsSimplificationVisitor<'a> {
+    chained: HashSet<(usize, usize)>,
+    simplifications: Vec<((usize, usize), String)>,
+    source: &'a str,
+}
+
+impl NotEqualsSimplificationVisitor<'_> {
+    fn text(&self, tree: &ExpressionTree) -> &str {
+        let (start, end) = tree.range();
+        self.source[start..end].trim()
+    }
+
+    // `needs_parentheses` is whether the tree is one side of a binary operation other than `and` and
+    // `or`, where the simplified comparison would need parentheses to keep the same meaning
+    fn check_tree(&mut self, tree: &ExpressionTree, needs_parentheses: bool) {
+        match tree {
+            ExpressionTree::Binary { lhs, op, rhs } => {
+                let needs_parentheses = !matches!(op, ast::BinOp::And(_) | ast::BinOp::Or(_));
+                self.check_tree(lhs, needs_parentheses);
+                self.check_tree(rhs, needs_parentheses);
+            }
+
+            ExpressionTree::Unary { op, operand } => {
+                if_chain::if_chain! {
+                    if let ast::UnOp::Not(_) = op;
+                    if let Some(expression) = parenthesized(operand);
+                    if let ExpressionTree::Binary { lhs, op, rhs } = ExpressionTree::new(expression);
+                    if is_equality(op);
+                    then {
+                        let operator = match op {
+                            ast::BinOp::TwoEqual(_) => "~=",
+                            _ => "==",
+                        };
+
+                        let comparison =
+                            format!("{} {} {}", self.text(&lhs), operator, self.text(&rhs));
+
+                        self.simplifications.push((
+                            tree.range(),
+                            if needs_parentheses {
+                                format!("({})", comparison)
+                            } else {
+                                comparison
+                            },
+                        ));
+                    }
+                }
+
+                self.check_tree(operand, false);
+            }
+
+            ExpressionTree::Parentheses(_) | ExpressionTree::Value(_) => {}
+        }
+    }
+}
+
+impl Visitor<'_> for NotEqualsSimplificationVisitor<'_> {
+    fn visit_expression(&mut self, expression: &ast::Expression) {
+        if self.chained.contains(&range(expression)) {
+            return;
+        }
+
+        self.chained.extend(chained_expressions(expression));
+        self.check_tree(&ExpressionTree::new(expression), false);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_not_equals_simplification() {
+        test_lint(
+            NotEqualsSimplificationLint::new(()).unwrap(),
+            "not_equals_simplification",
+            "not_equals_simplification",
+        );
+    }
+}
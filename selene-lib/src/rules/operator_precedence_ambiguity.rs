@@ -0,0 +1,262 @@
+use super::*;
+use crate::ast_util::{
+    expression_tree::{chained_expressions, operator_text, precedence, ExpressionTree},
+    range,
+};
+use std::{collections::HashSet, convert::Infallible};
+
+use full_moon::{
+    ast::{self, Ast},
+    visitors::Visitor,
+};
+use serde::Deserialize;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+pub enum Operator {
+    #[serde(rename = "and")]
+    And,
+    #[serde(rename = "^")]
+    Caret,
+    #[serde(rename = ">")]
+    GreaterThan,
+    #[serde(rename = ">=")]
+    GreaterThanEqual,
+    #[serde(rename = "<")]
+    LessThan,
+    #[serde(rename = "<=")]
+    LessThanEqual,
+    #[serde(rename = "-")]
+    Minus,
+    #[serde(rename = "or")]
+    Or,
+    #[serde(rename = "%")]
+    Percent,
+    #[serde(rename = "+")]
+    Plus,
+    #[serde(rename = "/")]
+    Slash,
+    #[serde(rename = "*")]
+    Star,
+    #[serde(rename = "~=")]
+    TildeEqual,
+    #[serde(rename = "..")]
+    TwoDots,
+    #[serde(rename = "==")]
+    TwoEqual,
+}
+
+impl Operator {
+    fn from_bin_op(op: &ast::BinOp) -> Self {
+        match op {
+            ast::BinOp::And(_) => Operator::And,
+            ast::BinOp::Caret(_) => Operator::Caret,
+            ast::BinOp::GreaterThan(_) => Operator::GreaterThan,
+            ast::BinOp::GreaterThanEqual(_) => Operator::GreaterThanEqual,
+            ast::BinOp::LessThan(_) => Operator::LessThan,
+            ast::BinOp::LessThanEqual(_) => Operator::LessThanEqual,
+            ast::BinOp::Minus(_) => Operator::Minus,
+            ast::BinOp::Or(_) => Operator::Or,
+            ast::BinOp::Percent(_) => Operator::Percent,
+            ast::BinOp::Plus(_) => Operator::Plus,
+            ast::BinOp::Slash(_) => Operator::Slash,
+            ast::BinOp::Star(_) => Operator::Star,
+            ast::BinOp::TildeEqual(_) => Operator::TildeEqual,
+            ast::BinOp::TwoDots(_) => Operator::TwoDots,
+            ast::BinOp::TwoEqual(_) => Operator::TwoEqual,
+        }
+    }
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(default)]
+pub struct OperatorPrecedenceAmbiguityConfig {
+    // Operators that need parentheses when they are mixed with another from the same group
+    groups: Vec<Vec<Operator>>,
+}
+
+impl Default for OperatorPrecedenceAmbiguityConfig {
+    fn default() -> Self {
+        Self {
+            groups: vec![
+                vec![Operator::And, Operator::Or],
+                vec![
+                    Operator::Plus,
+                    Operator::Minus,
+                    Operator::Star,
+                    Operator::Slash,
+                    Operator::Percent,
+                    Operator::Caret,
+                    Operator::TwoDots,
+                ],
+            ],
+        }
+    }
+}
+
+pub struct OperatorPrecedenceAmbiguityLint {
+    config: OperatorPrecedenceAmbiguityConfig,
+}
+
+impl Rule for OperatorPrecedenceAmbiguityLint {
+    type Config = OperatorPrecedenceAmbiguityConfig;
+    type Error = Infallible;
+
+    fn new(config: Self::Config) -> Result<Self, Self::Error> {
+        Ok(OperatorPrecedenceAmbiguityLint { config })
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let mut visitor = OperatorPrecedenceAmbiguityVisitor {
+            chained: HashSet::new(),
+            config: &self.config,
+            mixes: Vec::new(),
+            source: full_moon::print(ast),
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .mixes
+            .iter()
+            .map(|mix| {
+                Diagnostic::new_complete(
+                    "operator_precedence_ambiguity",
+                    format!(
+                        "`{}` and `{}` are mixed without parentheses",
+                        mix.outer, mix.inner
+                    ),
+                    Label::new(mix.range),
+                    vec![format!("note: this is evaluated as `{}`", mix.evaluated_as)],
+                    Vec::new(),
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Style
+    }
+
+    fn allow(&self) -> bool {
+        true
+    }
+}
+
+struct Mix {
+    outer: &'static str,
+    inner: &'static str,
+    // The expression with parentheses around what is evaluated first
+    evaluated_as: String,
+    range: (usize, usize),
+}
+
+struct OperatorPrecedenceAmbiguityVisitor<'a> {
+    chained: HashSet<(usize, usize)>,
+    config: &'a OperatorPrecedenceAmbiguityConfig,
+    mixes: Vec<Mix>,
+    source: String,
+}
+
+impl OperatorPrecedenceAmbiguityVisitor<'_> {
+    fn is_ambiguous(&self, outer: &ast::BinOp, inner: &ast::BinOp) -> bool {
+        let (outer_operator, inner_operator) =
+            (Operator::from_bin_op(outer), Operator::from_bin_op(inner));
+
+        // Operators with the same precedence, such as `a + b - c`, are read left to right
+        precedence(outer) != precedence(inner)
+            && self
+                .config
+                .groups
+                .iter()
+                .any(|group| group.contains(&outer_operator) && group.contains(&inner_operator))
+    }
+
+    fn check(&mut self, tree: &ExpressionTree) {
+        match tree {
+            ExpressionTree::Binary { lhs, op, rhs } => {
+                for &(side, is_lhs) in &[(lhs, true), (rhs, false)] {
+                    let inner = match &**side {
+                        ExpressionTree::Binary { op: inner, .. } => inner,
+                        _ => continue,
+                    };
+
+                    // `a and b or c` is how Lua writes a ternary, see and_or_ternary
+                    if is_lhs
+                        && matches!(op, ast::BinOp::Or(_))
+                        && matches!(inner, ast::BinOp::And(_))
+                    {
+                        continue;
+                    }
+
+                    if !self.is_ambiguous(op, inner) {
+                        continue;
+                    }
+
+                    let (start, end) = tree.range();
+                    let (inner_start, inner_end) = side.range();
+
+                    self.mixes.push(Mix {
+                        outer: operator_text(op),
+                        inner: operator_text(inner),
+                        evaluated_as: format!(
+                            "{}({}){}",
+                            &self.source[start..inner_start],
+                            &self.source[inner_start..inner_end],
+                            &self.source[inner_end..end],
+                        ),
+                        range: (start, end),
+                    });
+                }
+
+                self.check(lhs);
+                self.check(rhs);
+            }
+
+            ExpressionTree::Unary { operand, .. } => self.check(operand),
+
+            ExpressionTree::Parentheses(_) | ExpressionTree::Value(_) => {}
+        }
+    }
+}
+
+impl Visitor<'_> for OperatorPrecedenceAmbiguityVisitor<'_> {
+    fn visit_expression(&mut self, expression: &ast::Expression) {
+        if self.chained.contains(&range(expression)) {
+            return;
+        }
+
+        self.chained.extend(chained_expressions(expression));
+        self.check(&ExpressionTree::new(expression));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_operator_precedence_ambiguity() {
+        test_lint(
+            OperatorPrecedenceAmbiguityLint::new(OperatorPrecedenceAmbiguityConfig::default())
+                .unwrap(),
+            "operator_precedence_ambiguity",
+            "operator_precedence_ambiguity",
+        );
+    }
+
+    #[test]
+    fn test_groups() {
+        test_lint(
+            OperatorPrecedenceAmbiguityLint::new(OperatorPrecedenceAmbiguityConfig {
+                groups: vec![vec![Operator::TwoEqual, Operator::And, Operator::Or]],
+            })
+            .unwrap(),
+            "operator_precedence_ambiguity",
+            "groups",
+        );
+    }
+}
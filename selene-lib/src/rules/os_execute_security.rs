@@ -0,0 +1,161 @@
+use super::*;
+use crate::ast_util::{global_aliases::GlobalAliases, range};
+use std::{collections::HashSet, convert::Infallible};
+
+use full_moon::{
+    ast::{self, Ast},
+    visitors::Visitor,
+};
+
+pub struct OsExecuteSecurityLint;
+
+impl Rule for OsExecuteSecurityLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(OsExecuteSecurityLint)
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        shell_diagnostics("os_execute_security", "os.execute", ast)
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Correctness
+    }
+}
+
+// The diagnostics for a function that runs a shell command, shared with io_popen_security
+pub(crate) fn shell_diagnostics(code: &'static str, function: &str, ast: &Ast) -> Vec<Diagnostic> {
+    find_uses(ast, &function.split('.').collect::<Vec<_>>())
+        .into_iter()
+        .map(|position| {
+            Diagnostic::new_complete(
+                code,
+                format!("`{}` runs a shell command", function),
+                Label::new(position),
+                vec![
+                    "note: if the command can be controlled by a user, they can run any command they want"
+                        .to_owned(),
+                ],
+                Vec::new(),
+            )
+        })
+        .collect()
+}
+
+// Finds every use of a global function such as `os.execute`, including calls through locals
+// that alias it, such as `local execute = os.execute` or `local system = os`
+fn find_uses(ast: &Ast, function: &[&str]) -> Vec<(usize, usize)> {
+    let mut visitor = GlobalUsesVisitor {
+        aliases: GlobalAliases::new(ast),
+        alias_definitions: HashSet::new(),
+        function,
+        uses: Vec::new(),
+    };
+
+    visitor.visit_ast(ast);
+    visitor.uses
+}
+
+struct GlobalUsesVisitor<'a> {
+    // Locals that are aliases of a part of the function's path
+    aliases: GlobalAliases,
+    // The ranges of the values that aliases are defined as, which aren't uses themselves
+    alias_definitions: HashSet<(usize, usize)>,
+    function: &'a [&'a str],
+    uses: Vec<(usize, usize)>,
+}
+
+impl GlobalUsesVisitor<'_> {
+    fn is_function(&self, path: &[String]) -> bool {
+        path.len() == self.function.len()
+            && path
+                .iter()
+                .zip(self.function)
+                .all(|(part, name)| part == name)
+    }
+
+    fn is_alias_target(&self, path: &[String]) -> bool {
+        path.len() <= self.function.len()
+            && path
+                .iter()
+                .zip(self.function)
+                .all(|(part, name)| part == name)
+    }
+}
+
+impl Visitor<'_> for GlobalUsesVisitor<'_> {
+    fn visit_function_call(&mut self, call: &ast::FunctionCall) {
+        if let ast::Prefix::Name(name) = call.prefix() {
+            let indexes = call
+                .iter_suffixes()
+                .take_while(|suffix| matches!(suffix, ast::Suffix::Index(ast::Index::Dot { .. })))
+                .count();
+
+            if_chain::if_chain! {
+                if let Some(global) = self.aliases.path(name, call.iter_suffixes().take(indexes));
+                if self.is_function(&global.path);
+                // Only up to the call itself, not anything done with what it returns
+                if let Some(call_suffix @ ast::Suffix::Call(_)) = call.iter_suffixes().nth(indexes);
+                then {
+                    self.uses.push((range(name).0, range::<_, usize>(call_suffix).1));
+                }
+            }
+        }
+    }
+
+    fn visit_local_assignment(&mut self, local_assignment: &ast::LocalAssignment) {
+        for (name, expression) in local_assignment
+            .name_list()
+            .iter()
+            .zip(local_assignment.expr_list().iter())
+        {
+            if let Some((var, global)) = self.aliases.aliased_global(expression) {
+                if self.is_alias_target(&global.path) {
+                    self.aliases.insert(name, global.path);
+                    self.alias_definitions.insert(range(var));
+                }
+            }
+        }
+    }
+
+    // Uses that aren't calls, such as `pcall(os.execute, command)`
+    fn visit_var(&mut self, var: &ast::Var) {
+        if self.alias_definitions.contains(&range(var)) {
+            return;
+        }
+
+        let global = match var {
+            ast::Var::Expression(var_expression) => match var_expression.prefix() {
+                ast::Prefix::Name(name) => self.aliases.path(name, var_expression.iter_suffixes()),
+                _ => None,
+            },
+
+            ast::Var::Name(name) => self.aliases.path(name, std::iter::empty()),
+        };
+
+        if matches!(global, Some(global) if self.is_function(&global.path)) {
+            self.uses.push(range(var));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_os_execute_security() {
+        test_lint(
+            OsExecuteSecurityLint::new(()).unwrap(),
+            "os_execute_security",
+            "os_execute_security",
+        );
+    }
+}
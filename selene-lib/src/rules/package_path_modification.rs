@@ -0,0 +1,165 @@
+use super::*;
+use crate::ast_util::{range, scopes::ScopeManager};
+use std::{convert::Infallible, path::Path};
+
+use full_moon::{
+    ast::{self, Ast},
+    visitors::Visitor,
+};
+use serde::Deserialize;
+
+// Fields of `package` that `require` uses, and whether their own fields are changed as well,
+// such as `package.loaded.inventory = nil`
+const PACKAGE_FIELDS: &[(&str, bool)] = &[
+    ("cpath", false),
+    ("loaded", true),
+    ("path", false),
+    ("preload", true),
+];
+
+// Scripts that start a program, where setting up `package` once is expected
+const INIT_FILE_NAMES: &[&str] = &["init", "main"];
+
+#[derive(Clone, Copy, Default, Deserialize)]
+#[serde(default)]
+pub struct PackagePathModificationConfig {
+    allow_in_init_files: bool,
+}
+
+pub struct PackagePathModificationLint {
+    config: PackagePathModificationConfig,
+}
+
+impl Rule for PackagePathModificationLint {
+    type Config = PackagePathModificationConfig;
+    type Error = Infallible;
+
+    fn new(config: Self::Config) -> Result<Self, Self::Error> {
+        Ok(PackagePathModificationLint { config })
+    }
+
+    fn pass(&self, ast: &Ast, context: &Context) -> Vec<Diagnostic> {
+        if self.config.allow_in_init_files && is_init_file(context) {
+            return Vec::new();
+        }
+
+        let mut visitor = PackagePathModificationVisitor {
+            modifications: Vec::new(),
+            scope_manager: ScopeManager::new(ast),
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .modifications
+            .iter()
+            .map(|modification| {
+                let note = if modification.field == "path" || modification.field == "cpath" {
+                    "note: this changes where `require` looks for modules in every file, not just this one"
+                } else {
+                    "note: this changes what `require` gives for modules in every file, not just this one"
+                };
+
+                Diagnostic::new_complete(
+                    "package_path_modification",
+                    format!("`package.{}` is modified", modification.field),
+                    Label::new(modification.range),
+                    vec![note.to_owned()],
+                    Vec::new(),
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Correctness
+    }
+}
+
+fn is_init_file(context: &Context) -> bool {
+    match context.path.and_then(Path::file_stem) {
+        Some(stem) => INIT_FILE_NAMES.contains(&stem.to_string_lossy().as_ref()),
+        None => false,
+    }
+}
+
+struct Modification {
+    field: String,
+    // `package.loaded` in `package.loaded.inventory = nil`
+    range: (usize, usize),
+}
+
+struct PackagePathModificationVisitor {
+    modifications: Vec<Modification>,
+    scope_manager: ScopeManager,
+}
+
+impl Visitor<'_> for PackagePathModificationVisitor {
+    fn visit_assignment(&mut self, assignment: &ast::Assignment) {
+        for var in assignment.var_list() {
+            if_chain::if_chain! {
+                if let ast::Var::Expression(var_expression) = var;
+                if let ast::Prefix::Name(name) = var_expression.prefix();
+                if name.to_string() == "package";
+                let mut suffixes = var_expression.iter_suffixes();
+                if let Some(ast::Suffix::Index(ast::Index::Dot { name: field, .. })) = suffixes.next();
+                if let Some((_, fields_too)) = PACKAGE_FIELDS
+                    .iter()
+                    .find(|(package_field, _)| *package_field == field.to_string());
+                if *fields_too || suffixes.next().is_none();
+                then {
+                    // `local package = {}` is not the `package` library
+                    if let Some(reference) = self.scope_manager.reference_at_byte(range(name).0) {
+                        if reference.resolved.is_some() {
+                            continue;
+                        }
+                    }
+
+                    self.modifications.push(Modification {
+                        field: field.to_string(),
+                        range: (range(name).0, range(field).1),
+                    });
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_package_path_modification() {
+        test_lint(
+            PackagePathModificationLint::new(PackagePathModificationConfig::default()).unwrap(),
+            "package_path_modification",
+            "package_path_modification",
+        );
+    }
+
+    #[test]
+    fn test_allow_in_init_files() {
+        test_lint(
+            PackagePathModificationLint::new(PackagePathModificationConfig {
+                allow_in_init_files: true,
+            })
+            .unwrap(),
+            "package_path_modification",
+            "main",
+        );
+    }
+
+    #[test]
+    fn test_disallow_in_init_files() {
+        test_lint(
+            PackagePathModificationLint::new(PackagePathModificationConfig::default()).unwrap(),
+            "package_path_modification",
+            "init",
+        );
+    }
+}
@@ -0,0 +1,149 @@
+use super::*;
+use crate::ast_util::{
+    expression_tree::{ExpressionTree, StaticType},
+    literal_locals::LiteralLocals,
+    range,
+    scopes::ScopeManager,
+};
+use std::convert::Infallible;
+
+use full_moon::{
+    ast::{self, Ast},
+    visitors::Visitor,
+};
+
+pub struct PcallNonFunctionLint;
+
+impl Rule for PcallNonFunctionLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(PcallNonFunctionLint)
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let mut visitor = PcallNonFunctionVisitor {
+            calls: Vec::new(),
+            literal_locals: LiteralLocals::new(),
+            scope_manager: ScopeManager::new(ast),
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .calls
+            .into_iter()
+            .map(|call| {
+                Diagnostic::new_complete(
+                    "pcall_non_function",
+                    format!(
+                        "`{}` is given `{}`, which is {} and can't be called",
+                        call.name,
+                        call.local,
+                        call.literal_type.describe()
+                    ),
+                    Label::new(call.argument),
+                    vec![format!("note: `{}` will always fail", call.name)],
+                    Vec::new(),
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Correctness
+    }
+}
+
+struct Call {
+    argument: (usize, usize),
+    literal_type: StaticType,
+    local: String,
+    name: String,
+}
+
+struct PcallNonFunctionVisitor {
+    calls: Vec<Call>,
+    literal_locals: LiteralLocals,
+    scope_manager: ScopeManager,
+}
+
+impl Visitor<'_> for PcallNonFunctionVisitor {
+    fn visit_assignment(&mut self, assignment: &ast::Assignment) {
+        self.literal_locals.visit_assignment(assignment);
+    }
+
+    fn visit_block(&mut self, _: &ast::Block) {
+        self.literal_locals.visit_block();
+    }
+
+    fn visit_block_end(&mut self, _: &ast::Block) {
+        self.literal_locals.visit_block_end();
+    }
+
+    fn visit_function_call(&mut self, call: &ast::FunctionCall) {
+        if_chain::if_chain! {
+            if let ast::Prefix::Name(name) = call.prefix();
+            let name = name.to_string();
+            if name == "pcall" || name == "xpcall";
+            let mut suffixes = call.iter_suffixes();
+            if let Some(ast::Suffix::Call(ast::Call::AnonymousCall(
+                ast::FunctionArgs::Parentheses { arguments, .. }
+            ))) = suffixes.next();
+            if suffixes.next().is_none();
+            if let Some(argument) = arguments.iter().next();
+            // Literals, such as `pcall(nil)`, are already reported by the standard library, which
+            // types the first argument as a function
+            if let Some(ast::Value::Var(ast::Var::Name(local))) = ExpressionTree::new(argument).value();
+            let local = local.to_string();
+            if let Some(literal_type) = self.literal_locals.get(&local);
+            // Tables can be given a metatable with `__call` after they are assigned
+            if literal_type != StaticType::Function && literal_type != StaticType::Table;
+            then {
+                if let Some(reference) = self.scope_manager.reference_at_byte(range(call).0) {
+                    if reference.resolved.is_some() {
+                        return;
+                    }
+                }
+
+                self.calls.push(Call {
+                    argument: range(argument),
+                    literal_type,
+                    local,
+                    name,
+                });
+            }
+        }
+    }
+
+    fn visit_local_assignment(&mut self, local_assignment: &ast::LocalAssignment) {
+        self.literal_locals.visit_local_assignment(local_assignment);
+    }
+
+    fn visit_local_assignment_end(&mut self, _: &ast::LocalAssignment) {
+        self.literal_locals.visit_local_assignment_end();
+    }
+
+    fn visit_local_function(&mut self, local_function: &ast::LocalFunction) {
+        self.literal_locals.visit_local_function(local_function);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_pcall_non_function() {
+        test_lint(
+            PcallNonFunctionLint::new(()).unwrap(),
+            "pcall_non_function",
+            "pcall_non_function",
+        );
+    }
+}
@@ -0,0 +1,116 @@
+use super::*;
+use crate::ast_util::{call_name, range};
+use std::convert::Infallible;
+
+use full_moon::{
+    ast::{self, Ast},
+    visitors::Visitor,
+};
+use serde::Deserialize;
+
+#[derive(Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct PcallReturnIgnoredConfig {
+    extra_functions: Vec<String>,
+}
+
+pub struct PcallReturnIgnoredLint {
+    config: PcallReturnIgnoredConfig,
+}
+
+impl Rule for PcallReturnIgnoredLint {
+    type Config = PcallReturnIgnoredConfig;
+    type Error = Infallible;
+
+    fn new(config: Self::Config) -> Result<Self, Self::Error> {
+        Ok(PcallReturnIgnoredLint { config })
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let mut visitor = PcallReturnIgnoredVisitor { calls: Vec::new() };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .calls
+            .iter()
+            .filter(|call| {
+                call.name == "pcall"
+                    || call.name == "xpcall"
+                    || self.config.extra_functions.contains(&call.name)
+            })
+            .map(|call| {
+                Diagnostic::new_complete(
+                    "pcall_return_ignored",
+                    format!(
+                        "the result of `{}` is discarded, so any errors are silently ignored",
+                        call.name
+                    ),
+                    Label::new(call.range),
+                    vec![
+                        "help: check the first return value to see if the call succeeded"
+                            .to_owned(),
+                    ],
+                    Vec::new(),
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Correctness
+    }
+}
+
+struct Call {
+    name: String,
+    range: (usize, usize),
+}
+
+struct PcallReturnIgnoredVisitor {
+    calls: Vec<Call>,
+}
+
+impl Visitor<'_> for PcallReturnIgnoredVisitor {
+    fn visit_stmt(&mut self, stmt: &ast::Stmt) {
+        // Only calls used as statements discard their results
+        if let ast::Stmt::FunctionCall(call) = stmt {
+            if let Some(name) = call_name(call) {
+                self.calls.push(Call {
+                    name,
+                    range: range(call),
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_pcall_return_ignored() {
+        test_lint(
+            PcallReturnIgnoredLint::new(PcallReturnIgnoredConfig::default()).unwrap(),
+            "pcall_return_ignored",
+            "pcall_return_ignored",
+        );
+    }
+
+    #[test]
+    fn test_extra_functions() {
+        test_lint(
+            PcallReturnIgnoredLint::new(PcallReturnIgnoredConfig {
+                extra_functions: vec!["safeCall".to_owned(), "Promise.try".to_owned()],
+            })
+            .unwrap(),
+            "pcall_return_ignored",
+            "extra_functions",
+        );
+    }
+}
@@ -0,0 +1,228 @@
+use super::*;
+use crate::ast_util::{range, scopes::ScopeManager};
+
+use full_moon::{
+    ast::{self, Ast},
+    visitors::Visitor,
+};
+use glob::Pattern;
+use serde::Deserialize;
+
+#[derive(Clone, Deserialize)]
+#[serde(default)]
+pub struct PrintInProductionConfig {
+    allow_in_test_files: bool,
+    test_file_pattern: Option<String>,
+    allow_functions: Vec<String>,
+}
+
+impl Default for PrintInProductionConfig {
+    fn default() -> Self {
+        Self {
+            allow_in_test_files: true,
+            test_file_pattern: None,
+            allow_functions: Vec::new(),
+        }
+    }
+}
+
+pub struct PrintInProductionLint {
+    config: PrintInProductionConfig,
+    test_file_pattern: Option<Pattern>,
+}
+
+impl Rule for PrintInProductionLint {
+    type Config = PrintInProductionConfig;
+    type Error = glob::PatternError;
+
+    fn new(config: Self::Config) -> Result<Self, Self::Error> {
+        Ok(PrintInProductionLint {
+            test_file_pattern: match &config.test_file_pattern {
+                Some(pattern) => Some(Pattern::new(pattern)?),
+                None => None,
+            },
+            config,
+        })
+    }
+
+    fn pass(&self, ast: &Ast, context: &Context) -> Vec<Diagnostic> {
+        if self.config.allow_in_test_files && self.is_test_file(context) {
+            return Vec::new();
+        }
+
+        let mut visitor = PrintInProductionVisitor {
+            allow_functions: &self.config.allow_functions,
+            functions: Vec::new(),
+            next_function_name: None,
+            prints: Vec::new(),
+            scope_manager: ScopeManager::new(ast),
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .prints
+            .into_iter()
+            .map(|position| {
+                Diagnostic::new_complete(
+                    "print_in_production",
+                    "`print` should not be used outside of debugging".to_owned(),
+                    Label::new(position),
+                    vec![
+                        "help: remove it, or use a logging function if this output is intended"
+                            .to_owned(),
+                    ],
+                    Vec::new(),
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Style
+    }
+
+    fn allow(&self) -> bool {
+        true
+    }
+}
+
+impl PrintInProductionLint {
+    fn is_test_file(&self, context: &Context) -> bool {
+        match (&self.test_file_pattern, context.path) {
+            (Some(pattern), Some(path)) => pattern.matches_path(path),
+            (Some(_), None) => false,
+            (None, _) => context.is_test_file(),
+        }
+    }
+}
+
+struct PrintInProductionVisitor<'a> {
+    allow_functions: &'a [String],
+    // The names of every function this is inside of, `None` for anonymous functions
+    functions: Vec<Option<String>>,
+    // The name of the function whose body is about to be visited
+    next_function_name: Option<String>,
+    prints: Vec<(usize, usize)>,
+    scope_manager: ScopeManager,
+}
+
+impl Visitor<'_> for PrintInProductionVisitor<'_> {
+    fn visit_function_body(&mut self, _: &ast::FunctionBody) {
+        self.functions.push(self.next_function_name.take());
+    }
+
+    fn visit_function_body_end(&mut self, _: &ast::FunctionBody) {
+        self.functions.pop();
+    }
+
+    fn visit_function_call(&mut self, call: &ast::FunctionCall) {
+        if_chain::if_chain! {
+            if let ast::Prefix::Name(name) = call.prefix();
+            if name.to_string() == "print";
+            if let Some(ast::Suffix::Call(ast::Call::AnonymousCall(_))) = call.iter_suffixes().next();
+            then {
+                if let Some(reference) = self.scope_manager.reference_at_byte(range(name).0) {
+                    if reference.resolved.is_some() {
+                        return;
+                    }
+                }
+
+                let allowed = self.functions.iter().flatten().any(|function| {
+                    self.allow_functions.contains(function)
+                });
+
+                if !allowed {
+                    self.prints.push(range(call));
+                }
+            }
+        }
+    }
+
+    fn visit_function_declaration(&mut self, declaration: &ast::FunctionDeclaration) {
+        let name = declaration.name();
+        let mut full_name = name
+            .names()
+            .iter()
+            .map(|name| name.to_string())
+            .collect::<Vec<_>>()
+            .join(".");
+
+        if let Some(method_name) = name.method_name() {
+            full_name.push(':');
+            full_name.push_str(&method_name.to_string());
+        }
+
+        self.next_function_name = Some(full_name);
+    }
+
+    fn visit_local_function(&mut self, local_function: &ast::LocalFunction) {
+        self.next_function_name = Some(local_function.name().to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_print_in_production() {
+        test_lint(
+            PrintInProductionLint::new(PrintInProductionConfig::default()).unwrap(),
+            "print_in_production",
+            "print_in_production",
+        );
+    }
+
+    #[test]
+    fn test_allow_functions() {
+        test_lint(
+            PrintInProductionLint::new(PrintInProductionConfig {
+                allow_functions: vec!["main".to_owned(), "Logger:info".to_owned()],
+                ..PrintInProductionConfig::default()
+            })
+            .unwrap(),
+            "print_in_production",
+            "allow_functions",
+        );
+    }
+
+    #[test]
+    fn test_test_file() {
+        test_lint(
+            PrintInProductionLint::new(PrintInProductionConfig::default()).unwrap(),
+            "print_in_production",
+            "print_in_production_spec",
+        );
+    }
+
+    #[test]
+    fn test_test_file_pattern() {
+        test_lint(
+            PrintInProductionLint::new(PrintInProductionConfig {
+                test_file_pattern: Some("**/debug_*.lua".to_owned()),
+                ..PrintInProductionConfig::default()
+            })
+            .unwrap(),
+            "print_in_production",
+            "debug_tools",
+        );
+    }
+
+    #[test]
+    fn test_disallow_in_test_files() {
+        test_lint(
+            PrintInProductionLint::new(PrintInProductionConfig {
+                allow_in_test_files: false,
+                ..PrintInProductionConfig::default()
+            })
+            .unwrap(),
+            "print_in_production",
+            "disallow_in_test_files_spec",
+        );
+    }
+}
@@ -0,0 +1,195 @@
+use super::*;
+use crate::ast_util::range;
+use std::convert::Infallible;
+
+use full_moon::{
+    ast::Ast,
+    tokenizer::{StringLiteralQuoteType, TokenReference, TokenType},
+    visitors::Visitor,
+};
+use serde::Deserialize;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum QuoteStyle {
+    #[default]
+    Double,
+    Single,
+    // Whatever the first string in the file uses
+    Consistent,
+}
+
+#[derive(Clone, Copy, Default, Deserialize)]
+#[serde(default)]
+pub struct QuoteStyleConfig {
+    style: QuoteStyle,
+}
+
+pub struct QuoteStyleLint {
+    config: QuoteStyleConfig,
+}
+
+impl Rule for QuoteStyleLint {
+    type Config = QuoteStyleConfig;
+    type Error = Infallible;
+
+    fn new(config: Self::Config) -> Result<Self, Self::Error> {
+        Ok(QuoteStyleLint { config })
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let mut visitor = QuoteStyleVisitor {
+            strings: Vec::new(),
+        };
+
+        visitor.visit_ast(ast);
+
+        let preferred = match self.config.style {
+            QuoteStyle::Double => Quote::Double,
+            QuoteStyle::Single => Quote::Single,
+            QuoteStyle::Consistent => match visitor.strings.first() {
+                Some(string) => string.quote,
+                None => return Vec::new(),
+            },
+        };
+
+        visitor
+            .strings
+            .iter()
+            .filter(|string| string.quote != preferred)
+            // Switching quotes would mean escaping these
+            .filter(|string| !string.literal.contains(preferred.character()))
+            .map(|string| {
+                let (start, end) = string.range;
+
+                Diagnostic::new_complete(
+                    "quote_style",
+                    format!(
+                        "this string uses {} quotes, rather than {} quotes",
+                        string.quote.name(),
+                        preferred.name()
+                    ),
+                    Label::new((start, start + 1)),
+                    vec![format!(
+                        "help: try `{quote}{}{quote}`",
+                        string.literal,
+                        quote = preferred.character()
+                    )],
+                    vec![Label::new((end - 1, end))],
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Style
+    }
+
+    fn allow(&self) -> bool {
+        true
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Quote {
+    Double,
+    Single,
+}
+
+impl Quote {
+    fn character(self) -> char {
+        match self {
+            Quote::Double => '"',
+            Quote::Single => '\'',
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Quote::Double => "double",
+            Quote::Single => "single",
+        }
+    }
+}
+
+struct QuotedString {
+    literal: String,
+    quote: Quote,
+    range: (usize, usize),
+}
+
+struct QuoteStyleVisitor {
+    strings: Vec<QuotedString>,
+}
+
+impl Visitor<'_> for QuoteStyleVisitor {
+    fn visit_string_literal(&mut self, token: &TokenReference) {
+        if let TokenType::StringLiteral {
+            literal,
+            quote_type,
+            ..
+        } = &*token.token_type()
+        {
+            let quote = match quote_type {
+                StringLiteralQuoteType::Double => Quote::Double,
+                StringLiteralQuoteType::Single => Quote::Single,
+                // [[Long strings]] never need escaping, so they're left alone
+                StringLiteralQuoteType::Brackets => return,
+            };
+
+            // full-moon visits strings in expressions twice
+            let range = range(token);
+            if self.strings.iter().any(|string| string.range == range) {
+                return;
+            }
+
+            self.strings.push(QuotedString {
+                literal: literal.to_string(),
+                quote,
+                range,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_quote_style() {
+        test_lint(
+            QuoteStyleLint::new(QuoteStyleConfig::default()).unwrap(),
+            "quote_style",
+            "quote_style",
+        );
+    }
+
+    #[test]
+    fn test_single() {
+        test_lint(
+            QuoteStyleLint::new(QuoteStyleConfig {
+                style: QuoteStyle::Single,
+            })
+            .unwrap(),
+            "quote_style",
+            "single",
+        );
+    }
+
+    #[test]
+    fn test_consistent() {
+        test_lint(
+            QuoteStyleLint::new(QuoteStyleConfig {
+                style: QuoteStyle::Consistent,
+            })
+            .unwrap(),
+            "quote_style",
+            "consistent",
+        );
+    }
+}
@@ -0,0 +1,90 @@
+use super::*;
+use crate::ast_util::range;
+use std::convert::Infallible;
+
+use full_moon::{
+    ast::{self, Ast},
+    visitors::Visitor,
+};
+
+pub struct RedundantDoBlockLint;
+
+impl Rule for RedundantDoBlockLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(RedundantDoBlockLint)
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let mut visitor = RedundantDoBlockVisitor {
+            do_blocks: Vec::new(),
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .do_blocks
+            .iter()
+            .map(|(do_range, end_range)| {
+                Diagnostic::new_complete(
+                    "redundant_do_block",
+                    "this do block does not declare any locals".to_owned(),
+                    Label::new(*do_range),
+                    vec!["help: remove the `do` and `end`".to_owned()],
+                    vec![Label::new((end_range.0 as u32, end_range.1 as u32))],
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Style
+    }
+}
+
+struct RedundantDoBlockVisitor {
+    do_blocks: Vec<((usize, usize), (usize, usize))>,
+}
+
+impl Visitor<'_> for RedundantDoBlockVisitor {
+    fn visit_do(&mut self, do_block: &ast::Do) {
+        let block = do_block.block();
+
+        // `do return end` is how to return early, as `return` has to be the last statement of a block
+        if block.last_stmts().is_some() {
+            return;
+        }
+
+        let declares_locals = block.iter_stmts().any(|stmt| {
+            matches!(
+                stmt,
+                ast::Stmt::LocalAssignment(_) | ast::Stmt::LocalFunction(_)
+            )
+        });
+
+        if !declares_locals {
+            self.do_blocks
+                .push((range(do_block.do_token()), range(do_block.end_token())));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_redundant_do_block() {
+        test_lint(
+            RedundantDoBlockLint::new(()).unwrap(),
+            "redundant_do_block",
+            "redundant_do_block",
+        );
+    }
+}
@@ -0,0 +1,84 @@
+use super::*;
+use crate::ast_util::range;
+use std::convert::Infallible;
+
+use full_moon::{
+    ast::{self, Ast},
+    visitors::Visitor,
+};
+
+pub struct RedundantReturnLint;
+
+impl Rule for RedundantReturnLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(RedundantReturnLint)
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let mut visitor = RedundantReturnVisitor {
+            positions: Vec::new(),
+        };
+
+        check_block(ast.nodes(), &mut visitor.positions);
+        visitor.visit_ast(ast);
+
+        visitor
+            .positions
+            .iter()
+            .map(|position| {
+                Diagnostic::new_complete(
+                    "redundant_return",
+                    "this return is unnecessary".to_owned(),
+                    Label::new(*position),
+                    vec!["help: remove it, as the function returns here anyway".to_owned()],
+                    Vec::new(),
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Style
+    }
+}
+
+// Only the block of a function or the file itself is checked, as a `return` anywhere else
+// still skips the rest of the function
+fn check_block(block: &ast::Block, positions: &mut Vec<(usize, usize)>) {
+    if let Some(ast::LastStmt::Return(return_stmt)) = block.last_stmts() {
+        if return_stmt.returns().is_empty() {
+            positions.push(range(return_stmt));
+        }
+    }
+}
+
+struct RedundantReturnVisitor {
+    positions: Vec<(usize, usize)>,
+}
+
+impl Visitor<'_> for RedundantReturnVisitor {
+    fn visit_function_body(&mut self, body: &ast::FunctionBody) {
+        check_block(body.block(), &mut self.positions);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_redundant_return() {
+        test_lint(
+            RedundantReturnLint::new(()).unwrap(),
+            "redundant_return",
+            "redundant_return",
+        );
+    }
+}
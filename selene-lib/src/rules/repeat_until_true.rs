@@ -0,0 +1,142 @@
+use super::*;
+use crate::ast_util::{
+    expression_tree::ExpressionTree,
+    loops::{visit_loops, Loop, LoopStack},
+    range,
+};
+use std::convert::Infallible;
+
+use full_moon::{
+    ast::{self, Ast},
+    tokenizer::{Symbol, TokenType},
+    visitors::{Visit, Visitor},
+};
+
+pub struct RepeatUntilTrueLint;
+
+impl Rule for RepeatUntilTrueLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(RepeatUntilTrueLint)
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let mut visitor = RepeatUntilTrueVisitor {
+            repeats: Vec::new(),
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .repeats
+            .iter()
+            .map(|(position, breaks)| {
+                Diagnostic::new_complete(
+                    "repeat_until_true",
+                    "this loop only ever runs once".to_owned(),
+                    Label::new(*position),
+                    vec![if *breaks {
+                        "help: `break` is being used to exit early, try moving this into a function and using `return` instead".to_owned()
+                    } else {
+                        "help: try a `do ... end` block instead".to_owned()
+                    }],
+                    Vec::new(),
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Style
+    }
+}
+
+fn is_true(expression: &ast::Expression) -> bool {
+    if let Some(ast::Value::Symbol(symbol)) = ExpressionTree::new(expression).value() {
+        *symbol.token_type()
+            == TokenType::Symbol {
+                symbol: Symbol::True,
+            }
+    } else {
+        false
+    }
+}
+
+struct RepeatUntilTrueVisitor {
+    // The `repeat` token, and whether there is a `break` out of the loop
+    repeats: Vec<((usize, usize), bool)>,
+}
+
+impl Visitor<'_> for RepeatUntilTrueVisitor {
+    fn visit_repeat(&mut self, repeat: &ast::Repeat) {
+        if !is_true(repeat.until()) {
+            return;
+        }
+
+        let mut break_visitor = BreakVisitor {
+            breaks: false,
+            loops: LoopStack::new(),
+        };
+
+        repeat.block().visit(&mut break_visitor);
+
+        self.repeats
+            .push((range(repeat.repeat_token()), break_visitor.breaks));
+    }
+}
+
+// Looks for a `break` that leaves the loop itself, rather than a loop or function inside of it
+struct BreakVisitor {
+    breaks: bool,
+    loops: LoopStack<()>,
+}
+
+impl BreakVisitor {
+    fn open_loop(&mut self, _: Loop) {
+        self.loops.open_loop(());
+    }
+
+    fn close_loop(&mut self) {
+        self.loops.close_loop();
+    }
+}
+
+impl Visitor<'_> for BreakVisitor {
+    fn visit_function_body(&mut self, _: &ast::FunctionBody) {
+        self.loops.open_function();
+    }
+
+    fn visit_function_body_end(&mut self, _: &ast::FunctionBody) {
+        self.loops.close_function();
+    }
+
+    fn visit_last_stmt(&mut self, last_stmt: &ast::LastStmt) {
+        if let ast::LastStmt::Break(_) = last_stmt {
+            if !self.loops.in_function() && !self.loops.in_loop() {
+                self.breaks = true;
+            }
+        }
+    }
+
+    visit_loops!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_repeat_until_true() {
+        test_lint(
+            RepeatUntilTrueLint::new(()).unwrap(),
+            "repeat_until_true",
+            "repeat_until_true",
+        );
+    }
+}
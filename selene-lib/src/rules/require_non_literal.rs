@@ -0,0 +1,311 @@
+use super::*;
+use crate::ast_util::{range, string_literal};
+use std::{collections::HashSet, convert::Infallible};
+
+use full_moon::{
+    ast::{self, Ast},
+    visitors::Visitor,
+};
+use serde::Deserialize;
+
+// Methods that find an instance by a constant name, such as `script:WaitForChild("Module")`
+const ROBLOX_CHILD_METHODS: &[&str] = &["FindFirstChild", "GetService", "WaitForChild"];
+
+#[derive(Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct RequireNonLiteralConfig {
+    allow_concatenation: bool,
+    allow_patterns: Vec<String>,
+}
+
+pub struct RequireNonLiteralLint {
+    config: RequireNonLiteralConfig,
+}
+
+impl Rule for RequireNonLiteralLint {
+    type Config = RequireNonLiteralConfig;
+    type Error = Infallible;
+
+    fn new(config: Self::Config) -> Result<Self, Self::Error> {
+        Ok(RequireNonLiteralLint { config })
+    }
+
+    fn pass(&self, ast: &Ast, context: &Context) -> Vec<Diagnostic> {
+        let mut visitor = RequireNonLiteralVisitor {
+            config: &self.config,
+            definitions_of_require: vec!["require".to_owned()].into_iter().collect(),
+            positions: Vec::new(),
+            roblox: context.is_roblox(),
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .positions
+            .iter()
+            .map(|position| {
+                Diagnostic::new_complete(
+                    "require_non_literal",
+                    if visitor.roblox {
+                        "`require` is called with something other than a string literal or a constant path from `script` or `game`".to_owned()
+                    } else {
+                        "`require` is called with something other than a string literal".to_owned()
+                    },
+                    Label::new(*position),
+                    vec![
+                        "note: tools that follow requires, such as bundlers and circular_require, can't know which module this is".to_owned(),
+                    ],
+                    Vec::new(),
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Style
+    }
+}
+
+fn string_value(value: &ast::Value) -> Option<String> {
+    match value {
+        ast::Value::String(token) => string_literal(token),
+        _ => None,
+    }
+}
+
+fn string_argument(arguments: &ast::FunctionArgs) -> Option<String> {
+    match arguments {
+        ast::FunctionArgs::Parentheses { arguments, .. } if arguments.len() == 1 => {
+            match arguments.iter().next() {
+                Some(ast::Expression::Value { value, binop: None }) => string_value(value),
+                _ => None,
+            }
+        }
+
+        ast::FunctionArgs::String(token) => string_literal(token),
+        _ => None,
+    }
+}
+
+// The part of a required path that is known, such as `plugins/` in `require("plugins/" .. name)`
+struct StaticPath {
+    path: String,
+    // Whether the entire path is known
+    complete: bool,
+    // Whether this is a string literal concatenated with something else
+    concatenated: bool,
+}
+
+// `script.Parent.Module` or `game:GetService("ReplicatedStorage").Module`, as `script.Parent.Module`
+// or `game.ReplicatedStorage.Module`. Stops at the first part that isn't constant.
+fn roblox_path<'a, 'ast>(
+    prefix: &ast::Prefix,
+    suffixes: impl Iterator<Item = &'a ast::Suffix<'ast>>,
+) -> Option<StaticPath>
+where
+    'ast: 'a,
+{
+    let mut path = match prefix {
+        ast::Prefix::Name(name) if name.to_string() == "script" || name.to_string() == "game" => {
+            vec![name.to_string()]
+        }
+        _ => return None,
+    };
+
+    let mut complete = true;
+
+    for suffix in suffixes {
+        let name = match suffix {
+            ast::Suffix::Index(ast::Index::Dot { name, .. }) => Some(name.to_string()),
+
+            ast::Suffix::Index(ast::Index::Brackets {
+                expression: ast::Expression::Value { value, binop: None },
+                ..
+            }) => string_value(value),
+
+            ast::Suffix::Call(ast::Call::MethodCall(method_call))
+                if ROBLOX_CHILD_METHODS.contains(&method_call.name().to_string().as_str()) =>
+            {
+                string_argument(method_call.args())
+            }
+
+            _ => None,
+        };
+
+        match name {
+            Some(name) => path.push(name),
+            None => {
+                complete = false;
+                break;
+            }
+        }
+    }
+
+    Some(StaticPath {
+        path: path.join("."),
+        complete,
+        concatenated: false,
+    })
+}
+
+struct RequireNonLiteralVisitor<'a> {
+    config: &'a RequireNonLiteralConfig,
+    // `require`, and any locals defined as it, such as `local req = require`
+    definitions_of_require: HashSet<String>,
+    positions: Vec<(usize, usize)>,
+    roblox: bool,
+}
+
+impl RequireNonLiteralVisitor<'_> {
+    fn static_path(&self, argument: &ast::Expression) -> Option<StaticPath> {
+        match argument {
+            ast::Expression::Parentheses { expression, .. } => self.static_path(expression),
+
+            ast::Expression::Value { value, binop } => match binop {
+                None => match &**value {
+                    ast::Value::ParseExpression(expression) => self.static_path(expression),
+
+                    ast::Value::Var(ast::Var::Expression(var_expression)) if self.roblox => {
+                        roblox_path(var_expression.prefix(), var_expression.iter_suffixes())
+                    }
+
+                    ast::Value::FunctionCall(call) if self.roblox => {
+                        roblox_path(call.prefix(), call.iter_suffixes())
+                    }
+
+                    // Modules can be required by their asset ID
+                    ast::Value::Number(token) if self.roblox => Some(StaticPath {
+                        path: token.to_string(),
+                        complete: true,
+                        concatenated: false,
+                    }),
+
+                    value => string_value(value).map(|path| StaticPath {
+                        path,
+                        complete: true,
+                        concatenated: false,
+                    }),
+                },
+
+                // `require("modules/" .. name)`
+                Some(binop) if matches!(binop.bin_op(), ast::BinOp::TwoDots(_)) => {
+                    string_value(value).map(|path| StaticPath {
+                        path,
+                        complete: false,
+                        concatenated: true,
+                    })
+                }
+
+                Some(_) => None,
+            },
+
+            _ => None,
+        }
+    }
+
+    fn is_allowed(&self, argument: &ast::Expression) -> bool {
+        match self.static_path(argument) {
+            Some(static_path) => {
+                static_path.complete
+                    || (static_path.concatenated && self.config.allow_concatenation)
+                    || self
+                        .config
+                        .allow_patterns
+                        .iter()
+                        .any(|pattern| static_path.path.starts_with(pattern))
+            }
+
+            None => false,
+        }
+    }
+}
+
+impl Visitor<'_> for RequireNonLiteralVisitor<'_> {
+    fn visit_function_call(&mut self, call: &ast::FunctionCall) {
+        if_chain::if_chain! {
+            if let ast::Prefix::Name(name) = call.prefix();
+            if self.definitions_of_require.contains(&name.to_string());
+            let mut suffixes = call.iter_suffixes();
+            if let Some(ast::Suffix::Call(ast::Call::AnonymousCall(arguments))) = suffixes.next();
+            if suffixes.next().is_none();
+            then {
+                let argument = match arguments {
+                    ast::FunctionArgs::Parentheses { arguments, .. } => arguments.iter().next(),
+                    ast::FunctionArgs::String(_) => return,
+                    ast::FunctionArgs::TableConstructor(_) => None,
+                };
+
+                if !matches!(argument, Some(argument) if self.is_allowed(argument)) {
+                    self.positions.push(range(call));
+                }
+            }
+        }
+    }
+
+    fn visit_local_assignment(&mut self, node: &ast::LocalAssignment) {
+        for (name, expr) in node.name_list().iter().zip(node.expr_list().iter()) {
+            if_chain::if_chain! {
+                if let ast::Expression::Value { value, binop: None } = expr;
+                if let ast::Value::Var(ast::Var::Name(require)) = &**value;
+                if self.definitions_of_require.contains(&require.to_string());
+                then {
+                    self.definitions_of_require.insert(name.to_string());
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_require_non_literal() {
+        test_lint(
+            RequireNonLiteralLint::new(RequireNonLiteralConfig::default()).unwrap(),
+            "require_non_literal",
+            "require_non_literal",
+        );
+    }
+
+    #[test]
+    fn test_allow_concatenation() {
+        test_lint(
+            RequireNonLiteralLint::new(RequireNonLiteralConfig {
+                allow_concatenation: true,
+                ..RequireNonLiteralConfig::default()
+            })
+            .unwrap(),
+            "require_non_literal",
+            "allow_concatenation",
+        );
+    }
+
+    #[test]
+    fn test_allow_patterns() {
+        test_lint(
+            RequireNonLiteralLint::new(RequireNonLiteralConfig {
+                allow_patterns: vec!["plugins/".to_owned(), "script.Plugins".to_owned()],
+                ..RequireNonLiteralConfig::default()
+            })
+            .unwrap(),
+            "require_non_literal",
+            "allow_patterns",
+        );
+    }
+
+    #[cfg(feature = "roblox")]
+    #[test]
+    fn test_roblox() {
+        test_lint(
+            RequireNonLiteralLint::new(RequireNonLiteralConfig::default()).unwrap(),
+            "require_non_literal",
+            "roblox",
+        );
+    }
+}
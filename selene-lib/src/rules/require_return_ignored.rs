@@ -0,0 +1,170 @@
+use super::*;
+use crate::ast_util::{range, scopes::ScopeManager};
+use std::convert::Infallible;
+
+use full_moon::{
+    ast::{self, Ast},
+    node::Node,
+    tokenizer::TokenType,
+    visitors::Visitor,
+};
+use serde::Deserialize;
+
+#[derive(Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct RequireReturnIgnoredConfig {
+    side_effects_modules: Vec<String>,
+}
+
+pub struct RequireReturnIgnoredLint {
+    config: RequireReturnIgnoredConfig,
+}
+
+impl Rule for RequireReturnIgnoredLint {
+    type Config = RequireReturnIgnoredConfig;
+    type Error = Infallible;
+
+    fn new(config: Self::Config) -> Result<Self, Self::Error> {
+        Ok(RequireReturnIgnoredLint { config })
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let source = full_moon::print(ast);
+
+        let mut visitor = RequireReturnIgnoredVisitor {
+            requires: Vec::new(),
+            scope_manager: ScopeManager::new(ast),
+            source: &source,
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .requires
+            .iter()
+            .filter(|require| !self.config.side_effects_modules.contains(&require.module))
+            .map(|require| {
+                Diagnostic::new_complete(
+                    "require_return_ignored",
+                    format!("the module returned by requiring `{}` is discarded", require.module),
+                    Label::new(require.range),
+                    vec![
+                        "help: if this module is only required for its side effects, add it to `side_effects_modules`".to_owned(),
+                    ],
+                    Vec::new(),
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Correctness
+    }
+}
+
+struct Require {
+    // The string required, or the code for anything else, such as `script.Parent.Setup`
+    module: String,
+    range: (usize, usize),
+}
+
+struct RequireReturnIgnoredVisitor<'a> {
+    requires: Vec<Require>,
+    scope_manager: ScopeManager,
+    source: &'a str,
+}
+
+impl RequireReturnIgnoredVisitor<'_> {
+    fn module_name(&self, arguments: &ast::FunctionArgs) -> Option<String> {
+        let token = match arguments {
+            ast::FunctionArgs::Parentheses { arguments, .. } => match arguments.iter().next()? {
+                ast::Expression::Value { value, binop: None } => match &**value {
+                    ast::Value::String(token) => token,
+                    _ => return Some(self.text(arguments)),
+                },
+
+                _ => return Some(self.text(arguments)),
+            },
+
+            ast::FunctionArgs::String(token) => token,
+            ast::FunctionArgs::TableConstructor(_) => return None,
+        };
+
+        match &*token.token_type() {
+            TokenType::StringLiteral { literal, .. } => Some(literal.to_string()),
+            _ => None,
+        }
+    }
+
+    fn text<N: Node>(&self, node: N) -> String {
+        let (start, end) = range::<_, usize>(node);
+        self.source[start..end].trim().to_owned()
+    }
+}
+
+impl Visitor<'_> for RequireReturnIgnoredVisitor<'_> {
+    fn visit_stmt(&mut self, stmt: &ast::Stmt) {
+        // Only calls used as statements discard their results
+        let call = match stmt {
+            ast::Stmt::FunctionCall(call) => call,
+            _ => return,
+        };
+
+        if_chain::if_chain! {
+            if let ast::Prefix::Name(name) = call.prefix();
+            if name.to_string() == "require";
+            let mut suffixes = call.iter_suffixes();
+            if let Some(ast::Suffix::Call(ast::Call::AnonymousCall(arguments))) = suffixes.next();
+            if suffixes.next().is_none();
+            if let Some(module) = self.module_name(arguments);
+            then {
+                if let Some(reference) = self
+                    .scope_manager
+                    .reference_at_byte(call.start_position().unwrap().bytes())
+                {
+                    if reference.resolved.is_some() {
+                        return;
+                    }
+                }
+
+                self.requires.push(Require {
+                    module,
+                    range: range(call),
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_require_return_ignored() {
+        test_lint(
+            RequireReturnIgnoredLint::new(RequireReturnIgnoredConfig::default()).unwrap(),
+            "require_return_ignored",
+            "require_return_ignored",
+        );
+    }
+
+    #[test]
+    fn test_side_effects_modules() {
+        test_lint(
+            RequireReturnIgnoredLint::new(RequireReturnIgnoredConfig {
+                side_effects_modules: vec![
+                    "polyfills".to_owned(),
+                    "script.Parent.Setup".to_owned(),
+                ],
+            })
+            .unwrap(),
+            "require_return_ignored",
+            "side_effects_modules",
+        );
+    }
+}
@@ -0,0 +1,236 @@
+use super::*;
+use crate::ast_util::{
+    global_aliases::{GlobalAliases, GlobalPath},
+    range,
+};
+use std::{
+    collections::{BTreeMap, HashSet},
+    convert::Infallible,
+};
+
+use full_moon::{
+    ast::{self, Ast},
+    tokenizer::TokenReference,
+    visitors::Visitor,
+};
+use serde::Deserialize;
+
+// `debug.*` matches anything inside of `debug`, but not `debug` itself.
+// `os.execute` matches `os.execute`, and anything inside of it.
+fn matches(restricted: &str, path: &[String]) -> bool {
+    let (pattern, members_only) = match restricted.strip_suffix(".*") {
+        Some(prefix) => (prefix, true),
+        None => (restricted, false),
+    };
+
+    let pattern = pattern.split('.').collect::<Vec<_>>();
+
+    path.len() >= pattern.len() + members_only as usize
+        && path
+            .iter()
+            .zip(&pattern)
+            .all(|(part, pattern)| part == pattern)
+}
+
+#[derive(Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct RestrictedGlobalsConfig {
+    globals: Vec<String>,
+    // Explanations for entries in `globals`, such as what to use instead
+    messages: BTreeMap<String, String>,
+}
+
+impl RestrictedGlobalsConfig {
+    fn find(&self, path: &[String]) -> Option<&str> {
+        self.globals
+            .iter()
+            .find(|restricted| matches(restricted, path))
+            .map(String::as_str)
+    }
+}
+
+pub struct RestrictedGlobalsLint {
+    config: RestrictedGlobalsConfig,
+}
+
+impl Rule for RestrictedGlobalsLint {
+    type Config = RestrictedGlobalsConfig;
+    type Error = Infallible;
+
+    fn new(config: Self::Config) -> Result<Self, Self::Error> {
+        Ok(RestrictedGlobalsLint { config })
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        if self.config.globals.is_empty() {
+            return Vec::new();
+        }
+
+        let mut visitor = RestrictedGlobalsVisitor {
+            aliases: GlobalAliases::new(ast),
+            config: &self.config,
+            restricted_aliases: HashSet::new(),
+            uses: Vec::new(),
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .uses
+            .into_iter()
+            .map(|restricted_use| {
+                Diagnostic::new_complete(
+                    "restricted_globals",
+                    format!("use of `{}` is restricted", restricted_use.path.join(".")),
+                    Label::new(restricted_use.range),
+                    self.config
+                        .messages
+                        .get(restricted_use.restricted)
+                        .map(|message| format!("note: {}", message))
+                        .into_iter()
+                        .collect(),
+                    Vec::new(),
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Correctness
+    }
+}
+
+struct RestrictedUse<'a> {
+    path: Vec<String>,
+    range: (usize, usize),
+    // The entry in `globals` that this matched
+    restricted: &'a str,
+}
+
+struct RestrictedGlobalsVisitor<'a> {
+    aliases: GlobalAliases,
+    config: &'a RestrictedGlobalsConfig,
+    // Aliases that were reported themselves, so uses through them don't need to be.
+    // By the range of their declaration.
+    restricted_aliases: HashSet<(usize, usize)>,
+    uses: Vec<RestrictedUse<'a>>,
+}
+
+impl<'a> RestrictedGlobalsVisitor<'a> {
+    fn is_restricted_alias(&self, global: &GlobalPath) -> bool {
+        matches!(global.alias, Some(alias) if self.restricted_aliases.contains(&alias))
+    }
+
+    fn check<'b, 'ast: 'b>(
+        &mut self,
+        name: &TokenReference,
+        suffixes: impl Iterator<Item = &'b ast::Suffix<'ast>>,
+    ) -> bool {
+        let global = match self.aliases.path(name, suffixes) {
+            Some(global) => global,
+            None => return false,
+        };
+
+        if self.is_restricted_alias(&global) {
+            return false;
+        }
+
+        match self.config.find(&global.path) {
+            Some(restricted) => {
+                self.uses.push(RestrictedUse {
+                    path: global.path,
+                    range: global.range,
+                    restricted,
+                });
+
+                true
+            }
+
+            None => false,
+        }
+    }
+}
+
+impl Visitor<'_> for RestrictedGlobalsVisitor<'_> {
+    fn visit_function_call(&mut self, call: &ast::FunctionCall) {
+        if let ast::Prefix::Name(name) = call.prefix() {
+            self.check(name, call.iter_suffixes());
+        }
+    }
+
+    fn visit_local_assignment(&mut self, local_assignment: &ast::LocalAssignment) {
+        for (name, expression) in local_assignment
+            .name_list()
+            .iter()
+            .zip(local_assignment.expr_list().iter())
+        {
+            if let Some((_, global)) = self.aliases.aliased_global(expression) {
+                if self.is_restricted_alias(&global) || self.config.find(&global.path).is_some() {
+                    self.restricted_aliases.insert(range(name));
+                }
+
+                self.aliases.insert(name, global.path);
+            }
+        }
+    }
+
+    fn visit_var(&mut self, var: &ast::Var) {
+        match var {
+            ast::Var::Expression(var_expression) => {
+                if let ast::Prefix::Name(name) = var_expression.prefix() {
+                    self.check(name, var_expression.iter_suffixes());
+                }
+            }
+
+            ast::Var::Name(name) => {
+                self.check(name, std::iter::empty());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_matches() {
+        let path = |path: &str| path.split('.').map(ToOwned::to_owned).collect::<Vec<_>>();
+
+        assert!(matches("debug", &path("debug")));
+        assert!(matches("debug", &path("debug.sethook")));
+        assert!(!matches("debug.*", &path("debug")));
+        assert!(matches("debug.*", &path("debug.sethook")));
+        assert!(matches("os.execute", &path("os.execute")));
+        assert!(!matches("os.execute", &path("os")));
+        assert!(!matches("os.execute", &path("os.exit")));
+        assert!(!matches("debug", &path("debugger")));
+    }
+
+    #[test]
+    fn test_restricted_globals() {
+        test_lint(
+            RestrictedGlobalsLint::new(RestrictedGlobalsConfig {
+                globals: vec![
+                    "getfenv".to_owned(),
+                    "debug.*".to_owned(),
+                    "os.execute".to_owned(),
+                    "LoadLibrary".to_owned(),
+                ],
+                messages: vec![(
+                    "os.execute".to_owned(),
+                    "use `Process.run` instead, which is sandboxed".to_owned(),
+                )]
+                .into_iter()
+                .collect(),
+            })
+            .unwrap(),
+            "restricted_globals",
+            "restricted_globals",
+        );
+    }
+}
@@ -0,0 +1,100 @@
+use super::*;
+use crate::ast_util::range;
+use std::convert::Infallible;
+
+use full_moon::{
+    ast::{self, Ast},
+    visitors::Visitor,
+};
+
+pub struct InstanceNewParentLint;
+
+impl Rule for InstanceNewParentLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(InstanceNewParentLint)
+    }
+
+    fn pass(&self, ast: &Ast, context: &Context) -> Vec<Diagnostic> {
+        if !context.is_roblox() {
+            return Vec::new();
+        }
+
+        let mut visitor = InstanceNewParentVisitor::default();
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .positions
+            .iter()
+            .map(|position| {
+                Diagnostic::new_complete(
+                    "roblox_instance_new_parent",
+                    "Instance.new should not be given a parent".to_owned(),
+                    Label::new(*position),
+                    vec![
+                        "note: setting the parent before the properties means every property change has to be replicated".to_owned(),
+                        "help: set `.Parent` after setting the other properties instead".to_owned(),
+                    ],
+                    Vec::new(),
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Performance
+    }
+}
+
+#[derive(Default)]
+struct InstanceNewParentVisitor {
+    positions: Vec<(usize, usize)>,
+}
+
+impl Visitor<'_> for InstanceNewParentVisitor {
+    fn visit_function_call(&mut self, call: &ast::FunctionCall) {
+        if_chain::if_chain! {
+            if let ast::Prefix::Name(token) = call.prefix();
+            if token.to_string() == "Instance";
+            let mut suffixes = call.iter_suffixes().collect::<Vec<_>>();
+
+            if suffixes.len() == 2; // .new and ()
+            let call_suffix = suffixes.pop().unwrap();
+            let index_suffix = suffixes.pop().unwrap();
+
+            if let ast::Suffix::Index(index) = index_suffix;
+            if let ast::Index::Dot { name, .. } = index;
+            if name.to_string() == "new";
+
+            if let ast::Suffix::Call(call) = call_suffix;
+            if let ast::Call::AnonymousCall(args) = call;
+            if let ast::FunctionArgs::Parentheses { arguments, .. } = args;
+            if arguments.len() == 2;
+
+            then {
+                self.positions.push(range(arguments.iter().nth(1).unwrap()));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_roblox_instance_new_parent() {
+        test_lint(
+            InstanceNewParentLint::new(()).unwrap(),
+            "roblox_instance_new_parent",
+            "roblox_instance_new_parent",
+        );
+    }
+}
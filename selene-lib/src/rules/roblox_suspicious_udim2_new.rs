@@ -0,0 +1,136 @@
+use super::*;
+use crate::ast_util::{number_literal, range};
+use std::convert::Infallible;
+
+use full_moon::{
+    ast::{self, Ast},
+    visitors::Visitor,
+};
+
+pub struct SuspiciousUDim2NewLint;
+
+impl Rule for SuspiciousUDim2NewLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(SuspiciousUDim2NewLint)
+    }
+
+    fn pass(&self, ast: &Ast, context: &Context) -> Vec<Diagnostic> {
+        if !context.is_roblox() {
+            return Vec::new();
+        }
+
+        let mut visitor = SuspiciousUDim2NewVisitor::default();
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .calls
+            .iter()
+            .map(|call| match call {
+                SuspiciousCall::TwoArguments(range) => Diagnostic::new_complete(
+                    "roblox_suspicious_udim2_new",
+                    "UDim2.new takes 4 arguments, but only 2 were passed".to_owned(),
+                    Label::new(*range),
+                    vec![
+                        "help: did you mean to use UDim2.fromScale or UDim2.fromOffset instead?"
+                            .to_owned(),
+                    ],
+                    Vec::new(),
+                ),
+
+                SuspiciousCall::SwappedOffsets(range) => Diagnostic::new_complete(
+                    "roblox_suspicious_udim2_new",
+                    "UDim2.new was given scales as offsets, and offsets as scales".to_owned(),
+                    Label::new(*range),
+                    vec![
+                        "note: the arguments are (xScale, xOffset, yScale, yOffset)".to_owned(),
+                        "help: if you only need one kind, use UDim2.fromScale or UDim2.fromOffset instead".to_owned(),
+                    ],
+                    Vec::new(),
+                ),
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Correctness
+    }
+}
+
+enum SuspiciousCall {
+    TwoArguments((usize, usize)),
+    SwappedOffsets((usize, usize)),
+}
+
+#[derive(Default)]
+struct SuspiciousUDim2NewVisitor {
+    calls: Vec<SuspiciousCall>,
+}
+
+impl Visitor<'_> for SuspiciousUDim2NewVisitor {
+    fn visit_function_call(&mut self, call: &ast::FunctionCall) {
+        if_chain::if_chain! {
+            if let ast::Prefix::Name(token) = call.prefix();
+            if token.to_string() == "UDim2";
+            let mut suffixes = call.iter_suffixes().collect::<Vec<_>>();
+
+            if suffixes.len() == 2; // .new and ()
+            let call_suffix = suffixes.pop().unwrap();
+            let index_suffix = suffixes.pop().unwrap();
+
+            if let ast::Suffix::Index(index) = index_suffix;
+            if let ast::Index::Dot { name, .. } = index;
+            if name.to_string() == "new";
+
+            if let ast::Suffix::Call(call_suffix) = call_suffix;
+            if let ast::Call::AnonymousCall(args) = call_suffix;
+            if let ast::FunctionArgs::Parentheses { arguments, .. } = args;
+
+            let numbers = arguments.iter().map(number_literal).collect::<Option<Vec<_>>>();
+            if let Some(numbers) = numbers;
+
+            then {
+                match numbers.as_slice() {
+                    [_, _] => self.calls.push(SuspiciousCall::TwoArguments(range(call))),
+
+                    // UDim2.new(100, 0.5, 50, 0.5)
+                    [x_scale, x_offset, y_scale, y_offset] => {
+                        let is_scale = |number: f64| number > 0.0 && number < 1.0;
+                        let is_offset = |number: f64| number > 1.0 && number.fract() == 0.0;
+
+                        if is_offset(*x_scale)
+                            && is_scale(*x_offset)
+                            && is_offset(*y_scale)
+                            && is_scale(*y_offset)
+                        {
+                            self.calls.push(SuspiciousCall::SwappedOffsets(range(call)));
+                        }
+                    }
+
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_roblox_suspicious_udim2_new() {
+        test_lint(
+            SuspiciousUDim2NewLint::new(()).unwrap(),
+            "roblox_suspicious_udim2_new",
+            "roblox_suspicious_udim2_new",
+        );
+    }
+}
@@ -0,0 +1,155 @@
+use super::*;
+use crate::ast_util::range;
+use std::convert::Infallible;
+
+use full_moon::{
+    ast::{self, Ast},
+    visitors::{Visit, Visitor},
+};
+
+pub struct SelfAssignmentLint;
+
+impl Rule for SelfAssignmentLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(SelfAssignmentLint)
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let mut visitor = SelfAssignmentVisitor {
+            self_assignments: Vec::new(),
+            source: full_moon::print(ast),
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .self_assignments
+            .iter()
+            .map(|self_assignment| {
+                Diagnostic::new_complete(
+                    "self_assignment",
+                    format!("`{}` is assigned to itself", self_assignment.name),
+                    Label::new(self_assignment.var),
+                    vec![
+                        "note: this does nothing, did you mean to assign something else?"
+                            .to_owned(),
+                    ],
+                    vec![Label::new_with_message(
+                        (
+                            self_assignment.value.0 as u32,
+                            self_assignment.value.1 as u32,
+                        ),
+                        "assigned here".to_owned(),
+                    )],
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Correctness
+    }
+}
+
+struct SelfAssignment {
+    name: String,
+    var: (usize, usize),
+    value: (usize, usize),
+}
+
+struct SelfAssignmentVisitor {
+    self_assignments: Vec<SelfAssignment>,
+    // Compared by source text, see almost_swapped
+    source: String,
+}
+
+impl SelfAssignmentVisitor {
+    fn text(&self, range: (usize, usize)) -> &str {
+        self.source[range.0..range.1].trim()
+    }
+}
+
+// full-moon ends the range of `t[x]` at `x`, so this includes the `]`
+fn var_range(var: &ast::Var) -> (usize, usize) {
+    let (start, end) = range(var);
+
+    match var {
+        ast::Var::Expression(var_expression) => match var_expression.iter_suffixes().last() {
+            Some(ast::Suffix::Index(ast::Index::Brackets { brackets, .. })) => {
+                (start, range::<_, usize>(brackets.tokens().1).1)
+            }
+            _ => (start, end),
+        },
+
+        ast::Var::Name(_) => (start, end),
+    }
+}
+
+#[derive(Default)]
+struct CallVisitor {
+    calls: bool,
+}
+
+impl Visitor<'_> for CallVisitor {
+    fn visit_function_call(&mut self, _: &ast::FunctionCall) {
+        self.calls = true;
+    }
+}
+
+// `t[f()] = t[f()]` can do something, as `f` might return something different each time
+fn has_call(var: &ast::Var) -> bool {
+    let mut visitor = CallVisitor::default();
+    var.visit(&mut visitor);
+    visitor.calls
+}
+
+impl Visitor<'_> for SelfAssignmentVisitor {
+    fn visit_assignment(&mut self, assignment: &ast::Assignment) {
+        // Every value is evaluated before anything is assigned, so positions can be checked alone
+        for (var, expression) in assignment.var_list().iter().zip(assignment.expr_list()) {
+            let value = match expression {
+                ast::Expression::Value { value, binop: None } => match &**value {
+                    ast::Value::Var(value) => value,
+                    _ => continue,
+                },
+
+                _ => continue,
+            };
+
+            if has_call(var) || has_call(value) {
+                continue;
+            }
+
+            let (var, value) = (var_range(var), var_range(value));
+
+            if self.text(var) == self.text(value) {
+                self.self_assignments.push(SelfAssignment {
+                    name: self.text(var).to_owned(),
+                    var,
+                    value,
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_self_assignment() {
+        test_lint(
+            SelfAssignmentLint::new(()).unwrap(),
+            "self_assignment",
+            "self_assignment",
+        );
+    }
+}
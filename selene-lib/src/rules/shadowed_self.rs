@@ -0,0 +1,147 @@
+use super::*;
+use crate::ast_util::{
+    range,
+    scopes::{self, ScopeManager},
+};
+use std::{collections::HashSet, convert::Infallible};
+
+use full_moon::{
+    ast::{self, Ast},
+    visitors::Visitor,
+};
+
+pub struct ShadowedSelfLint;
+
+impl Rule for ShadowedSelfLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(ShadowedSelfLint)
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let mut visitor = ShadowedSelfVisitor::default();
+        visitor.visit_ast(ast);
+
+        let scope_manager = ScopeManager::new(ast);
+        let mut diagnostics = Vec::new();
+
+        // The implicit `self` is defined with the method name as its identifier
+        let implicit_self = |id| {
+            let variable: &scopes::Variable = &scope_manager.variables[id];
+            variable.name == "self" && visitor.method_names.contains(&variable.identifiers[0])
+        };
+
+        let implicit_range = |id| {
+            let (start, end) = scope_manager.variables[id].identifiers[0];
+            (start as u32, end as u32)
+        };
+
+        for (_, variable) in &scope_manager.variables {
+            if variable.name != "self" || visitor.parameters.contains(&variable.identifiers[0]) {
+                continue;
+            }
+
+            if let Some(shadowed) = variable.shadowed {
+                if implicit_self(shadowed) {
+                    diagnostics.push(Diagnostic::new_complete(
+                        "shadowed_self",
+                        "shadowing the implicit `self` of this method".to_owned(),
+                        Label::new(variable.identifiers[0]),
+                        vec!["member accesses through `self` after this will not refer to the method's object".to_owned()],
+                        vec![Label::new_with_message(
+                            implicit_range(shadowed),
+                            "implicit `self` defined here".to_owned(),
+                        )],
+                    ));
+                }
+            }
+        }
+
+        // ScopeManager repeats references
+        let mut checked = HashSet::new();
+
+        for (_, reference) in &scope_manager.references {
+            if !reference.write || !checked.insert(reference.identifier) {
+                continue;
+            }
+
+            // `self.x = 1` is recorded as a write to `self`, but only `self = x` reassigns it
+            if !visitor.assigned_names.contains(&reference.identifier) {
+                continue;
+            }
+
+            if let Some(resolved) = reference.resolved {
+                if implicit_self(resolved) {
+                    diagnostics.push(Diagnostic::new_complete(
+                        "shadowed_self",
+                        "reassigning the implicit `self` of this method".to_owned(),
+                        Label::new(reference.identifier),
+                        vec!["member accesses through `self` after this will not refer to the method's object".to_owned()],
+                        vec![Label::new_with_message(
+                            implicit_range(resolved),
+                            "implicit `self` defined here".to_owned(),
+                        )],
+                    ));
+                }
+            }
+        }
+
+        diagnostics
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Correctness
+    }
+}
+
+#[derive(Default)]
+struct ShadowedSelfVisitor {
+    // Names assigned to directly, such as `self` in `self = x`
+    assigned_names: HashSet<(usize, usize)>,
+    method_names: HashSet<(usize, usize)>,
+    parameters: HashSet<(usize, usize)>,
+}
+
+impl Visitor<'_> for ShadowedSelfVisitor {
+    fn visit_function_body(&mut self, body: &ast::FunctionBody) {
+        for parameter in body.iter_parameters() {
+            if let ast::Parameter::Name(name) = parameter {
+                self.parameters.insert(range(name));
+            }
+        }
+    }
+
+    fn visit_function_declaration(&mut self, declaration: &ast::FunctionDeclaration) {
+        if let Some(method_name) = declaration.name().method_name() {
+            self.method_names.insert(range(method_name));
+        }
+    }
+
+    fn visit_assignment(&mut self, assignment: &ast::Assignment) {
+        for var in assignment.var_list() {
+            if let ast::Var::Name(name) = var {
+                self.assigned_names.insert(range(name));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_shadowed_self() {
+        test_lint(
+            ShadowedSelfLint::new(()).unwrap(),
+            "shadowed_self",
+            "shadowed_self",
+        );
+    }
+}
@@ -0,0 +1,194 @@
+use super::*;
+use crate::ast_util::{
+    expression_tree::ExpressionTree,
+    loops::{self, visit_loops, LoopStack},
+    range,
+};
+use std::convert::Infallible;
+
+use full_moon::{
+    ast::{self, Ast},
+    visitors::Visitor,
+};
+
+pub struct StringConcatInLoopLint;
+
+impl Rule for StringConcatInLoopLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(StringConcatInLoopLint)
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let mut visitor = StringConcatInLoopVisitor {
+            ast_as_string: full_moon::print(ast),
+            concatenations: Vec::new(),
+            loops: LoopStack::new(),
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .concatenations
+            .iter()
+            .map(|concatenation| {
+                Diagnostic::new_complete(
+                    "string_concat_in_loop",
+                    format!(
+                        "concatenating onto `{}` inside of a loop",
+                        concatenation.name
+                    ),
+                    Label::new(concatenation.range),
+                    vec![
+                        "help: insert the strings into a table, and use `table.concat` after the loop"
+                            .to_owned(),
+                    ],
+                    Vec::new(),
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Performance
+    }
+}
+
+struct Concatenation {
+    name: String,
+    range: (usize, usize),
+}
+
+#[derive(Default)]
+struct Loop {
+    concatenations: Vec<Concatenation>,
+    uses_table_concat: bool,
+}
+
+struct StringConcatInLoopVisitor {
+    // Compared by source text, see almost_swapped
+    ast_as_string: String,
+    concatenations: Vec<Concatenation>,
+    loops: LoopStack<Loop>,
+}
+
+fn concatenated<'a, 'ast>(
+    tree: &'a ExpressionTree<'a, 'ast>,
+    operands: &mut Vec<&'a ExpressionTree<'a, 'ast>>,
+) {
+    match tree {
+        ExpressionTree::Binary {
+            lhs,
+            op: ast::BinOp::TwoDots(_),
+            rhs,
+        } => {
+            concatenated(lhs, operands);
+            concatenated(rhs, operands);
+        }
+
+        _ => operands.push(tree),
+    }
+}
+
+impl StringConcatInLoopVisitor {
+    fn text(&self, range: (usize, usize)) -> &str {
+        &self.ast_as_string[range.0..range.1]
+    }
+
+    fn open_loop(&mut self, _: loops::Loop) {
+        self.loops.open_loop(Loop::default());
+    }
+
+    fn close_loop(&mut self) {
+        let current = self.loops.close_loop();
+
+        // Loops inside of loops are part of the outer loop too, so it gets the final say
+        match self.loops.current_mut() {
+            Some(parent) => {
+                if current.uses_table_concat {
+                    parent.uses_table_concat = true;
+                } else {
+                    parent.concatenations.extend(current.concatenations);
+                }
+            }
+
+            None => {
+                if !current.uses_table_concat {
+                    self.concatenations.extend(current.concatenations);
+                }
+            }
+        }
+    }
+}
+
+impl Visitor<'_> for StringConcatInLoopVisitor {
+    fn visit_assignment(&mut self, assignment: &ast::Assignment) {
+        if !self.loops.in_loop() {
+            return;
+        }
+
+        for (var, expression) in assignment.var_list().iter().zip(assignment.expr_list()) {
+            let name = self.text(range(var)).to_owned();
+            let tree = ExpressionTree::new(expression);
+
+            let mut operands = Vec::new();
+            concatenated(&tree, &mut operands);
+
+            if operands.len() > 1
+                && operands
+                    .iter()
+                    .any(|operand| self.text(operand.range()) == name)
+            {
+                if let Some(current) = self.loops.current_mut() {
+                    current.concatenations.push(Concatenation {
+                        name,
+                        range: (range::<_, usize>(var).0, range::<_, usize>(expression).1),
+                    });
+                }
+            }
+        }
+    }
+
+    fn visit_function_body(&mut self, _: &ast::FunctionBody) {
+        self.loops.open_function();
+    }
+
+    fn visit_function_body_end(&mut self, _: &ast::FunctionBody) {
+        self.loops.close_function();
+    }
+
+    fn visit_function_call(&mut self, call: &ast::FunctionCall) {
+        if_chain::if_chain! {
+            if let Some(current) = self.loops.current_mut();
+            if let ast::Prefix::Name(name) = call.prefix();
+            if name.to_string() == "table";
+            if let Some(ast::Suffix::Index(ast::Index::Dot { name, .. })) = call.iter_suffixes().next();
+            if name.to_string() == "concat";
+            then {
+                current.uses_table_concat = true;
+            }
+        }
+    }
+
+    visit_loops!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_string_concat_in_loop() {
+        test_lint(
+            StringConcatInLoopLint::new(()).unwrap(),
+            "string_concat_in_loop",
+            "string_concat_in_loop",
+        );
+    }
+}
@@ -0,0 +1,226 @@
+use super::*;
+use crate::ast_util::{expression_string_literal, is_multiple_values, range, scopes::ScopeManager};
+use std::convert::Infallible;
+
+use full_moon::{
+    ast::{self, Ast},
+    node::Node,
+    visitors::Visitor,
+};
+
+pub struct StringFormatArgMismatchLint;
+
+impl Rule for StringFormatArgMismatchLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(StringFormatArgMismatchLint)
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let mut visitor = StringFormatArgMismatchVisitor {
+            mismatches: Vec::new(),
+            scope_manager: ScopeManager::new(ast),
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .mismatches
+            .iter()
+            .map(|mismatch| {
+                Diagnostic::new_complete(
+                    "string_format_arg_mismatch",
+                    format!(
+                        "format string expects {} {}, but {} {} passed",
+                        mismatch.expected,
+                        plural(mismatch.expected, "argument", "arguments"),
+                        mismatch.passed,
+                        plural(mismatch.passed, "was", "were"),
+                    ),
+                    Label::new(mismatch.call),
+                    Vec::new(),
+                    vec![Label::new_with_message(
+                        (mismatch.format.0 as u32, mismatch.format.1 as u32),
+                        format!(
+                            "{} {} here",
+                            mismatch.expected,
+                            plural(mismatch.expected, "specifier", "specifiers")
+                        ),
+                    )],
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Correctness
+    }
+}
+
+fn plural(count: usize, singular: &'static str, plural: &'static str) -> &'static str {
+    if count == 1 {
+        singular
+    } else {
+        plural
+    }
+}
+
+// Counts the arguments a format string uses. `%%` is a literal percent sign, and doesn't use one.
+// Returns None if the format string is not valid, as Lua will error for a different reason.
+fn count_specifiers(format: &str) -> Option<usize> {
+    let mut chars = format.chars().peekable();
+    let mut count = 0;
+
+    while let Some(character) = chars.next() {
+        if character != '%' {
+            continue;
+        }
+
+        if chars.peek() == Some(&'%') {
+            chars.next();
+            continue;
+        }
+
+        // Flags, width, and precision
+        while let Some(next) = chars.peek() {
+            if "-+ #0.".contains(*next) || next.is_ascii_digit() {
+                chars.next();
+            } else {
+                break;
+            }
+        }
+
+        match chars.next() {
+            Some(conversion) if "cdiouxXeEfgGqsaA".contains(conversion) => count += 1,
+            _ => return None,
+        }
+    }
+
+    Some(count)
+}
+
+struct Mismatch {
+    call: (usize, usize),
+    format: (usize, usize),
+    expected: usize,
+    passed: usize,
+}
+
+struct StringFormatArgMismatchVisitor {
+    mismatches: Vec<Mismatch>,
+    scope_manager: ScopeManager,
+}
+
+impl StringFormatArgMismatchVisitor {
+    fn check<'a, 'ast>(
+        &mut self,
+        call: &ast::FunctionCall,
+        format: &'a ast::Expression<'ast>,
+        arguments: Vec<&'a ast::Expression<'ast>>,
+    ) {
+        let (format, format_range) = match expression_string_literal(format) {
+            Some((format, token)) => (format, range(token)),
+            None => return,
+        };
+
+        let expected = match count_specifiers(&format) {
+            Some(expected) => expected,
+            None => return,
+        };
+
+        let passed = arguments.len();
+        let multiple_values = matches!(arguments.last(), Some(last) if is_multiple_values(last));
+
+        // `string.format("%s %s", ...)` could be passing any amount of arguments
+        let mismatched = if multiple_values {
+            passed - 1 > expected
+        } else {
+            passed != expected
+        };
+
+        if mismatched {
+            self.mismatches.push(Mismatch {
+                call: range(call),
+                format: format_range,
+                expected,
+                passed,
+            });
+        }
+    }
+}
+
+impl Visitor<'_> for StringFormatArgMismatchVisitor {
+    fn visit_function_call(&mut self, call: &ast::FunctionCall) {
+        let mut suffixes = call.iter_suffixes();
+
+        match (call.prefix(), suffixes.next()) {
+            // string.format("%d", x)
+            (
+                ast::Prefix::Name(name),
+                Some(ast::Suffix::Index(ast::Index::Dot { name: field, .. })),
+            ) if name.to_string() == "string" && field.to_string() == "format" => {
+                if let Some(reference) = self
+                    .scope_manager
+                    .reference_at_byte(call.start_position().unwrap().bytes())
+                {
+                    if reference.resolved.is_some() {
+                        return;
+                    }
+                }
+
+                if let Some(ast::Suffix::Call(ast::Call::AnonymousCall(
+                    ast::FunctionArgs::Parentheses { arguments, .. },
+                ))) = suffixes.next()
+                {
+                    let mut arguments = arguments.iter();
+
+                    if let Some(format) = arguments.next() {
+                        self.check(call, format, arguments.collect());
+                    }
+                }
+            }
+
+            // ("%d"):format(x)
+            (
+                ast::Prefix::Expression(format),
+                Some(ast::Suffix::Call(ast::Call::MethodCall(method_call))),
+            ) if method_call.name().to_string() == "format" => {
+                if let ast::FunctionArgs::Parentheses { arguments, .. } = method_call.args() {
+                    self.check(call, format, arguments.iter().collect());
+                }
+            }
+
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_count_specifiers() {
+        assert_eq!(count_specifiers("hello"), Some(0));
+        assert_eq!(count_specifiers("%d %s"), Some(2));
+        assert_eq!(count_specifiers("100%%"), Some(0));
+        assert_eq!(count_specifiers("%-5.2f%%, %03d"), Some(2));
+        assert_eq!(count_specifiers("%"), None);
+        assert_eq!(count_specifiers("%y"), None);
+    }
+
+    #[test]
+    fn test_string_format_arg_mismatch() {
+        test_lint(
+            StringFormatArgMismatchLint::new(()).unwrap(),
+            "string_format_arg_mismatch",
+            "string_format_arg_mismatch",
+        );
+    }
+}
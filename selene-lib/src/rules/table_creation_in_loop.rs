@@ -0,0 +1,218 @@
+use super::*;
+use crate::ast_util::{
+    call_name,
+    expression_tree::ExpressionTree,
+    loops::{visit_loops, Loop, LoopStack},
+    range,
+    scopes::ScopeManager,
+};
+use std::{
+    collections::{HashMap, HashSet},
+    convert::Infallible,
+};
+
+use full_moon::{
+    ast::{self, Ast},
+    visitors::Visitor,
+};
+
+// Functions that use a table passed as their first argument without keeping it
+const TRANSIENT_FUNCTIONS: &[&str] = &[
+    "ipairs",
+    "next",
+    "pairs",
+    "rawget",
+    "rawlen",
+    "rawset",
+    "table.concat",
+    "table.insert",
+    "table.remove",
+    "table.sort",
+    "table.unpack",
+    "unpack",
+];
+
+pub struct TableCreationInLoopLint;
+
+impl Rule for TableCreationInLoopLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(TableCreationInLoopLint)
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let mut visitor = TableCreationInLoopVisitor {
+            function_bodies: Vec::new(),
+            loops: LoopStack::new(),
+            safe_uses: HashSet::new(),
+            table_locals: HashMap::new(),
+        };
+
+        visitor.visit_ast(ast);
+
+        let scope_manager = ScopeManager::new(ast);
+        let mut diagnostics = Vec::new();
+
+        for (_, variable) in &scope_manager.variables {
+            let declaration = variable.identifiers[0];
+
+            let constructor = match visitor.table_locals.get(&declaration) {
+                Some(constructor) => *constructor,
+                None => continue,
+            };
+
+            // Closures could keep the table around, even if they only index it
+            let in_closure = |identifier: (usize, usize)| {
+                visitor.function_bodies.iter().any(|body| {
+                    body.0 <= identifier.0
+                        && identifier.1 <= body.1
+                        && !(body.0 <= declaration.0 && declaration.1 <= body.1)
+                })
+            };
+
+            let transient = variable.references.iter().all(|reference| {
+                let identifier = scope_manager.references[*reference].identifier;
+
+                identifier == declaration
+                    || (visitor.safe_uses.contains(&identifier) && !in_closure(identifier))
+            });
+
+            if transient {
+                diagnostics.push(Diagnostic::new_complete(
+                    "table_creation_in_loop",
+                    format!(
+                        "`{}` is a new table every iteration of this loop",
+                        variable.name
+                    ),
+                    Label::new(constructor),
+                    vec![
+                        "help: create the table once before the loop, and clear it at the start of each iteration".to_owned(),
+                    ],
+                    Vec::new(),
+                ));
+            }
+        }
+
+        diagnostics
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Performance
+    }
+}
+
+struct TableCreationInLoopVisitor {
+    function_bodies: Vec<(usize, usize)>,
+    loops: LoopStack<()>,
+    // Names that are used without the table escaping, such as `t` in `t.x` or `#t`
+    safe_uses: HashSet<(usize, usize)>,
+    // Locals declared as a table constructor inside of a loop, and the range of the constructor
+    table_locals: HashMap<(usize, usize), (usize, usize)>,
+}
+
+impl TableCreationInLoopVisitor {
+    fn open_loop(&mut self, _: Loop) {
+        self.loops.open_loop(());
+    }
+
+    fn close_loop(&mut self) {
+        self.loops.close_loop();
+    }
+
+    fn indexed_prefix(&mut self, prefix: &ast::Prefix, first_suffix: Option<&ast::Suffix>) {
+        if let (ast::Prefix::Name(name), Some(ast::Suffix::Index(_))) = (prefix, first_suffix) {
+            self.safe_uses.insert(range(name));
+        }
+    }
+
+    fn safe_name(&mut self, expression: &ast::Expression) {
+        if let Some(ast::Value::Var(ast::Var::Name(name))) = ExpressionTree::new(expression).value()
+        {
+            self.safe_uses.insert(range(name));
+        }
+    }
+}
+
+impl Visitor<'_> for TableCreationInLoopVisitor {
+    fn visit_expression(&mut self, expression: &ast::Expression) {
+        if let ast::Expression::UnaryOperator {
+            unop: ast::UnOp::Hash(_),
+            expression,
+        } = expression
+        {
+            self.safe_name(expression);
+        }
+    }
+
+    fn visit_function_body(&mut self, body: &ast::FunctionBody) {
+        self.function_bodies.push(range(body));
+        self.loops.open_function();
+    }
+
+    fn visit_function_body_end(&mut self, _: &ast::FunctionBody) {
+        self.loops.close_function();
+    }
+
+    fn visit_function_call(&mut self, call: &ast::FunctionCall) {
+        self.indexed_prefix(call.prefix(), call.iter_suffixes().next());
+
+        if_chain::if_chain! {
+            if let Some(name) = call_name(call);
+            if TRANSIENT_FUNCTIONS.contains(&name.as_str());
+            if let Some(ast::Suffix::Call(ast::Call::AnonymousCall(
+                ast::FunctionArgs::Parentheses { arguments, .. }
+            ))) = call.iter_suffixes().last();
+            if let Some(table) = arguments.iter().next();
+            then {
+                self.safe_name(table);
+            }
+        }
+    }
+
+    fn visit_local_assignment(&mut self, local_assignment: &ast::LocalAssignment) {
+        if !self.loops.in_loop() {
+            return;
+        }
+
+        for (name, expression) in local_assignment
+            .name_list()
+            .iter()
+            .zip(local_assignment.expr_list().iter())
+        {
+            if let Some(ast::Value::TableConstructor(constructor)) =
+                ExpressionTree::new(expression).value()
+            {
+                self.table_locals.insert(range(name), range(constructor));
+            }
+        }
+    }
+
+    fn visit_var_expression(&mut self, var_expression: &ast::VarExpression) {
+        self.indexed_prefix(
+            var_expression.prefix(),
+            var_expression.iter_suffixes().next(),
+        );
+    }
+
+    visit_loops!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_table_creation_in_loop() {
+        test_lint(
+            TableCreationInLoopLint::new(()).unwrap(),
+            "table_creation_in_loop",
+            "table_creation_in_loop",
+        );
+    }
+}
@@ -57,7 +57,7 @@ pub fn test_lint_config<
 
     if let Ok(test_std_contents) = fs::read_to_string(path_base.with_extension("std.toml")) {
         let mut std: StandardLibrary = toml::from_str(&test_std_contents).unwrap();
-        std.inflate();
+        std.inflate().unwrap();
         config.standard_library = std;
     }
 
@@ -70,7 +70,8 @@ pub fn test_lint_config<
     let mut diagnostics = rule.pass(
         &ast,
         &Context {
-            standard_library: config.standard_library,
+            standard_library: &config.standard_library,
+            path: Some(&path_base.with_extension("lua")),
         },
     );
 
@@ -0,0 +1,137 @@
+use super::*;
+use crate::ast_util::comments::comments;
+use std::convert::Infallible;
+
+use full_moon::ast::Ast;
+use serde::Deserialize;
+
+#[derive(Clone, Deserialize)]
+#[serde(default)]
+pub struct TodoCommentConfig {
+    tags: Vec<String>,
+    case_sensitive: bool,
+}
+
+impl Default for TodoCommentConfig {
+    fn default() -> Self {
+        Self {
+            tags: ["TODO", "FIXME", "HACK", "XXX"]
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            case_sensitive: true,
+        }
+    }
+}
+
+pub struct TodoCommentLint {
+    config: TodoCommentConfig,
+}
+
+impl Rule for TodoCommentLint {
+    type Config = TodoCommentConfig;
+    type Error = Infallible;
+
+    fn new(config: Self::Config) -> Result<Self, Self::Error> {
+        Ok(TodoCommentLint { config })
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+
+        for comment in comments(ast) {
+            for (index, tag) in self.find_tags(&comment.text) {
+                let start = comment.range.0 + index;
+
+                diagnostics.push(Diagnostic::new(
+                    "todo_comment",
+                    format!("this comment contains `{}`", tag),
+                    Label::new((start, start + tag.len())),
+                ));
+            }
+        }
+
+        diagnostics
+    }
+
+    fn allow(&self) -> bool {
+        true
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Style
+    }
+}
+
+fn is_word_character(character: char) -> bool {
+    character.is_alphanumeric() || character == '_'
+}
+
+impl TodoCommentLint {
+    // Finds every tag that is a word by itself, so that `TODO` does not match `TODOS`.
+    // Returns the byte index of each tag, and the tag as it was written.
+    fn find_tags<'a>(&self, text: &'a str) -> Vec<(usize, &'a str)> {
+        // Only ASCII is lowercased, so that byte indices stay the same
+        let normalize = |text: &str| {
+            if self.config.case_sensitive {
+                text.to_owned()
+            } else {
+                text.to_ascii_lowercase()
+            }
+        };
+
+        let searched = normalize(text);
+        let mut found = Vec::new();
+
+        for tag in &self.config.tags {
+            if tag.is_empty() {
+                continue;
+            }
+
+            for (index, _) in searched.match_indices(&normalize(tag)) {
+                let end = index + tag.len();
+
+                let is_word = !text[..index].ends_with(is_word_character)
+                    && !text[end..].starts_with(is_word_character);
+
+                if is_word {
+                    found.push((index, &text[index..end]));
+                }
+            }
+        }
+
+        found.sort_unstable();
+        found
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_todo_comment() {
+        test_lint(
+            TodoCommentLint::new(TodoCommentConfig::default()).unwrap(),
+            "todo_comment",
+            "todo_comment",
+        );
+    }
+
+    #[test]
+    fn test_case_insensitive() {
+        test_lint(
+            TodoCommentLint::new(TodoCommentConfig {
+                tags: vec!["todo".to_owned(), "NOTE".to_owned()],
+                case_sensitive: false,
+            })
+            .unwrap(),
+            "todo_comment",
+            "case_insensitive",
+        );
+    }
+}
@@ -0,0 +1,96 @@
+use super::*;
+use crate::ast_util::range;
+use std::convert::Infallible;
+
+use full_moon::{ast::Ast, tokenizer::TokenReference, visitors::Visitor};
+
+pub struct TrailingWhitespaceLint;
+
+impl Rule for TrailingWhitespaceLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(TrailingWhitespaceLint)
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let source = full_moon::print(ast);
+
+        let mut visitor = TrailingWhitespaceVisitor {
+            ignored: Vec::new(),
+        };
+
+        visitor.visit_ast(ast);
+
+        let mut diagnostics = Vec::new();
+        let mut line_start = 0;
+
+        for line in source.split('\n') {
+            let start = line_start;
+            line_start += line.len() + 1;
+
+            let line = line.trim_end_matches('\r');
+            let trimmed = line.trim_end_matches(&[' ', '\t'][..]);
+
+            if trimmed.len() == line.len() {
+                continue;
+            }
+
+            let whitespace = (start + trimmed.len(), start + line.len());
+
+            if visitor
+                .ignored
+                .iter()
+                .any(|ignored| ignored.0 <= whitespace.0 && whitespace.1 <= ignored.1)
+            {
+                continue;
+            }
+
+            diagnostics.push(Diagnostic::new(
+                "trailing_whitespace",
+                "this line has trailing whitespace".to_owned(),
+                Label::new(whitespace),
+            ));
+        }
+
+        diagnostics
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Style
+    }
+}
+
+struct TrailingWhitespaceVisitor {
+    // Multi-line strings and comments, where whitespace is a part of the text
+    ignored: Vec<(usize, usize)>,
+}
+
+impl Visitor<'_> for TrailingWhitespaceVisitor {
+    fn visit_multi_line_comment(&mut self, token: &TokenReference) {
+        self.ignored.push(range(token));
+    }
+
+    fn visit_string_literal(&mut self, token: &TokenReference) {
+        self.ignored.push(range(token));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_trailing_whitespace() {
+        test_lint(
+            TrailingWhitespaceLint::new(()).unwrap(),
+            "trailing_whitespace",
+            "trailing_whitespace",
+        );
+    }
+}
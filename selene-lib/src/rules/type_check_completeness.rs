@@ -0,0 +1,285 @@
+use super::*;
+use crate::ast_util::{expression_tree::ExpressionTree, range, scopes::ScopeManager};
+use std::convert::Infallible;
+
+use full_moon::{
+    ast::{self, Ast},
+    tokenizer::TokenType,
+    visitors::Visitor,
+};
+use serde::Deserialize;
+
+const LUA_TYPES: &[&str] = &[
+    "boolean", "function", "nil", "number", "string", "table", "thread", "userdata",
+];
+
+#[derive(Clone, Copy, Default, Deserialize)]
+#[serde(default)]
+pub struct TypeCheckCompletenessConfig {
+    require_else_branch: bool,
+}
+
+pub struct TypeCheckCompletenessLint {
+    config: TypeCheckCompletenessConfig,
+}
+
+impl Rule for TypeCheckCompletenessLint {
+    type Config = TypeCheckCompletenessConfig;
+    type Error = Infallible;
+
+    fn new(config: Self::Config) -> Result<Self, Self::Error> {
+        Ok(TypeCheckCompletenessLint { config })
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let source = full_moon::print(ast);
+
+        let mut visitor = TypeCheckCompletenessVisitor {
+            chains: Vec::new(),
+            minimum_checks: if self.config.require_else_branch {
+                1
+            } else {
+                2
+            },
+            scope_manager: ScopeManager::new(ast),
+            source: &source,
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .chains
+            .into_iter()
+            .map(|chain| {
+                Diagnostic::new_complete(
+                    "type_check_completeness",
+                    "this if statement checks `type` without handling every type".to_owned(),
+                    Label::new(chain.range),
+                    vec![
+                        format!("note: {} not handled", list(&chain.missing)),
+                        "help: add an `else` branch for the other types".to_owned(),
+                    ],
+                    Vec::new(),
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Correctness
+    }
+
+    fn allow(&self) -> bool {
+        true
+    }
+}
+
+// `boolean`, `nil`, and `table` are
+fn list(types: &[&str]) -> String {
+    let types = types
+        .iter()
+        .map(|name| format!("`{}`", name))
+        .collect::<Vec<_>>();
+
+    match types.len() {
+        1 => format!("{} is", types[0]),
+        2 => format!("{} and {} are", types[0], types[1]),
+        _ => format!(
+            "{}, and {} are",
+            types[..types.len() - 1].join(", "),
+            types[types.len() - 1]
+        ),
+    }
+}
+
+struct Chain {
+    missing: Vec<&'static str>,
+    range: (usize, usize),
+}
+
+struct TypeCheckCompletenessVisitor<'a> {
+    chains: Vec<Chain>,
+    // How many conditions an if statement needs before being checked
+    minimum_checks: usize,
+    scope_manager: ScopeManager,
+    source: &'a str,
+}
+
+impl TypeCheckCompletenessVisitor<'_> {
+    fn text(&self, range: (usize, usize)) -> &str {
+        self.source[range.0..range.1].trim()
+    }
+
+    // The text of `x` in `type(x)`
+    fn type_call_argument(&self, tree: &ExpressionTree) -> Option<String> {
+        let call = match tree.value()? {
+            ast::Value::FunctionCall(call) => call,
+            _ => return None,
+        };
+
+        let name = match call.prefix() {
+            ast::Prefix::Name(name) if name.to_string() == "type" => name,
+            _ => return None,
+        };
+
+        if let Some(reference) = self
+            .scope_manager
+            .reference_at_byte(range::<_, usize>(name).0)
+        {
+            if reference.resolved.is_some() {
+                return None;
+            }
+        }
+
+        let mut suffixes = call.iter_suffixes();
+
+        match (suffixes.next(), suffixes.next()) {
+            (
+                Some(ast::Suffix::Call(ast::Call::AnonymousCall(ast::FunctionArgs::Parentheses {
+                    arguments,
+                    ..
+                }))),
+                None,
+            ) if arguments.len() == 1 => {
+                let argument = arguments.iter().next().unwrap();
+                Some(self.text(range(argument)).to_owned())
+            }
+
+            _ => None,
+        }
+    }
+
+    // The value and types checked by a condition like `type(x) == "number" or type(x) == "string"`
+    fn checked_types(&self, tree: &ExpressionTree) -> Option<(String, Vec<String>)> {
+        match tree {
+            ExpressionTree::Binary {
+                lhs,
+                op: ast::BinOp::Or(_),
+                rhs,
+            } => {
+                let (lhs_value, mut types) = self.checked_types(lhs)?;
+                let (rhs_value, rhs_types) = self.checked_types(rhs)?;
+
+                if lhs_value != rhs_value {
+                    return None;
+                }
+
+                types.extend(rhs_types);
+                Some((lhs_value, types))
+            }
+
+            ExpressionTree::Binary {
+                lhs,
+                op: ast::BinOp::TwoEqual(_),
+                rhs,
+            } => {
+                let (call, literal) = match self.type_call_argument(lhs) {
+                    Some(value) => (value, rhs),
+                    None => (self.type_call_argument(rhs)?, lhs),
+                };
+
+                match literal.value()? {
+                    ast::Value::String(token) => match &*token.token_type() {
+                        TokenType::StringLiteral { literal, .. } => {
+                            Some((call, vec![literal.to_string()]))
+                        }
+                        _ => None,
+                    },
+
+                    _ => None,
+                }
+            }
+
+            ExpressionTree::Parentheses(ast::Expression::Parentheses { expression, .. }) => {
+                self.checked_types(&ExpressionTree::new(expression))
+            }
+
+            _ => None,
+        }
+    }
+}
+
+impl Visitor<'_> for TypeCheckCompletenessVisitor<'_> {
+    fn visit_if(&mut self, if_block: &ast::If) {
+        if if_block.else_block().is_some() {
+            return;
+        }
+
+        let mut conditions = vec![if_block.condition()];
+        conditions.extend(
+            if_block
+                .else_if()
+                .into_iter()
+                .flatten()
+                .map(|else_if| else_if.condition()),
+        );
+
+        if conditions.len() < self.minimum_checks {
+            return;
+        }
+
+        let mut checked_value = None;
+        let mut checked_types = Vec::new();
+
+        for condition in &conditions {
+            let (value, types) = match self.checked_types(&ExpressionTree::new(condition)) {
+                Some(checked) => checked,
+                None => return,
+            };
+
+            if checked_value.get_or_insert_with(|| value.clone()) != &value {
+                return;
+            }
+
+            checked_types.extend(types);
+        }
+
+        let missing = LUA_TYPES
+            .iter()
+            .copied()
+            .filter(|name| !checked_types.iter().any(|checked| checked == name))
+            .collect::<Vec<_>>();
+
+        if !missing.is_empty() {
+            let last_condition = conditions.last().unwrap();
+
+            self.chains.push(Chain {
+                missing,
+                range: (
+                    range::<_, usize>(if_block).0,
+                    ExpressionTree::new(last_condition).range().1,
+                ),
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_type_check_completeness() {
+        test_lint(
+            TypeCheckCompletenessLint::new(TypeCheckCompletenessConfig::default()).unwrap(),
+            "type_check_completeness",
+            "type_check_completeness",
+        );
+    }
+
+    #[test]
+    fn test_require_else_branch() {
+        test_lint(
+            TypeCheckCompletenessLint::new(TypeCheckCompletenessConfig {
+                require_else_branch: true,
+            })
+            .unwrap(),
+            "type_check_completeness",
+            "require_else_branch",
+        );
+    }
+}
@@ -0,0 +1,87 @@
+use super::*;
+use crate::ast_util::{
+    expression_tree::{ExpressionTree, StaticType},
+    range,
+};
+use std::convert::Infallible;
+
+use full_moon::{
+    ast::{self, Ast},
+    visitors::Visitor,
+};
+
+pub struct UncallableLiteralLint;
+
+impl Rule for UncallableLiteralLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(UncallableLiteralLint)
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        called_literals(ast)
+            .into_iter()
+            .filter(|(_, literal_type)| *literal_type != StaticType::Table)
+            .map(|(range, literal_type)| {
+                Diagnostic::new(
+                    "uncallable_literal",
+                    format!("{} can't be called", literal_type.describe()),
+                    Label::new(range),
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Correctness
+    }
+}
+
+// Every literal that is immediately called, such as `(5)()`, alongside its type.
+// Functions are left out, as `(function() end)()` is fine.
+pub(crate) fn called_literals(ast: &Ast) -> Vec<((usize, usize), StaticType)> {
+    let mut visitor = CalledLiteralVisitor {
+        literals: Vec::new(),
+    };
+
+    visitor.visit_ast(ast);
+    visitor.literals
+}
+
+struct CalledLiteralVisitor {
+    literals: Vec<((usize, usize), StaticType)>,
+}
+
+impl Visitor<'_> for CalledLiteralVisitor {
+    fn visit_function_call(&mut self, call: &ast::FunctionCall) {
+        if_chain::if_chain! {
+            if let ast::Prefix::Expression(expression) = call.prefix();
+            if let Some(ast::Suffix::Call(ast::Call::AnonymousCall(_))) = call.iter_suffixes().next();
+            if let Some(literal_type) = ExpressionTree::new(expression).literal_type();
+            if literal_type != StaticType::Function;
+            then {
+                self.literals.push((range(expression), literal_type));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_uncallable_literal() {
+        test_lint(
+            UncallableLiteralLint::new(()).unwrap(),
+            "uncallable_literal",
+            "uncallable_literal",
+        );
+    }
+}
@@ -0,0 +1,54 @@
+use super::{uncallable_literal::called_literals, *};
+use crate::ast_util::expression_tree::StaticType;
+use std::convert::Infallible;
+
+use full_moon::ast::Ast;
+
+pub struct UncallableTableLint;
+
+impl Rule for UncallableTableLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(UncallableTableLint)
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        called_literals(ast)
+            .into_iter()
+            .filter(|(_, literal_type)| *literal_type == StaticType::Table)
+            .map(|(range, _)| {
+                Diagnostic::new_complete(
+                    "uncallable_table",
+                    "a table can't be called without a metatable".to_owned(),
+                    Label::new(range),
+                    vec!["note: only tables with a `__call` metamethod can be called".to_owned()],
+                    Vec::new(),
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Correctness
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_uncallable_table() {
+        test_lint(
+            UncallableTableLint::new(()).unwrap(),
+            "uncallable_table",
+            "uncallable_table",
+        );
+    }
+}
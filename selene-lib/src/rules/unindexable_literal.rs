@@ -0,0 +1,137 @@
+use super::*;
+use crate::ast_util::{
+    expression_tree::{ExpressionTree, StaticType},
+    literal_locals::LiteralLocals,
+    range,
+};
+use std::convert::Infallible;
+
+use full_moon::{
+    ast::{self, Ast},
+    visitors::Visitor,
+};
+
+pub struct UnindexableLiteralLint;
+
+impl Rule for UnindexableLiteralLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(UnindexableLiteralLint)
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let mut visitor = UnindexableLiteralVisitor {
+            indexes: Vec::new(),
+            literal_locals: LiteralLocals::new(),
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .indexes
+            .iter()
+            .map(|(range, literal_type)| {
+                Diagnostic::new_complete(
+                    "unindexable_literal",
+                    format!("{} can't be indexed", literal_type.describe()),
+                    Label::new(*range),
+                    vec!["note: this will error when it is run".to_owned()],
+                    Vec::new(),
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Correctness
+    }
+}
+
+struct UnindexableLiteralVisitor {
+    indexes: Vec<((usize, usize), StaticType)>,
+    literal_locals: LiteralLocals,
+}
+
+impl UnindexableLiteralVisitor {
+    fn check<'a, 'ast>(
+        &mut self,
+        prefix: &ast::Prefix,
+        mut suffixes: impl Iterator<Item = &'a ast::Suffix<'ast>>,
+    ) where
+        'ast: 'a,
+    {
+        // `x.y`, `x[y]`, and `x:y()` all index `x`
+        if !matches!(
+            suffixes.next(),
+            Some(ast::Suffix::Index(_)) | Some(ast::Suffix::Call(ast::Call::MethodCall(_)))
+        ) {
+            return;
+        }
+
+        let literal_type = match prefix {
+            ast::Prefix::Expression(expression) => ExpressionTree::new(expression).literal_type(),
+            ast::Prefix::Name(name) => self.literal_locals.get(&name.to_string()),
+        };
+
+        if let Some(literal_type) = literal_type {
+            // Strings have a metatable for the string library
+            if !matches!(literal_type, StaticType::String | StaticType::Table) {
+                self.indexes.push((range(prefix), literal_type));
+            }
+        }
+    }
+}
+
+impl Visitor<'_> for UnindexableLiteralVisitor {
+    fn visit_assignment(&mut self, assignment: &ast::Assignment) {
+        self.literal_locals.visit_assignment(assignment);
+    }
+
+    fn visit_block(&mut self, _: &ast::Block) {
+        self.literal_locals.visit_block();
+    }
+
+    fn visit_block_end(&mut self, _: &ast::Block) {
+        self.literal_locals.visit_block_end();
+    }
+
+    fn visit_function_call(&mut self, call: &ast::FunctionCall) {
+        self.check(call.prefix(), call.iter_suffixes());
+    }
+
+    fn visit_local_assignment(&mut self, local_assignment: &ast::LocalAssignment) {
+        self.literal_locals.visit_local_assignment(local_assignment);
+    }
+
+    fn visit_local_assignment_end(&mut self, _: &ast::LocalAssignment) {
+        self.literal_locals.visit_local_assignment_end();
+    }
+
+    fn visit_local_function(&mut self, local_function: &ast::LocalFunction) {
+        self.literal_locals.visit_local_function(local_function);
+    }
+
+    fn visit_var_expression(&mut self, var_expression: &ast::VarExpression) {
+        self.check(var_expression.prefix(), var_expression.iter_suffixes());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_unindexable_literal() {
+        test_lint(
+            UnindexableLiteralLint::new(()).unwrap(),
+            "unindexable_literal",
+            "unindexable_literal",
+        );
+    }
+}
@@ -0,0 +1,415 @@
+use super::*;
+use crate::ast_util::{
+    is_multiple_values, range,
+    scopes::{ScopeManager, Variable},
+};
+use std::{
+    collections::{HashMap, HashSet},
+    convert::Infallible,
+};
+
+use full_moon::{
+    ast::{self, Ast},
+    node::Node,
+    tokenizer::TokenReference,
+    visitors::Visitor,
+};
+use id_arena::Id;
+
+use super::{dead_assignment::captured_variables, implicit_nil_return::is_error_call};
+
+pub struct UninitializedVariableLint;
+
+impl Rule for UninitializedVariableLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(UninitializedVariableLint)
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let scope_manager = ScopeManager::new(ast);
+
+        let mut function_visitor = FunctionBodyVisitor {
+            function_bodies: Vec::new(),
+        };
+
+        function_visitor.visit_ast(ast);
+
+        let mut analysis = Analysis {
+            breaks: Vec::new(),
+            // Functions that use a variable could run at any time, including after it is assigned
+            captured: captured_variables(&scope_manager, &function_visitor.function_bodies),
+            reads: Vec::new(),
+            reported: HashSet::new(),
+            variables: scope_manager
+                .variables
+                .iter()
+                .map(|(id, variable)| (variable.identifiers[0], id))
+                .collect(),
+            scope_manager: &scope_manager,
+        };
+
+        analysis.block(ast.nodes(), &mut State::default());
+
+        analysis
+            .reads
+            .iter()
+            .map(|read| {
+                let variable = &scope_manager.variables[read.variable];
+
+                Diagnostic::new_complete(
+                    "uninitialized_variable",
+                    if read.definitely {
+                        format!("`{}` is read before it is given a value", variable.name)
+                    } else {
+                        format!(
+                            "`{}` might be read before it is given a value",
+                            variable.name
+                        )
+                    },
+                    Label::new(read.range),
+                    vec![format!(
+                        "note: `{}` is always nil until it is assigned",
+                        variable.name
+                    )],
+                    vec![Label::new_with_message(
+                        (
+                            variable.identifiers[0].0 as u32,
+                            variable.identifiers[0].1 as u32,
+                        ),
+                        "declared here without a value".to_owned(),
+                    )],
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Correctness
+    }
+}
+
+struct UninitializedRead {
+    variable: Id<Variable>,
+    range: (usize, usize),
+    // Whether the variable has no value on every path to the read, rather than only some
+    definitely: bool,
+}
+
+// The locals that haven't been given a value yet at a point in the code
+#[derive(Clone, Default)]
+struct State {
+    // Locals that don't have a value on at least one path here
+    maybe: HashSet<Id<Variable>>,
+    // Locals that don't have a value on every path here
+    definitely: HashSet<Id<Variable>>,
+}
+
+impl State {
+    fn declare(&mut self, variable: Id<Variable>) {
+        self.maybe.insert(variable);
+        self.definitely.insert(variable);
+    }
+
+    fn assign(&mut self, variable: Id<Variable>) {
+        self.maybe.remove(&variable);
+        self.definitely.remove(&variable);
+    }
+
+    // Combines the states of two paths that come back together, such as after an `if`
+    fn join(&mut self, other: &State) {
+        self.maybe.extend(other.maybe.iter().copied());
+        self.definitely
+            .retain(|variable| other.definitely.contains(variable));
+    }
+
+    // Combines the states of every path that reaches a point. None if no path does.
+    fn join_all(states: impl IntoIterator<Item = State>) -> Option<State> {
+        let mut states = states.into_iter();
+        let mut joined = states.next()?;
+
+        for state in states {
+            joined.join(&state);
+        }
+
+        Some(joined)
+    }
+}
+
+struct FunctionBodyVisitor {
+    function_bodies: Vec<(usize, usize)>,
+}
+
+impl Visitor<'_> for FunctionBodyVisitor {
+    fn visit_function_body(&mut self, body: &ast::FunctionBody) {
+        self.function_bodies.push(range(body));
+    }
+}
+
+struct Analysis<'a> {
+    // The states at every `break` of the loops being analyzed, innermost last
+    breaks: Vec<Vec<State>>,
+    captured: HashSet<Id<Variable>>,
+    reads: Vec<UninitializedRead>,
+    // Only the first read of each variable is reported
+    reported: HashSet<Id<Variable>>,
+    scope_manager: &'a ScopeManager,
+    // Every variable by where it was first declared
+    variables: HashMap<(usize, usize), Id<Variable>>,
+}
+
+impl Analysis<'_> {
+    // Reports reads of locals without a value inside of a node
+    fn reads<N: Node>(&mut self, node: N, state: &State) {
+        let (start, end) = match node.range() {
+            Some((start, end)) => (start.bytes(), end.bytes()),
+            None => return,
+        };
+
+        let mut variables = state.maybe.iter().copied().collect::<Vec<_>>();
+        variables.sort_by_key(|variable| self.scope_manager.variables[*variable].identifiers[0]);
+
+        for variable in variables {
+            if self.reported.contains(&variable) {
+                continue;
+            }
+
+            let read = self.scope_manager.variables[variable]
+                .references
+                .iter()
+                .map(|reference| &self.scope_manager.references[*reference])
+                .find(|reference| {
+                    reference.read
+                        && reference.identifier.0 >= start
+                        && reference.identifier.1 <= end
+                });
+
+            if let Some(read) = read {
+                self.reported.insert(variable);
+                self.reads.push(UninitializedRead {
+                    variable,
+                    range: read.identifier,
+                    definitely: state.definitely.contains(&variable),
+                });
+            }
+        }
+    }
+
+    fn assign(&self, name: &TokenReference, state: &mut State) {
+        let resolved = self
+            .scope_manager
+            .reference_at_byte(range(name).0)
+            .and_then(|reference| reference.resolved);
+
+        if let Some(variable) = resolved {
+            state.assign(variable);
+        }
+    }
+
+    // Analyzes a loop body that might run any amount of times, and gives the state after the loop
+    fn loop_block(&mut self, block: &ast::Block, state: &State) -> State {
+        self.breaks.push(Vec::new());
+        let mut body = state.clone();
+        let falls_through = self.block(block, &mut body);
+        let breaks = self.breaks.pop().unwrap();
+
+        let mut after = state.clone();
+
+        if falls_through {
+            after.join(&body);
+        }
+
+        for break_state in &breaks {
+            after.join(break_state);
+        }
+
+        after
+    }
+
+    // Analyzes a block, and gives whether the end of it can be reached
+    fn block(&mut self, block: &ast::Block, state: &mut State) -> bool {
+        for stmt in block.iter_stmts() {
+            if !self.stmt(stmt, state) {
+                return false;
+            }
+        }
+
+        match block.last_stmts() {
+            Some(ast::LastStmt::Return(return_stmt)) => {
+                self.reads(return_stmt, state);
+                false
+            }
+
+            Some(ast::LastStmt::Break(_)) => {
+                if let Some(breaks) = self.breaks.last_mut() {
+                    breaks.push(state.clone());
+                }
+
+                false
+            }
+
+            None => true,
+        }
+    }
+
+    // Analyzes a statement, and gives whether the code after it can be reached
+    fn stmt(&mut self, stmt: &ast::Stmt, state: &mut State) -> bool {
+        match stmt {
+            ast::Stmt::Assignment(assignment) => {
+                for expression in assignment.expr_list() {
+                    self.reads(expression, state);
+                }
+
+                for var in assignment.var_list() {
+                    match var {
+                        ast::Var::Name(name) => self.assign(name, state),
+                        ast::Var::Expression(_) => self.reads(var, state),
+                    }
+                }
+            }
+
+            ast::Stmt::Do(do_block) => return self.block(do_block.block(), state),
+
+            ast::Stmt::FunctionCall(call) => {
+                self.reads(call, state);
+                return !is_error_call(call);
+            }
+
+            ast::Stmt::FunctionDeclaration(declaration) => {
+                let names = declaration.name().names();
+
+                if names.len() == 1 && declaration.name().method_name().is_none() {
+                    if let Some(name) = names.iter().next() {
+                        self.assign(name, state);
+                    }
+                } else {
+                    self.reads(declaration.name(), state);
+                }
+            }
+
+            ast::Stmt::GenericFor(generic_for) => {
+                for expression in generic_for.expr_list() {
+                    self.reads(expression, state);
+                }
+
+                *state = self.loop_block(generic_for.block(), state);
+            }
+
+            ast::Stmt::If(if_block) => {
+                self.reads(if_block.condition(), state);
+
+                let mut branches = Vec::new();
+
+                let mut branch = state.clone();
+                if self.block(if_block.block(), &mut branch) {
+                    branches.push(branch);
+                }
+
+                for else_if in if_block.else_if().into_iter().flatten() {
+                    self.reads(else_if.condition(), state);
+
+                    let mut branch = state.clone();
+                    if self.block(else_if.block(), &mut branch) {
+                        branches.push(branch);
+                    }
+                }
+
+                match if_block.else_block() {
+                    Some(else_block) => {
+                        let mut branch = state.clone();
+                        if self.block(else_block, &mut branch) {
+                            branches.push(branch);
+                        }
+                    }
+
+                    None => branches.push(state.clone()),
+                }
+
+                match State::join_all(branches) {
+                    Some(joined) => *state = joined,
+                    None => return false,
+                }
+            }
+
+            ast::Stmt::LocalAssignment(local_assignment) => {
+                let expressions = local_assignment.expr_list();
+
+                for expression in expressions {
+                    self.reads(expression, state);
+                }
+
+                // `local a, b = f()` can give every name a value
+                if matches!(expressions.iter().last(), Some(last) if is_multiple_values(last)) {
+                    return true;
+                }
+
+                for name in local_assignment.name_list().iter().skip(expressions.len()) {
+                    if let Some(variable) = self.variables.get(&range(name)) {
+                        if !self.captured.contains(variable) {
+                            state.declare(*variable);
+                        }
+                    }
+                }
+            }
+
+            ast::Stmt::LocalFunction(_) => {}
+
+            ast::Stmt::NumericFor(numeric_for) => {
+                self.reads(numeric_for.start(), state);
+                self.reads(numeric_for.end(), state);
+
+                if let Some(step) = numeric_for.step() {
+                    self.reads(step, state);
+                }
+
+                *state = self.loop_block(numeric_for.block(), state);
+            }
+
+            // The body of a `repeat` always runs at least once
+            ast::Stmt::Repeat(repeat) => {
+                self.breaks.push(Vec::new());
+                let falls_through = self.block(repeat.block(), state);
+                let breaks = self.breaks.pop().unwrap();
+
+                let mut paths = breaks;
+
+                if falls_through {
+                    self.reads(repeat.until(), state);
+                    paths.push(state.clone());
+                }
+
+                match State::join_all(paths) {
+                    Some(joined) => *state = joined,
+                    None => return false,
+                }
+            }
+
+            ast::Stmt::While(while_loop) => {
+                self.reads(while_loop.condition(), state);
+                *state = self.loop_block(while_loop.block(), state);
+            }
+        }
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_uninitialized_variable() {
+        test_lint(
+            UninitializedVariableLint::new(()).unwrap(),
+            "uninitialized_variable",
+            "uninitialized_variable",
+        );
+    }
+}
@@ -0,0 +1,258 @@
+use super::*;
+use crate::{
+    ast_util::{range, scopes::ScopeManager},
+    standard_library::{Field, StandardLibrary, Writable},
+};
+use std::{collections::HashSet, convert::Infallible};
+
+use full_moon::{
+    ast::{self, Ast},
+    tokenizer::TokenReference,
+    visitors::Visitor,
+};
+
+pub struct UnknownGlobalWriteLint;
+
+impl Rule for UnknownGlobalWriteLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(UnknownGlobalWriteLint)
+    }
+
+    fn pass(&self, ast: &Ast, context: &Context) -> Vec<Diagnostic> {
+        let mut visitor = UnknownGlobalWriteVisitor::default();
+        visitor.visit_ast(ast);
+
+        let scope_manager = ScopeManager::new(ast);
+
+        // Hoisted functions are still globals
+        let is_global = |write: &GlobalWrite| match scope_manager.reference_at_byte(write.base.0) {
+            Some(reference) => match reference.resolved {
+                Some(resolved) => visitor
+                    .hoisted
+                    .contains(&scope_manager.variables[resolved].identifiers[0]),
+                None => true,
+            },
+
+            None => true,
+        };
+
+        visitor
+            .writes
+            .iter()
+            .filter(|write| is_global(write) && !is_writable(context.standard_library, write))
+            .map(|write| {
+                let known = context
+                    .standard_library
+                    .find_global(&write.names[..1])
+                    .is_some();
+
+                if known {
+                    Diagnostic::new(
+                        "unknown_global_write",
+                        if write.field {
+                            format!(
+                                "assigning to a field of `{}`, which is not writable in the standard library",
+                                write.names[0]
+                            )
+                        } else {
+                            format!(
+                                "assigning to `{}`, which is not writable in the standard library",
+                                write.names[0]
+                            )
+                        },
+                        Label::new(write.range),
+                    )
+                } else if write.field {
+                    Diagnostic::new(
+                        "unknown_global_write",
+                        format!(
+                            "assigning to a field of `{}`, which is not a standard library global",
+                            write.names[0]
+                        ),
+                        Label::new(write.range),
+                    )
+                } else {
+                    Diagnostic::new_complete(
+                        "unknown_global_write",
+                        format!(
+                            "assigning to `{}`, which is not a standard library global",
+                            write.names[0]
+                        ),
+                        Label::new(write.range),
+                        vec![format!(
+                            "help: use `local {}`, or add it to your standard library as writable",
+                            write.names[0]
+                        )],
+                        Vec::new(),
+                    )
+                }
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Correctness
+    }
+}
+
+// Whether the standard library lets this be written to, either because it can be overridden or
+// because something it is a field of can be given new fields
+fn is_writable(standard_library: &StandardLibrary, write: &GlobalWrite) -> bool {
+    // `Foo.bar[key] = 1` only adds a field to `Foo.bar`
+    let parents = if write.indexed {
+        write.names.len()
+    } else {
+        write.names.len() - 1
+    };
+
+    for length in 1..=parents {
+        match standard_library.find_global(&write.names[..length]) {
+            Some(Field::Any)
+            | Some(Field::Property {
+                writable: Some(Writable::NewFields),
+            })
+            | Some(Field::Property {
+                writable: Some(Writable::Full),
+            }) => return true,
+
+            Some(_) => {}
+            None => return false,
+        }
+    }
+
+    !write.indexed
+        && matches!(
+            standard_library.find_global(&write.names),
+            Some(Field::Any)
+                | Some(Field::Property {
+                    writable: Some(Writable::Overridden),
+                })
+                | Some(Field::Property {
+                    writable: Some(Writable::Full),
+                })
+        )
+}
+
+struct GlobalWrite {
+    // The global and the fields being written to, `Foo`, `bar` in `Foo.bar = 1`
+    names: Vec<String>,
+    // Whether a field of the names is written to by something other than its name, such as
+    // `Foo[key] = 1`
+    indexed: bool,
+    // The range of the global itself, `Foo` in `Foo.bar = 1`
+    base: (usize, usize),
+    // The range of everything being written to, `Foo.bar` in `Foo.bar = 1`
+    range: (usize, usize),
+    field: bool,
+}
+
+#[derive(Default)]
+struct UnknownGlobalWriteVisitor {
+    hoisted: HashSet<(usize, usize)>,
+    writes: Vec<GlobalWrite>,
+}
+
+impl UnknownGlobalWriteVisitor {
+    fn push_write(
+        &mut self,
+        base: &TokenReference,
+        names: Vec<String>,
+        indexed: bool,
+        write_range: (usize, usize),
+    ) {
+        self.writes.push(GlobalWrite {
+            field: names.len() > 1 || indexed,
+            names,
+            indexed,
+            base: range(base),
+            range: write_range,
+        });
+    }
+}
+
+impl Visitor<'_> for UnknownGlobalWriteVisitor {
+    fn visit_assignment(&mut self, assignment: &ast::Assignment) {
+        for var in assignment.var_list() {
+            match var {
+                ast::Var::Name(name) => {
+                    self.push_write(name, vec![name.to_string()], false, range(name))
+                }
+
+                ast::Var::Expression(var_expr) => {
+                    // `foo().bar = 1` and `(foo).bar = 1` don't write to `foo` itself
+                    if let ast::Prefix::Name(name) = var_expr.prefix() {
+                        if var_expr
+                            .iter_suffixes()
+                            .all(|suffix| matches!(suffix, ast::Suffix::Index(_)))
+                        {
+                            let mut names = vec![name.to_string()];
+                            let mut indexed = false;
+
+                            for suffix in var_expr.iter_suffixes() {
+                                match suffix {
+                                    ast::Suffix::Index(ast::Index::Dot { name, .. }) => {
+                                        names.push(name.to_string())
+                                    }
+
+                                    _ => {
+                                        indexed = true;
+                                        break;
+                                    }
+                                }
+                            }
+
+                            self.push_write(name, names, indexed, range(var_expr));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn visit_function_declaration(&mut self, declaration: &ast::FunctionDeclaration) {
+        let name = declaration.name();
+        let base = name.names().iter().next().unwrap();
+
+        // ScopeManager can hoist the base of function declarations into variables
+        self.hoisted.insert(range(base));
+
+        let names = name
+            .names()
+            .iter()
+            .chain(name.method_name())
+            .map(ToString::to_string)
+            .collect();
+
+        self.push_write(base, names, false, range(name));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_unknown_global_write() {
+        test_lint(
+            UnknownGlobalWriteLint::new(()).unwrap(),
+            "unknown_global_write",
+            "unknown_global_write",
+        );
+    }
+
+    #[test]
+    fn test_writable_globals() {
+        test_lint(
+            UnknownGlobalWriteLint::new(()).unwrap(),
+            "unknown_global_write",
+            "writable_globals",
+        );
+    }
+}
@@ -0,0 +1,277 @@
+use super::*;
+use crate::ast_util::{
+    expression_tree::{ExpressionTree, StaticType},
+    range,
+    scopes::{ScopeManager, Variable},
+};
+use std::{collections::HashSet, convert::Infallible};
+
+use full_moon::{
+    ast::{self, Ast},
+    node::Node,
+    visitors::Visitor,
+};
+use id_arena::Id;
+
+const RAW_FUNCTIONS: &[&str] = &["rawequal", "rawget", "rawset"];
+
+pub struct UnnecessaryRawaccessLint;
+
+impl Rule for UnnecessaryRawaccessLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(UnnecessaryRawaccessLint)
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let source = full_moon::print(ast);
+        let scope_manager = ScopeManager::new(ast);
+
+        let mut uses_visitor = UsesVisitor {
+            safe_uses: HashSet::new(),
+            table_locals: HashSet::new(),
+        };
+
+        uses_visitor.visit_ast(ast);
+
+        let mut visitor = UnnecessaryRawaccessVisitor {
+            calls: Vec::new(),
+            plain_tables: plain_tables(&scope_manager, &uses_visitor),
+            scope_manager: &scope_manager,
+            source: &source,
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .calls
+            .into_iter()
+            .map(|call| {
+                Diagnostic::new_complete(
+                    "unnecessary_rawaccess",
+                    format!(
+                        "`{}` is unnecessary, as there is no metatable to bypass",
+                        call.function
+                    ),
+                    Label::new(call.range),
+                    vec![format!("help: try `{}`", call.suggestion)],
+                    Vec::new(),
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Performance
+    }
+}
+
+// Locals that are created as a table constructor, and are only ever indexed or passed to raw functions.
+// Anything else, such as passing it to a function, could give it a metatable.
+fn plain_tables(scope_manager: &ScopeManager, uses: &UsesVisitor) -> HashSet<Id<Variable>> {
+    scope_manager
+        .variables
+        .iter()
+        .filter(|(_, variable)| {
+            let declaration = variable.identifiers[0];
+
+            uses.table_locals.contains(&declaration)
+                && variable.references.iter().all(|reference| {
+                    let reference = &scope_manager.references[*reference];
+
+                    reference.identifier == declaration
+                        || uses.safe_uses.contains(&reference.identifier)
+                })
+        })
+        .map(|(id, _)| id)
+        .collect()
+}
+
+fn raw_function_arguments<'a, 'ast>(
+    call: &'a ast::FunctionCall<'ast>,
+) -> Option<(String, Vec<&'a ast::Expression<'ast>>)> {
+    if_chain::if_chain! {
+        if let ast::Prefix::Name(name) = call.prefix();
+        if RAW_FUNCTIONS.contains(&name.to_string().as_str());
+        let mut suffixes = call.iter_suffixes();
+        if let Some(ast::Suffix::Call(ast::Call::AnonymousCall(
+            ast::FunctionArgs::Parentheses { arguments, .. }
+        ))) = suffixes.next();
+        if suffixes.next().is_none();
+        then {
+            Some((name.to_string(), arguments.iter().collect()))
+        } else {
+            None
+        }
+    }
+}
+
+struct UsesVisitor {
+    // Names that are indexed, such as `t` in `t.x`, or passed to raw functions
+    safe_uses: HashSet<(usize, usize)>,
+    // Locals declared as a table constructor, such as `local t = {}`
+    table_locals: HashSet<(usize, usize)>,
+}
+
+impl UsesVisitor {
+    fn indexed_prefix(&mut self, prefix: &ast::Prefix, first_suffix: Option<&ast::Suffix>) {
+        if let (ast::Prefix::Name(name), Some(ast::Suffix::Index(_))) = (prefix, first_suffix) {
+            self.safe_uses.insert(range(name));
+        }
+    }
+}
+
+impl Visitor<'_> for UsesVisitor {
+    fn visit_function_call(&mut self, call: &ast::FunctionCall) {
+        self.indexed_prefix(call.prefix(), call.iter_suffixes().next());
+
+        if let Some((_, arguments)) = raw_function_arguments(call) {
+            for argument in arguments {
+                if let Some(ast::Value::Var(ast::Var::Name(name))) =
+                    ExpressionTree::new(argument).value()
+                {
+                    self.safe_uses.insert(range(name));
+                }
+            }
+        }
+    }
+
+    fn visit_local_assignment(&mut self, local_assignment: &ast::LocalAssignment) {
+        for (name, expression) in local_assignment
+            .name_list()
+            .iter()
+            .zip(local_assignment.expr_list().iter())
+        {
+            if let Some(ast::Value::TableConstructor(_)) = ExpressionTree::new(expression).value() {
+                self.table_locals.insert(range(name));
+            }
+        }
+    }
+
+    fn visit_var_expression(&mut self, var_expression: &ast::VarExpression) {
+        self.indexed_prefix(
+            var_expression.prefix(),
+            var_expression.iter_suffixes().next(),
+        );
+    }
+}
+
+struct RawCall {
+    function: String,
+    range: (usize, usize),
+    suggestion: String,
+}
+
+struct UnnecessaryRawaccessVisitor<'a> {
+    calls: Vec<RawCall>,
+    plain_tables: HashSet<Id<Variable>>,
+    scope_manager: &'a ScopeManager,
+    source: &'a str,
+}
+
+impl UnnecessaryRawaccessVisitor<'_> {
+    fn text(&self, expression: &ast::Expression) -> &str {
+        let (start, end) = range::<_, usize>(expression);
+        self.source[start..end].trim()
+    }
+
+    // `{}[key]` isn't valid, so table constructors have to be wrapped in parentheses to be indexed
+    fn indexable_text(&self, expression: &ast::Expression) -> String {
+        match ExpressionTree::new(expression) {
+            ExpressionTree::Value(ast::Value::TableConstructor(_)) => {
+                format!("({})", self.text(expression))
+            }
+
+            _ => self.text(expression).to_owned(),
+        }
+    }
+
+    // Whether the expression is a table that can't have a metatable
+    fn is_plain_table(&self, expression: &ast::Expression) -> bool {
+        let tree = ExpressionTree::new(expression);
+
+        match tree.value() {
+            Some(ast::Value::TableConstructor(_)) => true,
+
+            Some(ast::Value::Var(ast::Var::Name(name))) => self
+                .scope_manager
+                .reference_at_byte(range::<_, usize>(name).0)
+                .and_then(|reference| reference.resolved)
+                .map(|variable| self.plain_tables.contains(&variable))
+                .unwrap_or(false),
+
+            _ => false,
+        }
+    }
+
+    // Whether `==` would act the same as `rawequal`, as neither side can have an `__eq` metamethod
+    fn has_no_eq(&self, expression: &ast::Expression) -> bool {
+        match ExpressionTree::new(expression).literal_type() {
+            Some(StaticType::Table) | None => self.is_plain_table(expression),
+            Some(_) => true,
+        }
+    }
+}
+
+impl Visitor<'_> for UnnecessaryRawaccessVisitor<'_> {
+    fn visit_function_call(&mut self, call: &ast::FunctionCall) {
+        let (function, arguments) = match raw_function_arguments(call) {
+            Some(raw_call) => raw_call,
+            None => return,
+        };
+
+        if let Some(reference) = self
+            .scope_manager
+            .reference_at_byte(call.start_position().unwrap().bytes())
+        {
+            if reference.resolved.is_some() {
+                return;
+            }
+        }
+
+        let suggestion = match (function.as_str(), arguments.as_slice()) {
+            ("rawget", [table, key]) if self.is_plain_table(table) => {
+                format!("{}[{}]", self.indexable_text(table), self.text(key))
+            }
+
+            ("rawset", [table, key, value]) if self.is_plain_table(table) => format!(
+                "{}[{}] = {}",
+                self.indexable_text(table),
+                self.text(key),
+                self.text(value)
+            ),
+
+            ("rawequal", [lhs, rhs]) if self.has_no_eq(lhs) && self.has_no_eq(rhs) => {
+                format!("{} == {}", self.text(lhs), self.text(rhs))
+            }
+
+            _ => return,
+        };
+
+        self.calls.push(RawCall {
+            function,
+            range: range(call),
+            suggestion,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_unnecessary_rawaccess() {
+        test_lint(
+            UnnecessaryRawaccessLint::new(()).unwrap(),
+            "unnecessary_rawaccess",
+            "unnecessary_rawaccess",
+        );
+    }
+}
@@ -0,0 +1,145 @@
+use super::*;
+use crate::ast_util::range;
+use std::{collections::HashSet, convert::Infallible};
+
+use full_moon::{
+    ast::{self, Ast},
+    tokenizer::{Symbol, TokenReference, TokenType},
+    visitors::Visitor,
+};
+
+pub struct UnnecessarySemicolonLint;
+
+impl Rule for UnnecessarySemicolonLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(UnnecessarySemicolonLint)
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let mut visitor = UnnecessarySemicolonVisitor {
+            semicolons: Vec::new(),
+            table_separators: HashSet::new(),
+        };
+
+        visitor.visit_ast(ast);
+
+        // Semicolons between statements are visited more than once
+        visitor.semicolons.sort_unstable();
+        visitor.semicolons.dedup();
+
+        let code = full_moon::print(ast);
+
+        visitor
+            .semicolons
+            .iter()
+            .filter(|semicolon| !visitor.table_separators.contains(semicolon))
+            .filter(|semicolon| !separates_statements(&code[semicolon.1..]))
+            .map(|semicolon| {
+                Diagnostic::new_complete(
+                    "unnecessary_semicolon",
+                    "unnecessary semicolon".to_owned(),
+                    Label::new(*semicolon),
+                    vec!["help: remove the semicolon".to_owned()],
+                    Vec::new(),
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Style
+    }
+}
+
+// Whether the code after a semicolon has another statement on the same line, such as in `a(); b()`.
+// Semicolons before a line starting with `(` are also needed, as `a()\n(b)()` is ambiguous.
+fn separates_statements(after: &str) -> bool {
+    let mut rest = after;
+    let mut same_line = true;
+
+    loop {
+        let trimmed = rest.trim_start_matches(|character: char| {
+            if character == '\n' {
+                same_line = false;
+            }
+
+            character.is_whitespace()
+        });
+
+        if trimmed.starts_with("--") {
+            // Comments are skipped, multi-line comments are not worth the trouble
+            if trimmed.starts_with("--[") {
+                return true;
+            }
+
+            match trimmed.find('\n') {
+                Some(newline) => rest = &trimmed[newline..],
+                None => return false,
+            }
+
+            continue;
+        }
+
+        // `if x then f(); end`, the semicolon is before the end of a block rather than a statement
+        let word = trimmed
+            .split(|character: char| !character.is_alphanumeric() && character != '_')
+            .next()
+            .unwrap();
+
+        if ["else", "elseif", "end", "until"].contains(&word) {
+            return false;
+        }
+
+        return match trimmed.chars().next() {
+            None | Some(';') => false,
+            Some('(') => true,
+            Some(_) => same_line,
+        };
+    }
+}
+
+struct UnnecessarySemicolonVisitor {
+    semicolons: Vec<(usize, usize)>,
+    table_separators: HashSet<(usize, usize)>,
+}
+
+impl Visitor<'_> for UnnecessarySemicolonVisitor {
+    fn visit_symbol(&mut self, token: &TokenReference) {
+        if *token.token_type()
+            == (TokenType::Symbol {
+                symbol: Symbol::Semicolon,
+            })
+        {
+            self.semicolons.push(range(token));
+        }
+    }
+
+    fn visit_table_constructor(&mut self, constructor: &ast::TableConstructor) {
+        for (_, separator) in constructor.iter_fields() {
+            if let Some(separator) = separator {
+                self.table_separators.insert(range(separator));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_unnecessary_semicolon() {
+        test_lint(
+            UnnecessarySemicolonLint::new(()).unwrap(),
+            "unnecessary_semicolon",
+            "unnecessary_semicolon",
+        );
+    }
+}
@@ -0,0 +1,87 @@
+use super::*;
+use crate::ast_util::scopes::ScopeManager;
+use std::{collections::HashSet, convert::Infallible};
+
+use full_moon::ast::Ast;
+
+pub struct UseBeforeDeclarationLint;
+
+impl Rule for UseBeforeDeclarationLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(UseBeforeDeclarationLint)
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let scope_manager = ScopeManager::new(ast);
+        let mut diagnostics = Vec::new();
+
+        // ScopeManager repeats references
+        let mut checked = HashSet::new();
+
+        for (_, reference) in &scope_manager.references {
+            if reference.resolved.is_some() || !checked.insert(reference.identifier) {
+                continue;
+            }
+
+            let position = reference.identifier.0;
+
+            // A local of the same name declared after this, in a block this is inside of
+            let declaration = scope_manager
+                .scopes
+                .iter()
+                .filter(|(_, scope)| position >= scope.block.0 && position <= scope.block.1)
+                .flat_map(|(_, scope)| scope.variables.iter())
+                .map(|variable| &scope_manager.variables[*variable])
+                .filter(|variable| variable.name == reference.name)
+                .filter_map(|variable| variable.identifiers.first())
+                .filter(|identifier| identifier.0 > position)
+                .min();
+
+            if let Some(declaration) = declaration {
+                diagnostics.push(Diagnostic::new_complete(
+                    "use_before_declaration",
+                    format!(
+                        "`{}` is used before it is declared as a local",
+                        reference.name
+                    ),
+                    Label::new(reference.identifier),
+                    vec![
+                        "note: locals only exist after they are declared, so this uses a global of the same name instead".to_owned(),
+                    ],
+                    vec![Label::new_with_message(
+                        (declaration.0 as u32, declaration.1 as u32),
+                        "declared here".to_owned(),
+                    )],
+                ));
+            }
+        }
+
+        diagnostics.sort_by_key(|diagnostic| diagnostic.primary_label.range);
+        diagnostics
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Correctness
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_use_before_declaration() {
+        test_lint(
+            UseBeforeDeclarationLint::new(()).unwrap(),
+            "use_before_declaration",
+            "use_before_declaration",
+        );
+    }
+}
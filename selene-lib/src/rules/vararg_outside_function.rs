@@ -0,0 +1,101 @@
+use super::*;
+use crate::ast_util::range;
+use std::convert::Infallible;
+
+use full_moon::{
+    ast::{self, Ast},
+    tokenizer::{Symbol, TokenType},
+    visitors::Visitor,
+};
+
+pub struct VarargOutsideFunctionLint;
+
+impl Rule for VarargOutsideFunctionLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(VarargOutsideFunctionLint)
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let mut visitor = VarargOutsideFunctionVisitor {
+            // The file itself is given varargs
+            functions: vec![true],
+            positions: Vec::new(),
+        };
+
+        visitor.visit_ast(ast);
+
+        visitor
+            .positions
+            .iter()
+            .map(|position| {
+                Diagnostic::new_complete(
+                    "vararg_outside_function",
+                    "`...` is used inside of a function that does not take varargs".to_owned(),
+                    Label::new(*position),
+                    vec![
+                        "note: varargs are not captured by closures, so `...` can only refer to the function it is used in".to_owned(),
+                        "help: add `...` to the end of the function's parameters".to_owned(),
+                    ],
+                    Vec::new(),
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Correctness
+    }
+}
+
+struct VarargOutsideFunctionVisitor {
+    // Whether each function body (and the file itself) takes varargs
+    functions: Vec<bool>,
+    positions: Vec<(usize, usize)>,
+}
+
+impl Visitor<'_> for VarargOutsideFunctionVisitor {
+    fn visit_function_body(&mut self, body: &ast::FunctionBody) {
+        self.functions.push(
+            body.iter_parameters()
+                .any(|parameter| matches!(parameter, ast::Parameter::Ellipse(_))),
+        );
+    }
+
+    fn visit_function_body_end(&mut self, _: &ast::FunctionBody) {
+        self.functions.pop();
+    }
+
+    fn visit_value(&mut self, value: &ast::Value) {
+        if let ast::Value::Symbol(symbol) = value {
+            if *symbol.token_type()
+                == (TokenType::Symbol {
+                    symbol: Symbol::Ellipse,
+                })
+                && !self.functions.last().unwrap()
+            {
+                self.positions.push(range(symbol));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_vararg_outside_function() {
+        test_lint(
+            VarargOutsideFunctionLint::new(()).unwrap(),
+            "vararg_outside_function",
+            "vararg_outside_function",
+        );
+    }
+}
@@ -0,0 +1,237 @@
+use super::*;
+use crate::ast_util::{
+    range,
+    scopes::{ScopeManager, Variable},
+};
+use std::{
+    collections::{HashMap, HashSet},
+    convert::Infallible,
+};
+
+use full_moon::{
+    ast::{self, Ast},
+    visitors::{Visit, Visitor},
+};
+use id_arena::Id;
+
+pub struct WriteOnlyVariableLint;
+
+impl Rule for WriteOnlyVariableLint {
+    type Config = ();
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(WriteOnlyVariableLint)
+    }
+
+    fn pass(&self, ast: &Ast, _: &Context) -> Vec<Diagnostic> {
+        let scope_manager = ScopeManager::new(ast);
+
+        let mut visitor = AssignmentVisitor {
+            assigned: Vec::new(),
+            locals: Vec::new(),
+            scope_manager: &scope_manager,
+            variables: scope_manager
+                .variables
+                .iter()
+                .map(|(id, variable)| (variable.identifiers[0], id))
+                .collect(),
+        };
+
+        visitor.visit_ast(ast);
+
+        let mut write_only = write_only_variables(&scope_manager, &visitor);
+        write_only.sort_by_key(|variable| scope_manager.variables[*variable].identifiers[0]);
+
+        write_only
+            .iter()
+            .map(|variable| {
+                let variable = &scope_manager.variables[*variable];
+
+                Diagnostic::new_complete(
+                    "write_only_variable",
+                    format!("`{}` is written to, but its value is never used", variable.name),
+                    Label::new(variable.identifiers[0]),
+                    vec![format!(
+                        "note: `{}` is only ever read to give a value to itself or other unused locals",
+                        variable.name
+                    )],
+                    Vec::new(),
+                )
+            })
+            .collect()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn rule_type(&self) -> RuleType {
+        RuleType::Style
+    }
+}
+
+// Where the value of an expression goes, such as `total + 1` in `total = total + 1`
+struct Assigned {
+    range: (usize, usize),
+    // The local given the value, if it is a local
+    variable: Option<Id<Variable>>,
+}
+
+#[derive(Default)]
+struct EffectVisitor {
+    effects: bool,
+}
+
+impl Visitor<'_> for EffectVisitor {
+    fn visit_function_body(&mut self, _: &ast::FunctionBody) {
+        self.effects = true;
+    }
+
+    fn visit_function_call(&mut self, _: &ast::FunctionCall) {
+        self.effects = true;
+    }
+}
+
+// Calls use the values given to them, and functions can read variables whenever they are called,
+// so reads inside of either are always used
+fn has_effects(expression: &ast::Expression) -> bool {
+    let mut visitor = EffectVisitor::default();
+    expression.visit(&mut visitor);
+    visitor.effects
+}
+
+struct AssignmentVisitor<'a> {
+    // Every expression assigned to a name, without calls or functions
+    assigned: Vec<Assigned>,
+    // Locals declared with `local`
+    locals: Vec<Id<Variable>>,
+    scope_manager: &'a ScopeManager,
+    // Every variable by where it was first declared
+    variables: HashMap<(usize, usize), Id<Variable>>,
+}
+
+impl AssignmentVisitor<'_> {
+    fn assign(&mut self, expression: &ast::Expression, variable: Option<Id<Variable>>) {
+        if !has_effects(expression) {
+            self.assigned.push(Assigned {
+                range: range(expression),
+                variable,
+            });
+        }
+    }
+}
+
+impl Visitor<'_> for AssignmentVisitor<'_> {
+    fn visit_assignment(&mut self, assignment: &ast::Assignment) {
+        for (var, expression) in assignment.var_list().iter().zip(assignment.expr_list()) {
+            // `t.field = value` and `t[key] = value` put the value somewhere else that can be read
+            if let ast::Var::Name(name) = var {
+                let resolved = self
+                    .scope_manager
+                    .reference_at_byte(range(name).0)
+                    .and_then(|reference| reference.resolved);
+
+                self.assign(expression, resolved);
+            }
+        }
+    }
+
+    fn visit_local_assignment(&mut self, local_assignment: &ast::LocalAssignment) {
+        for name in local_assignment.name_list() {
+            if let Some(variable) = self.variables.get(&range(name)).copied() {
+                self.locals.push(variable);
+            }
+        }
+
+        for (name, expression) in local_assignment
+            .name_list()
+            .iter()
+            .zip(local_assignment.expr_list())
+        {
+            let variable = self.variables.get(&range(name)).copied();
+            self.assign(expression, variable);
+        }
+    }
+}
+
+// Locals that are read, but only to give a value to themselves or other write only locals,
+// such as `count` in `count = count + 1`
+fn write_only_variables(
+    scope_manager: &ScopeManager,
+    visitor: &AssignmentVisitor,
+) -> Vec<Id<Variable>> {
+    // Every read of a local, and the local its value is assigned to, if any
+    let reads = visitor
+        .locals
+        .iter()
+        .map(|variable| {
+            let targets = scope_manager.variables[*variable]
+                .references
+                .iter()
+                .map(|reference| &scope_manager.references[*reference])
+                .filter(|reference| reference.read)
+                .map(|reference| {
+                    let position = reference.identifier.0;
+
+                    visitor
+                        .assigned
+                        .iter()
+                        .find(|assigned| {
+                            position >= assigned.range.0 && position < assigned.range.1
+                        })
+                        .and_then(|assigned| assigned.variable)
+                })
+                .collect::<Vec<_>>();
+
+            (*variable, targets)
+        })
+        // Locals that are never read at all are reported by unused_variable
+        .filter(|(_, targets)| !targets.is_empty())
+        .collect::<Vec<_>>();
+
+    let mut write_only = reads
+        .iter()
+        .map(|(variable, _)| *variable)
+        .collect::<HashSet<_>>();
+
+    // A local stops being write only once any of its reads are used, which can make the locals
+    // it was assigned from used as well
+    loop {
+        let used = reads
+            .iter()
+            .filter(|(variable, targets)| {
+                write_only.contains(variable)
+                    && targets.iter().any(|target| match target {
+                        Some(target) => !write_only.contains(target),
+                        None => true,
+                    })
+            })
+            .map(|(variable, _)| *variable)
+            .collect::<Vec<_>>();
+
+        if used.is_empty() {
+            break;
+        }
+
+        for variable in used {
+            write_only.remove(&variable);
+        }
+    }
+
+    write_only.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_util::test_lint, *};
+
+    #[test]
+    fn test_write_only_variable() {
+        test_lint(
+            WriteOnlyVariableLint::new(()).unwrap(),
+            "write_only_variable",
+            "write_only_variable",
+        );
+    }
+}
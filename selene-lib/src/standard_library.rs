@@ -1,7 +1,7 @@
 use std::{
-    collections::{BTreeMap, HashMap},
+    collections::BTreeMap,
     fmt, fs, io,
-    path::Path,
+    path::{Path, PathBuf},
 };
 
 use serde::{
@@ -10,6 +10,10 @@ use serde::{
     Deserialize, Serialize,
 };
 
+// Every file in default_std by its name, such as `lua51`, embedded by build.rs
+const DEFAULT_STANDARD_LIBRARIES: &[(&str, &str)] =
+    include!(concat!(env!("OUT_DIR"), "/default_std.rs"));
+
 lazy_static::lazy_static! {
     static ref ANY_TABLE: BTreeMap<String, Field> = {
         let mut map = BTreeMap::new();
@@ -40,17 +44,50 @@ pub struct StandardLibraryMeta {
 
 #[derive(Debug)]
 pub enum StandardLibraryError {
+    // A standard library is based on itself, through every file in the chain
+    BaseCycle(Vec<PathBuf>),
     DeserializeError(toml::de::Error),
     IoError(io::Error),
+    // A function has a required argument after an optional one, which can't be left out
+    RequiredAfterOptional { function: String },
+    // A field is a struct that was never defined in `[selene.structs]`
+    UndefinedStruct { name: String, used_by: String },
+    // A segment of a library name, such as `busted` in `lua51+busted`, isn't built in and has no file
+    UnknownLibrary { name: String, path: PathBuf },
 }
 
 impl fmt::Display for StandardLibraryError {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         match self {
+            StandardLibraryError::BaseCycle(chain) => write!(
+                formatter,
+                "standard library is based on itself: {}",
+                chain
+                    .iter()
+                    .map(|path| path.display().to_string())
+                    .collect::<Vec<_>>()
+                    .join(" -> "),
+            ),
             StandardLibraryError::DeserializeError(error) => {
                 write!(formatter, "deserialize error: {}", error)
             }
             StandardLibraryError::IoError(error) => write!(formatter, "io error: {}", error),
+            StandardLibraryError::RequiredAfterOptional { function } => write!(
+                formatter,
+                "`{}` has a required argument after an optional one",
+                function,
+            ),
+            StandardLibraryError::UndefinedStruct { name, used_by } => write!(
+                formatter,
+                "`{}` is a struct named `{}`, but no struct with that name is defined",
+                used_by, name,
+            ),
+            StandardLibraryError::UnknownLibrary { name, path } => write!(
+                formatter,
+                "unknown standard library `{}`, it is not built in and {} does not exist",
+                name,
+                path.display(),
+            ),
         }
     }
 }
@@ -62,6 +99,10 @@ impl std::error::Error for StandardLibraryError {
         match self {
             DeserializeError(error) => Some(error),
             IoError(error) => Some(error),
+            BaseCycle(_)
+            | RequiredAfterOptional { .. }
+            | UndefinedStruct { .. }
+            | UnknownLibrary { .. } => None,
         }
     }
 }
@@ -74,56 +115,49 @@ impl From<io::Error> for StandardLibraryError {
 
 impl StandardLibrary {
     pub fn from_name(name: &str) -> Option<StandardLibrary> {
-        macro_rules! names {
-            {$($name:expr => $path:expr,)+} => {
-                match name {
-                    $(
-                        $name => {
-                            let mut std = toml::from_str::<StandardLibrary>(
-                                include_str!($path)
-                            ).unwrap_or_else(|error| {
-                                panic!(
-                                    "default standard library '{}' failed deserialization: {}",
-                                    name,
-                                    error,
-                                )
-                            });
-
-                            if let Some(meta) = &std.meta {
-                                if let Some(base_name) = &meta.base {
-                                    let base = StandardLibrary::from_name(base_name);
-
-                                    std.extend(
-                                        base.expect("built-in library based off of non-existent built-in"),
-                                    );
-                                }
-                            }
-
-                            std.inflate();
-
-                            Some(std)
-                        },
-                    )+
+        let (_, contents) = DEFAULT_STANDARD_LIBRARIES
+            .iter()
+            .find(|(default_name, _)| *default_name == name)?;
+
+        let mut std = toml::from_str::<StandardLibrary>(contents).unwrap_or_else(|error| {
+            panic!(
+                "default standard library '{}' failed deserialization: {}",
+                name, error,
+            )
+        });
+
+        if let Some(meta) = &std.meta {
+            if let Some(base_name) = &meta.base {
+                let base = StandardLibrary::from_name(base_name);
 
-                    _ => None
-                }
-            };
+                std.extend(base.expect("built-in library based off of non-existent built-in"));
+            }
         }
 
-        names! {
-            "lua51" => "../default_std/lua51.toml",
-            "lua52" => "../default_std/lua52.toml",
-        }
+        std.inflate().unwrap_or_else(|error| {
+            panic!("default standard library '{}' is invalid: {}", name, error)
+        });
+
+        Some(std)
     }
 
     pub fn from_config_name(
         name: &str,
         directory: Option<&Path>,
+    ) -> Result<Option<StandardLibrary>, StandardLibraryError> {
+        Self::from_config_name_with_chain(name, directory, &mut Vec::new())
+    }
+
+    // `chain` is every file currently being loaded, each based on the next, to catch cycles
+    fn from_config_name_with_chain(
+        name: &str,
+        directory: Option<&Path>,
+        chain: &mut Vec<PathBuf>,
     ) -> Result<Option<StandardLibrary>, StandardLibraryError> {
         let mut library: Option<StandardLibrary> = None;
 
         for segment in name.split('+') {
-            let segment_library = match StandardLibrary::from_name(segment) {
+            let mut segment_library = match StandardLibrary::from_name(segment) {
                 Some(default) => default,
 
                 None => {
@@ -136,37 +170,84 @@ impl StandardLibrary {
                             )
                         );
 
-                    path.push(format!("{}.toml", segment));
-                    match StandardLibrary::from_file(&path)? {
+                    // Either the name of a library next to this one, or a path to its file
+                    if segment.ends_with(".toml") {
+                        path.push(segment);
+                    } else {
+                        path.push(format!("{}.toml", segment));
+                    }
+
+                    if !path.exists() {
+                        return Err(StandardLibraryError::UnknownLibrary {
+                            name: segment.to_owned(),
+                            path,
+                        });
+                    }
+
+                    match StandardLibrary::from_file_with_chain(&path, chain)? {
                         Some(library) => library,
                         None => return Ok(None),
                     }
                 }
             };
 
-            match library {
-                Some(ref mut base) => base.extend(segment_library),
-                None => library = Some(segment_library),
-            };
+            // Later libraries take precedence over earlier ones, such as `busted` in `lua51+busted`
+            library = Some(match library {
+                Some(earlier) => {
+                    let earlier_name = earlier.meta.as_ref().and_then(|meta| meta.name.clone());
+                    segment_library.extend(earlier);
+
+                    // Keep the name of `roblox` in `roblox+busted`, so Roblox lints still run
+                    let meta = segment_library.meta.get_or_insert_with(Default::default);
+                    if meta.name.is_none() {
+                        meta.name = earlier_name;
+                    }
+
+                    segment_library
+                }
+
+                None => segment_library,
+            });
         }
 
         if let Some(ref mut library) = library {
-            library.inflate();
+            library.inflate()?;
         }
 
         Ok(library)
     }
 
     pub fn from_file(filename: &Path) -> Result<Option<StandardLibrary>, StandardLibraryError> {
+        Self::from_file_with_chain(filename, &mut Vec::new())
+    }
+
+    fn from_file_with_chain(
+        filename: &Path,
+        chain: &mut Vec<PathBuf>,
+    ) -> Result<Option<StandardLibrary>, StandardLibraryError> {
         let content = fs::read_to_string(filename)?;
         let mut library: StandardLibrary =
             toml::from_str(&content).map_err(StandardLibraryError::DeserializeError)?;
 
+        let canonical = filename.canonicalize()?;
+
+        if let Some(start) = chain.iter().position(|loading| *loading == canonical) {
+            let mut cycle = chain[start..].to_vec();
+            cycle.push(canonical);
+            return Err(StandardLibraryError::BaseCycle(cycle));
+        }
+
         if let Some(meta) = &library.meta {
             if let Some(base_name) = &meta.base {
-                if let Some(base) =
-                    StandardLibrary::from_config_name(&base_name, filename.parent())?
-                {
+                chain.push(canonical);
+                let base = StandardLibrary::from_config_name_with_chain(
+                    &base_name,
+                    filename.parent(),
+                    chain,
+                )?;
+                chain.pop();
+
+                if let Some(base) = base {
                     library.extend(base);
                 }
             }
@@ -247,12 +328,22 @@ impl StandardLibrary {
                     self.meta = Some(StandardLibraryMeta::default());
                 }
 
-                let meta = self.meta.as_mut().unwrap();
-
-                if let Some(structs) = meta.structs.as_mut() {
-                    structs.extend(other_structs.iter().map(|(k, v)| (k.clone(), v.clone())));
-                } else {
-                    meta.structs = Some(other_structs.clone());
+                let structs = self
+                    .meta
+                    .as_mut()
+                    .unwrap()
+                    .structs
+                    .get_or_insert_with(BTreeMap::new);
+
+                // Like globals, our own fields take precedence over the other library's
+                for (name, other_fields) in other_structs.iter() {
+                    let fields = structs.entry(name.to_owned()).or_default();
+
+                    for (field_name, field) in other_fields {
+                        fields
+                            .entry(field_name.to_owned())
+                            .or_insert_with(|| field.clone());
+                    }
                 }
             }
         }
@@ -263,7 +354,48 @@ impl StandardLibrary {
         self.globals = globals;
     }
 
-    pub fn inflate(&mut self) {
+    // Makes the structs defined in `[selene.structs]` available to `unstruct`.
+    // Struct fields are left as references rather than copied, which is what lets a struct
+    // contain itself, such as `Instance.Parent` being an `Instance`.
+    pub fn inflate(&mut self) -> Result<(), StandardLibraryError> {
+        fn check_fields(
+            structs: &BTreeMap<String, Field>,
+            path: &str,
+            fields: &BTreeMap<String, Field>,
+        ) -> Result<(), StandardLibraryError> {
+            for (name, field) in fields {
+                let path = if path.is_empty() {
+                    name.to_owned()
+                } else {
+                    format!("{}.{}", path, name)
+                };
+
+                match field {
+                    Field::Struct(struct_name) if !structs.contains_key(struct_name) => {
+                        return Err(StandardLibraryError::UndefinedStruct {
+                            name: struct_name.to_owned(),
+                            used_by: path,
+                        });
+                    }
+
+                    Field::Function { arguments, .. }
+                        if arguments
+                            .iter()
+                            .skip_while(|argument| argument.required != Required::NotRequired)
+                            .any(|argument| argument.required != Required::NotRequired) =>
+                    {
+                        return Err(StandardLibraryError::RequiredAfterOptional { function: path });
+                    }
+
+                    Field::Table(children) => check_fields(structs, &path, children)?,
+
+                    _ => {}
+                }
+            }
+
+            Ok(())
+        }
+
         let structs = self
             .meta
             .as_ref()
@@ -274,15 +406,32 @@ impl StandardLibrary {
             self.structs
                 .insert(name.to_owned(), Field::Table(children.clone()));
         }
+
+        // Each struct is only checked once, rather than every place it is used
+        check_fields(&self.structs, "", &self.globals)?;
+        check_fields(&self.structs, "selene.structs", &self.structs)?;
+
+        Ok(())
     }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Field {
     Any,
+    // A global that no longer exists, such as `unpack` in Lua 5.2. It's kept so that uses of it can
+    // be given a suggestion, rather than being reported as undefined.
+    Deprecated {
+        removed_in: String,
+        suggestion: Option<String>,
+    },
     Function {
         arguments: Vec<Argument>,
         method: bool,
+        // How many values the function returns, if it's always the same
+        returns: Option<usize>,
+        // Whether the function returns `nil` and an error message when it fails, like `io.open`
+        failable: bool,
+        deprecated: Option<Deprecated>,
     },
     Property {
         writable: Option<Writable>,
@@ -304,7 +453,18 @@ impl<'de> Deserialize<'de> for Field {
             return Ok(Field::Removed);
         }
 
-        let is_function = field_raw.args.is_some() || field_raw.method;
+        if let Some(removed_in) = field_raw.removed_in {
+            return Ok(Field::Deprecated {
+                removed_in,
+                suggestion: field_raw.suggestion,
+            });
+        }
+
+        let is_function = field_raw.args.is_some()
+            || field_raw.method
+            || field_raw.returns.is_some()
+            || field_raw.failable
+            || field_raw.deprecated.is_some();
 
         if !field_raw.property
             && !is_function
@@ -316,6 +476,13 @@ impl<'de> Deserialize<'de> for Field {
             ));
         }
 
+        // Otherwise, it would be silently ignored and the field would stay read-only
+        if field_raw.writable.is_some() && !field_raw.property {
+            return Err(de::Error::custom(
+                "only properties can be writable, use `property = true`",
+            ));
+        }
+
         if field_raw.property && is_function {
             return Err(de::Error::custom("field is both a property and a function"));
         }
@@ -331,10 +498,28 @@ impl<'de> Deserialize<'de> for Field {
         }
 
         if is_function {
-            // TODO: Don't allow vararg in the middle
+            let arguments = field_raw.args.unwrap_or_else(Vec::new);
+
+            if let Some(deprecated) = &field_raw.deprecated {
+                deprecated
+                    .check_replacement(&arguments)
+                    .map_err(de::Error::custom)?;
+            }
+
+            if let Some((_, rest)) = arguments.split_last() {
+                if rest.iter().any(Argument::is_vararg) {
+                    return Err(de::Error::custom(
+                        "only the last argument can be `...` or `variadic`",
+                    ));
+                }
+            }
+
             return Ok(Field::Function {
-                arguments: field_raw.args.unwrap_or_else(Vec::new),
+                arguments,
                 method: field_raw.method,
+                returns: field_raw.returns,
+                failable: field_raw.failable,
+                deprecated: field_raw.deprecated,
             });
         }
 
@@ -351,12 +536,39 @@ impl Serialize for Field {
                 map.end()
             }
 
-            Field::Function { arguments, method } => {
+            Field::Deprecated {
+                removed_in,
+                suggestion,
+            } => {
+                let mut map = serializer.serialize_map(None)?;
+                map.serialize_entry("removed_in", removed_in)?;
+                if let Some(suggestion) = suggestion {
+                    map.serialize_entry("suggestion", suggestion)?;
+                }
+                map.end()
+            }
+
+            Field::Function {
+                arguments,
+                method,
+                returns,
+                failable,
+                deprecated,
+            } => {
                 let mut map = serializer.serialize_map(None)?;
                 if *method {
                     map.serialize_entry("method", &true)?;
                 }
                 map.serialize_entry("args", arguments)?;
+                if let Some(returns) = returns {
+                    map.serialize_entry("returns", returns)?;
+                }
+                if *failable {
+                    map.serialize_entry("failable", &true)?;
+                }
+                if let Some(deprecated) = deprecated {
+                    map.serialize_entry("deprecated", deprecated)?;
+                }
                 map.end()
             }
 
@@ -389,6 +601,135 @@ impl Serialize for Field {
     }
 }
 
+// A function that still exists, but shouldn't be used anymore, such as `wait` in Roblox
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Deprecated {
+    pub message: String,
+    // What to use instead, such as `task.wait(%1)`, where `%1` is the first argument
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replace: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ReplacementPart<'a> {
+    Text(&'a str),
+    // Which argument to use, starting at 1
+    Argument(usize),
+}
+
+// Splits `task.wait(%1)` into `task.wait(`, the first argument, and `)`. `%%` is a literal `%`.
+fn replacement_parts(replace: &str) -> Result<Vec<ReplacementPart<'_>>, String> {
+    let mut parts = Vec::new();
+    let mut text_start = 0;
+    let mut chars = replace.char_indices().peekable();
+
+    while let Some((index, character)) = chars.next() {
+        if character != '%' {
+            continue;
+        }
+
+        parts.push(ReplacementPart::Text(&replace[text_start..index]));
+
+        match chars.peek() {
+            Some((percent, '%')) => {
+                parts.push(ReplacementPart::Text(&replace[*percent..=*percent]));
+                chars.next();
+                text_start = index + 2;
+            }
+
+            Some((start, digit)) if digit.is_ascii_digit() => {
+                let start = *start;
+                let mut end = start;
+
+                while let Some((next, digit)) = chars.peek() {
+                    if !digit.is_ascii_digit() {
+                        break;
+                    }
+
+                    end = *next + 1;
+                    chars.next();
+                }
+
+                let argument = replace[start..end].parse().unwrap();
+                if argument == 0 {
+                    return Err(format!(
+                        "`{}` uses `%0`, but arguments start at `%1`",
+                        replace
+                    ));
+                }
+
+                parts.push(ReplacementPart::Argument(argument));
+                text_start = end;
+            }
+
+            _ => {
+                return Err(format!(
+                    "`{}` has a `%` that isn't followed by an argument number, use `%%` for a literal `%`",
+                    replace
+                ));
+            }
+        }
+    }
+
+    parts.push(ReplacementPart::Text(&replace[text_start..]));
+    parts.retain(|part| *part != ReplacementPart::Text(""));
+    Ok(parts)
+}
+
+impl Deprecated {
+    fn check_replacement(&self, arguments: &[Argument]) -> Result<(), String> {
+        let replace = match &self.replace {
+            Some(replace) => replace,
+            None => return Ok(()),
+        };
+
+        // Any amount of arguments can be given to a vararg
+        if matches!(arguments.last(), Some(last) if last.is_vararg()) {
+            return replacement_parts(replace).map(|_| ());
+        }
+
+        for part in replacement_parts(replace)? {
+            if let ReplacementPart::Argument(argument) = part {
+                if argument > arguments.len() {
+                    return Err(format!(
+                        "`{}` uses `%{}`, but the function only has {} {}",
+                        replace,
+                        argument,
+                        arguments.len(),
+                        if arguments.len() == 1 {
+                            "argument"
+                        } else {
+                            "arguments"
+                        },
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The replacement with the arguments of a call filled in, such as `task.wait(5)` for
+    /// `wait(5)`. Returns None if there is no replacement, or it uses an argument that wasn't
+    /// passed.
+    pub fn replacement(&self, arguments: &[String]) -> Option<String> {
+        let parts = replacement_parts(self.replace.as_ref()?).ok()?;
+        let mut replacement = String::new();
+
+        for part in parts {
+            match part {
+                ReplacementPart::Text(text) => replacement.push_str(text),
+                ReplacementPart::Argument(argument) => {
+                    replacement.push_str(arguments.get(argument - 1)?)
+                }
+            }
+        }
+
+        Some(replacement)
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(rename_all = "kebab-case")]
 pub enum Writable {
@@ -409,10 +750,20 @@ struct FieldSerde {
     #[serde(default)]
     removed: bool,
     #[serde(default)]
+    removed_in: Option<String>,
+    #[serde(default)]
+    suggestion: Option<String>,
+    #[serde(default)]
     writable: Option<Writable>,
     #[serde(default)]
     args: Option<Vec<Argument>>,
     #[serde(default)]
+    returns: Option<usize>,
+    #[serde(default)]
+    failable: bool,
+    #[serde(default)]
+    deprecated: Option<Deprecated>,
+    #[serde(default)]
     #[serde(rename = "struct")]
     strukt: Option<String>,
     #[serde(default)]
@@ -428,6 +779,20 @@ pub struct Argument {
     pub required: Required,
     #[serde(rename = "type")]
     pub argument_type: ArgumentType,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "is_false")]
+    pub variadic: bool,
+}
+
+impl Argument {
+    /// Whether this argument takes any amount of values, either by being `...` or `variadic`
+    pub fn is_vararg(&self) -> bool {
+        self.variadic || self.argument_type == ArgumentType::Vararg
+    }
+}
+
+fn is_false(value: &bool) -> bool {
+    !value
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -444,6 +809,7 @@ pub enum ArgumentType {
     String,
     // TODO: Types for tables
     Table,
+    Union(Vec<ArgumentType>),
     // TODO: Support repeating types (like for string.char)
     Vararg,
 }
@@ -461,11 +827,9 @@ impl Serialize for ArgumentType {
             | &ArgumentType::Vararg => serializer.serialize_str(&self.to_string()),
 
             ArgumentType::Constant(constants) => {
-                let mut seq = serializer.serialize_seq(Some(constants.len()))?;
-                for constant in constants {
-                    seq.serialize_element(constant)?;
-                }
-                seq.end()
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry("enum", constants)?;
+                map.end()
             }
 
             ArgumentType::Display(display) => {
@@ -473,6 +837,22 @@ impl Serialize for ArgumentType {
                 map.serialize_entry("display", display)?;
                 map.end()
             }
+
+            ArgumentType::Union(types) => {
+                let mut seq = serializer.serialize_seq(None)?;
+                for argument_type in types {
+                    match argument_type {
+                        ArgumentType::Constant(constants) => {
+                            for constant in constants {
+                                seq.serialize_element(constant)?;
+                            }
+                        }
+
+                        other => seq.serialize_element(&other.to_string())?,
+                    }
+                }
+                seq.end()
+            }
         }
     }
 }
@@ -489,71 +869,121 @@ impl<'de> Visitor<'de> for ArgumentTypeVisitor {
     type Value = ArgumentType;
 
     fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        formatter.write_str("an argument type or an array of constant strings")
+        formatter.write_str("an argument type, an array of types and constant strings, or a map")
     }
 
     fn visit_map<A: de::MapAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
-        let mut map: HashMap<String, String> = HashMap::new();
+        let argument_type = match access.next_key::<String>()?.as_deref() {
+            Some("display") => ArgumentType::Display(access.next_value()?),
+            Some("enum") => ArgumentType::Constant(access.next_value()?),
+            _ => {
+                return Err(de::Error::custom(
+                    "map value must have a `display` or `enum` property",
+                ))
+            }
+        };
 
-        while let Some((key, value)) = access.next_entry()? {
-            map.insert(key, value);
+        if access.next_key::<String>()?.is_some() {
+            return Err(de::Error::custom(
+                "map value must only have one of `display` or `enum`",
+            ));
         }
 
-        if let Some(display) = map.remove("display") {
-            Ok(ArgumentType::Display(display))
-        } else {
-            Err(de::Error::custom(
-                "map value must have a `display` property",
-            ))
-        }
+        Ok(argument_type)
     }
 
+    // `["r", "w"]` is a list of constants, `["string", "number"]` is a union of types, and
+    // `["number", "*a"]` is a union of a number and the constants
     fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut types = Vec::new();
         let mut constants = Vec::new();
 
-        while let Some(value) = seq.next_element()? {
-            constants.push(value);
+        while let Some(value) = seq.next_element::<String>()? {
+            match ArgumentType::from_type_name(&value) {
+                Some(ArgumentType::Vararg) => {
+                    return Err(de::Error::custom("`...` can't be a part of a union"));
+                }
+
+                Some(argument_type) => types.push(argument_type),
+                None => constants.push(value),
+            }
+        }
+
+        if types.is_empty() {
+            return Ok(ArgumentType::Constant(constants));
         }
 
-        Ok(ArgumentType::Constant(constants))
+        if !constants.is_empty() {
+            types.push(ArgumentType::Constant(constants));
+        }
+
+        Ok(ArgumentType::Union(types))
     }
 
     fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
-        match value {
-            "any" => Ok(ArgumentType::Any),
-            "bool" => Ok(ArgumentType::Bool),
-            "function" => Ok(ArgumentType::Function),
-            "nil" => Ok(ArgumentType::Nil),
-            "number" => Ok(ArgumentType::Number),
-            "string" => Ok(ArgumentType::String),
-            "table" => Ok(ArgumentType::Table),
-            "..." => Ok(ArgumentType::Vararg),
-            other => Err(de::Error::custom(format!("unknown type {}", other))),
+        ArgumentType::from_type_name(value)
+            .ok_or_else(|| de::Error::custom(format!("unknown type {}", value)))
+    }
+}
+
+impl ArgumentType {
+    fn from_type_name(name: &str) -> Option<Self> {
+        match name {
+            "any" => Some(ArgumentType::Any),
+            "bool" => Some(ArgumentType::Bool),
+            "function" => Some(ArgumentType::Function),
+            "nil" => Some(ArgumentType::Nil),
+            "number" => Some(ArgumentType::Number),
+            "string" => Some(ArgumentType::String),
+            "table" => Some(ArgumentType::Table),
+            "..." => Some(ArgumentType::Vararg),
+            _ => None,
         }
     }
 }
 
+// How many constants are shown in diagnostics before the rest are left out
+const MAX_DISPLAYED_CONSTANTS: usize = 10;
+
 impl fmt::Display for ArgumentType {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         match self {
             ArgumentType::Any => write!(formatter, "any"),
             ArgumentType::Bool => write!(formatter, "bool"),
-            ArgumentType::Constant(options) => write!(
-                formatter,
-                "{}",
-                // TODO: This gets pretty ugly with a lot of variants
-                options
+            ArgumentType::Constant(options) => {
+                let shown = options
                     .iter()
+                    .take(MAX_DISPLAYED_CONSTANTS)
                     .map(|string| format!("\"{}\"", string))
                     .collect::<Vec<_>>()
-                    .join(", ")
-            ),
+                    .join(", ");
+
+                if options.len() > MAX_DISPLAYED_CONSTANTS {
+                    write!(
+                        formatter,
+                        "{}, and {} more",
+                        shown,
+                        options.len() - MAX_DISPLAYED_CONSTANTS
+                    )
+                } else {
+                    write!(formatter, "{}", shown)
+                }
+            }
             ArgumentType::Display(display) => write!(formatter, "{}", display),
             ArgumentType::Function => write!(formatter, "function"),
             ArgumentType::Nil => write!(formatter, "nil"),
             ArgumentType::Number => write!(formatter, "number"),
             ArgumentType::String => write!(formatter, "string"),
             ArgumentType::Table => write!(formatter, "table"),
+            ArgumentType::Union(types) => write!(
+                formatter,
+                "{}",
+                types
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(" | ")
+            ),
             ArgumentType::Vararg => write!(formatter, "..."),
         }
     }
@@ -623,5 +1053,444 @@ mod tests {
     fn valid_serde() {
         StandardLibrary::from_name("lua51").expect("lua51.toml wasn't found");
         StandardLibrary::from_name("lua52").expect("lua52.toml wasn't found");
+        StandardLibrary::from_name("lua53").expect("lua53.toml wasn't found");
+        StandardLibrary::from_name("luajit").expect("luajit.toml wasn't found");
+    }
+
+    #[test]
+    fn lua_versions() {
+        let path = |path: &str| path.split('.').map(ToOwned::to_owned).collect::<Vec<_>>();
+        let is_removed = |std: &StandardLibrary, name: &str| {
+            matches!(std.globals.get(name), Some(Field::Deprecated { .. }))
+        };
+
+        let lua51 = StandardLibrary::from_name("lua51").unwrap();
+        let lua52 = StandardLibrary::from_name("lua52").unwrap();
+        let lua53 = StandardLibrary::from_name("lua53").unwrap();
+
+        assert!(lua51.find_global(&path("setfenv")).is_some());
+        assert!(lua51.find_global(&path("table.unpack")).is_none());
+        assert!(lua51.find_global(&path("bit32.band")).is_none());
+
+        assert!(is_removed(&lua52, "setfenv"));
+        assert!(is_removed(&lua52, "module"));
+        assert!(lua52.find_global(&path("table.unpack")).is_some());
+        assert!(lua52.find_global(&path("table.pack")).is_some());
+        assert!(lua52.find_global(&path("bit32.band")).is_some());
+        assert!(lua52.find_global(&path("rawlen")).is_some());
+        assert!(lua52.find_global(&path("math.type")).is_none());
+        assert!(lua52.find_global(&path("utf8.char")).is_none());
+
+        assert!(is_removed(&lua53, "setfenv"));
+        assert!(is_removed(&lua53, "bit32"));
+        assert!(lua53.find_global(&path("table.unpack")).is_some());
+        assert!(lua53.find_global(&path("table.move")).is_some());
+        assert!(lua53.find_global(&path("math.type")).is_some());
+        assert!(lua53.find_global(&path("math.maxinteger")).is_some());
+        assert!(lua53.find_global(&path("utf8.char")).is_some());
+        assert!(lua53.find_global(&path("string.pack")).is_some());
+
+        match lua53.find_global(&path("math.pow")) {
+            Some(Field::Function { deprecated, .. }) => assert!(deprecated.is_some()),
+            other => panic!("math.pow is not a function: {:?}", other),
+        }
+    }
+
+    // Lints a snippet under every built-in library, catching globals they use but don't define
+    #[test]
+    fn bundled_libraries() {
+        let snippets = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("standard_libraries")
+            .join("bundled");
+
+        for (name, _) in DEFAULT_STANDARD_LIBRARIES {
+            let code =
+                fs::read_to_string(snippets.join(format!("{}.lua", name))).unwrap_or_else(|_| {
+                    panic!(
+                        "{} has no snippet in tests/standard_libraries/bundled",
+                        name
+                    )
+                });
+
+            let config = crate::CheckerConfig::<toml::value::Value> {
+                std: (*name).to_owned(),
+                ..crate::CheckerConfig::default()
+            };
+
+            let checker =
+                crate::Checker::new(config, StandardLibrary::from_name(name).unwrap()).unwrap();
+
+            let ast = full_moon::ast::owned::Owned::owned(&full_moon::parse(&code).unwrap());
+
+            let diagnostics = checker
+                .test_on(&ast)
+                .into_iter()
+                .filter(|diagnostic| {
+                    diagnostic.diagnostic.code == "undefined_variable"
+                        || diagnostic.diagnostic.code == "incorrect_standard_library_use"
+                })
+                .map(|diagnostic| diagnostic.diagnostic.message)
+                .collect::<Vec<_>>();
+
+            assert!(
+                diagnostics.is_empty(),
+                "{}'s snippet has diagnostics: {:?}",
+                name,
+                diagnostics
+            );
+        }
+    }
+
+    #[test]
+    fn method_serde() {
+        let std: StandardLibrary = toml::from_str(
+            r#"
+            [foo.bar]
+            method = true
+            args = []
+
+            [foo.baz]
+            args = []
+            "#,
+        )
+        .unwrap();
+
+        let path = |path: &str| path.split('.').map(ToOwned::to_owned).collect::<Vec<_>>();
+
+        assert!(matches!(
+            std.find_global(&path("foo.bar")),
+            Some(Field::Function { method: true, .. })
+        ));
+
+        assert!(matches!(
+            std.find_global(&path("foo.baz")),
+            Some(Field::Function { method: false, .. })
+        ));
+
+        let round_trip: StandardLibrary = toml::from_str(&toml::to_string(&std).unwrap()).unwrap();
+        assert_eq!(round_trip, std);
+    }
+
+    #[test]
+    fn deprecated_replacement() {
+        let deprecated = |replace: &str| Deprecated {
+            message: "deprecated".to_owned(),
+            replace: Some(replace.to_owned()),
+        };
+
+        let arguments = vec!["x".to_owned(), "10".to_owned()];
+
+        assert_eq!(
+            deprecated("math.log(%1, 10)").replacement(&arguments),
+            Some("math.log(x, 10)".to_owned())
+        );
+        assert_eq!(
+            deprecated("f(%2, %1)").replacement(&arguments),
+            Some("f(10, x)".to_owned())
+        );
+        assert_eq!(
+            deprecated("%1 %% 2").replacement(&arguments),
+            Some("x % 2".to_owned())
+        );
+        assert_eq!(deprecated("f(%3)").replacement(&arguments), None);
+    }
+
+    #[test]
+    fn deprecated_validation() {
+        let parse = |replace: &str| {
+            toml::from_str::<StandardLibrary>(&format!(
+                r#"
+                [wait.deprecated]
+                message = "use `task.wait` instead"
+                replace = "{}"
+
+                [[wait.args]]
+                type = "number"
+                "#,
+                replace
+            ))
+        };
+
+        assert!(parse("task.wait(%1)").is_ok());
+        assert!(parse("task.wait()").is_ok());
+        assert!(parse("task.wait(%2)").is_err());
+        assert!(parse("task.wait(%0)").is_err());
+        assert!(parse("task.wait(%a)").is_err());
+        assert!(parse("task.wait(%1 %)").is_err());
+    }
+
+    #[test]
+    fn argument_type_serde() {
+        let std: StandardLibrary = toml::from_str(
+            r#"
+            [[foo.args]]
+            type = ["r", "w"]
+
+            [[foo.args]]
+            type = ["string", "number"]
+
+            [[foo.args]]
+            type = ["number", "*a", "*l"]
+
+            [[foo.args]]
+            type = { enum = ["nil", "string"] }
+            "#,
+        )
+        .unwrap();
+
+        let arguments = match std.find_global(&["foo".to_owned()]) {
+            Some(Field::Function { arguments, .. }) => arguments
+                .iter()
+                .map(|argument| argument.argument_type.clone())
+                .collect::<Vec<_>>(),
+            other => panic!("foo is not a function: {:?}", other),
+        };
+
+        let constants = |constants: &[&str]| {
+            ArgumentType::Constant(constants.iter().map(ToString::to_string).collect())
+        };
+
+        assert_eq!(
+            arguments,
+            vec![
+                constants(&["r", "w"]),
+                ArgumentType::Union(vec![ArgumentType::String, ArgumentType::Number]),
+                ArgumentType::Union(vec![ArgumentType::Number, constants(&["*a", "*l"])]),
+                constants(&["nil", "string"]),
+            ]
+        );
+
+        let round_trip: StandardLibrary = toml::from_str(&toml::to_string(&std).unwrap()).unwrap();
+        assert_eq!(round_trip, std);
+
+        assert!(toml::from_str::<StandardLibrary>(
+            r#"
+            [[foo.args]]
+            type = ["string", "..."]
+            "#,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn vararg_must_be_last() {
+        assert!(toml::from_str::<StandardLibrary>(
+            r#"
+            [[foo.args]]
+            type = "..."
+
+            [[foo.args]]
+            type = "number"
+            "#,
+        )
+        .is_err());
+
+        assert!(toml::from_str::<StandardLibrary>(
+            r#"
+            [[foo.args]]
+            type = "number"
+            variadic = true
+
+            [[foo.args]]
+            type = "string"
+            "#,
+        )
+        .is_err());
+
+        let std: StandardLibrary = toml::from_str(
+            r#"
+            [[foo.args]]
+            type = "string"
+
+            [[foo.args]]
+            type = "number"
+            variadic = true
+            "#,
+        )
+        .unwrap();
+
+        let round_trip: StandardLibrary = toml::from_str(&toml::to_string(&std).unwrap()).unwrap();
+        assert_eq!(round_trip, std);
+    }
+
+    #[test]
+    fn writable_requires_property() {
+        assert!(toml::from_str::<StandardLibrary>(
+            r#"
+            [shared]
+            writable = "new-fields"
+            "#,
+        )
+        .is_err());
+
+        assert!(toml::from_str::<StandardLibrary>(
+            r#"
+            [shared]
+            property = true
+            writable = "new-fields"
+            "#,
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn recursive_structs() {
+        let mut std: StandardLibrary = toml::from_str(
+            r#"
+            [selene.structs.Instance.Parent]
+            struct = "Instance"
+
+            [selene.structs.Instance.Destroy]
+            method = true
+            args = []
+
+            [workspace]
+            struct = "Instance"
+            "#,
+        )
+        .unwrap();
+
+        std.inflate().unwrap();
+
+        let path = |path: &str| path.split('.').map(ToOwned::to_owned).collect::<Vec<_>>();
+
+        assert!(matches!(
+            std.find_global(&path("workspace.Parent.Parent.Destroy")),
+            Some(Field::Function { method: true, .. })
+        ));
+    }
+
+    #[test]
+    fn undefined_structs() {
+        let mut std: StandardLibrary = toml::from_str(
+            r#"
+            [selene.structs.Instance.Changed]
+            struct = "Event"
+
+            [workspace]
+            struct = "Instance"
+            "#,
+        )
+        .unwrap();
+
+        match std.inflate() {
+            Err(StandardLibraryError::UndefinedStruct { name, used_by }) => {
+                assert_eq!(name, "Event");
+                assert_eq!(used_by, "selene.structs.Instance.Changed");
+            }
+
+            other => panic!("expected an undefined struct error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn required_after_optional() {
+        let mut std: StandardLibrary = toml::from_str(
+            r#"
+            [[string.find.args]]
+            type = "string"
+
+            [[string.find.args]]
+            type = "number"
+            required = false
+
+            [[string.find.args]]
+            type = "bool"
+            "#,
+        )
+        .unwrap();
+
+        match std.inflate() {
+            Err(StandardLibraryError::RequiredAfterOptional { function }) => {
+                assert_eq!(function, "string.find");
+            }
+
+            other => panic!("expected a required after optional error, got {:?}", other),
+        }
+    }
+
+    fn test_library_path(name: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("standard_libraries")
+            .join(name)
+    }
+
+    #[test]
+    fn based_on_file() {
+        let std = StandardLibrary::from_config_name("app", Some(&test_library_path("")))
+            .unwrap()
+            .unwrap();
+
+        let path = |path: &str| path.split('.').map(ToOwned::to_owned).collect::<Vec<_>>();
+
+        // From lua51, through framework.toml
+        assert!(std.find_global(&path("math.floor")).is_some());
+        // Removed by framework.toml
+        assert!(std.find_global(&path("print")).is_none());
+
+        // app.toml takes precedence over framework.toml
+        match std.find_global(&path("framework.route")) {
+            Some(Field::Function { arguments, .. }) => assert_eq!(arguments.len(), 3),
+            other => panic!("framework.route is not a function: {:?}", other),
+        }
+
+        // Struct fields from both are combined
+        assert!(std.find_global(&path("request.body")).is_some());
+        assert!(std.find_global(&path("request.user")).is_some());
+    }
+
+    #[test]
+    fn based_on_cycle() {
+        match StandardLibrary::from_file(&test_library_path("cycle_a.toml")) {
+            Err(StandardLibraryError::BaseCycle(chain)) => {
+                let names = chain
+                    .iter()
+                    .map(|path| path.file_name().unwrap().to_str().unwrap())
+                    .collect::<Vec<_>>();
+
+                assert_eq!(names, vec!["cycle_a.toml", "cycle_b.toml", "cycle_a.toml"]);
+            }
+
+            other => panic!("expected a base cycle error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn chained_libraries() {
+        let directory = test_library_path("");
+        let path = |path: &str| path.split('.').map(ToOwned::to_owned).collect::<Vec<_>>();
+
+        let std = StandardLibrary::from_config_name("app+busted", Some(&directory))
+            .unwrap()
+            .unwrap();
+
+        assert!(std.find_global(&path("describe")).is_some());
+        assert!(std.find_global(&path("framework.route")).is_some());
+        // busted.toml has no name of its own
+        assert_eq!(std.meta.unwrap().name, Some("app".to_owned()));
+
+        // Later libraries take precedence over earlier ones
+        let std = StandardLibrary::from_config_name("lua51+busted", Some(&directory))
+            .unwrap()
+            .unwrap();
+        assert_eq!(std.find_global(&path("assert")), Some(&Field::Any));
+
+        let std = StandardLibrary::from_config_name("busted+lua51", Some(&directory))
+            .unwrap()
+            .unwrap();
+        assert!(matches!(
+            std.find_global(&path("assert")),
+            Some(Field::Function { .. })
+        ));
+    }
+
+    #[test]
+    fn unknown_chained_library() {
+        match StandardLibrary::from_config_name("lua51+missing", Some(&test_library_path(""))) {
+            Err(StandardLibraryError::UnknownLibrary { name, .. }) => assert_eq!(name, "missing"),
+            other => panic!("expected an unknown library error, got {:?}", other),
+        }
     }
 }
@@ -0,0 +1,10 @@
+local enabled = condition and false or true
+local value = condition and nil or default
+local nested = (condition and false or true)
+call(a, b and (nil) or c)
+
+local label = condition and "yes" or "no"
+local result = condition and value or default
+local flag = condition and true or false
+local fallback = a or b
+local both = a and b
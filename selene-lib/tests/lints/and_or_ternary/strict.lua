@@ -0,0 +1,8 @@
+local enabled = condition and false or true
+local result = condition and value or default
+local chained = a and b or c or d
+local called = condition and get() or fallback
+
+local label = condition and "yes" or "no"
+local flag = condition and true or false
+local fallback = a or b
@@ -0,0 +1,16 @@
+assert(player)
+assert(player ~= nil)
+assert(player, "player must not be nil")
+assert(typeof(player) == "Instance", "player must be an Instance")
+
+local file = assert(io.open("data.txt"))
+assert(...)
+
+local function check(value)
+	assert(value.enabled)
+end
+
+do
+	local assert = require("assert")
+	assert(player)
+end
@@ -0,0 +1,2 @@
+assert(player)
+assert(player.enabled)
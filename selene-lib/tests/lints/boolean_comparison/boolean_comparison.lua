@@ -0,0 +1,17 @@
+if x == true then end
+if x ~= true then end
+if x == false then end
+if x ~= false then end
+if true == x then end
+
+if not x == true then end
+if not x == false then end
+if (a and b) == false then end
+if player.alive == false then end
+if isReady() ~= false and other then end
+
+local same = (a == b) == true
+
+if x == nil then end
+if true == false then end
+if x then end
@@ -0,0 +1,8 @@
+local message = "Hello, " .. name .. "! You have " .. count .. " new messages."
+local path = root .. "/" .. folder .. "/" .. file .. ".lua"
+print(a .. b .. c .. d)
+print(a .. b .. (c .. d .. e .. f .. g))
+local header = "one" .. "two" .. "three" .. "four" .. 5
+local same = a .. b .. c .. d .. e == expected
+local length = #a .. b .. c .. d .. e
+local sum = a .. b + c .. d .. e
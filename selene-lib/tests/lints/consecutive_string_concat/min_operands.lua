@@ -0,0 +1,3 @@
+print(a .. b .. c)
+print(a .. b)
+print("a" .. "b" .. "c")
@@ -0,0 +1,59 @@
+local function expensive_operation() end
+local function other_thing() end
+
+local result = expensive_operation()
+result = other_thing()
+print(result)
+
+local total = 0
+total = total + 1
+total = 10
+print(total)
+
+local function check(condition)
+    local message = "unknown"
+
+    if condition then
+        message = "yes"
+    end
+
+    print(message)
+
+    message = "done"
+end
+
+local counter = 1
+print(counter)
+counter = 2
+
+local cleared = {}
+print(cleared)
+cleared = nil
+
+local index = 1
+while index < 10 do
+    index = index + 1
+end
+
+local captured = 1
+local function read()
+    return captured
+end
+captured = 2
+read()
+
+global = 1
+global = 2
+
+local unused = 1
+unused = 2
+
+local value = "first"
+do
+    value = "second"
+end
+print(value)
+
+local a, b = 1, 2
+a, b = b, a
+print(a, b)
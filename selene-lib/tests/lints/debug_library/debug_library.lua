@@ -0,0 +1,12 @@
+local info = debug.getinfo(1)
+debug.sethook(hook, "c")
+local trace = debug.traceback()
+local library = debug
+local getLocal = debug.getlocal
+
+xpcall(main, debug.traceback)
+
+local function inspect()
+	local debug = require("debugger")
+	debug.getinfo(1)
+end
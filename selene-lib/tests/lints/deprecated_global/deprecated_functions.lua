@@ -0,0 +1,18 @@
+wait(5)
+wait()
+local elapsed = wait(delay * 2)
+spawn(function()
+    print("spawned")
+end)
+workspace:FindPartOnRay(ray)
+
+local function forward(...)
+    wait(...)
+end
+
+task.wait(5)
+
+local function shadowed()
+    local wait = print
+    wait(5)
+end
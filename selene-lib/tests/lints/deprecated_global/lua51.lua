@@ -0,0 +1,12 @@
+local a, b = unpack(list)
+local chunk = loadstring("return 1")
+setfenv(chunk, {})
+print(getfenv(1))
+module("inventory", package.seeall)
+
+local values = table.unpack(list)
+
+local function shadowed()
+    local unpack = table.unpack
+    return unpack(list)
+end
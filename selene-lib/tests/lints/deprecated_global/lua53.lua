@@ -0,0 +1,8 @@
+print(bit32.band(1, 2))
+print(math.pow(x, 2))
+print(math.ldexp(m, e))
+print(math.atan2(y, x))
+print(math.atan(y, x))
+print(x ^ 2)
+setfenv(chunk, {})
+print(table.unpack(list))
@@ -0,0 +1,38 @@
+local cfg = {}
+cfg.timeout = 5
+cfg.timeout = 10
+
+local player = {}
+player.name = "Player"
+player.health = 100
+player["name"] = "Guest"
+player.armor = 0
+
+local window = {}
+window.width = 100
+window.width = window.width * 2
+
+local settings = {}
+settings.volume = 1
+settings.muted = isMuted()
+settings.volume = 0.5
+
+local point = {}
+point.x = 1
+print(point.x)
+point.x = 2
+
+local a, b = {}, {}
+a.value = 1
+b.value = 2
+a.value = 3
+
+local list = {}
+list[1] = "a"
+list[1] = "b"
+
+local options = {}
+options.retries = 3
+if retry then
+    options.retries = 5
+end
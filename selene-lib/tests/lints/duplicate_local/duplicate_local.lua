@@ -0,0 +1,24 @@
+local count = 1
+print(count)
+local count = 2
+
+local function update() end
+local function update() end
+
+local value = tonumber(value)
+local value = value + 1
+
+local _ = first()
+local _ = second()
+
+local a, b = 1, 2
+
+if a then
+	local a = 3
+	local x = 1
+end
+
+do
+	local x = 2
+	local x = 3
+end
@@ -0,0 +1,3 @@
+local value = 1
+local value = value + 1
+local value = tostring(value)
@@ -0,0 +1,25 @@
+local function f(a, b, a)
+	return a + b
+end
+
+local g = function(x, x, x)
+	return x
+end
+
+function Player:Move(self, direction)
+	return direction
+end
+
+function Player.Update(self, deltaTime)
+	return deltaTime
+end
+
+function Player:Move(direction)
+	local callback = function(self)
+		return self
+	end
+end
+
+local function ignored(_, _, value, ...)
+	return value
+end
@@ -0,0 +1,9 @@
+assert(value, "bad value: " .. tostring(value))
+assert(value, string.format("bad value: %s", tostring(value)))
+assert(value, ("bad value: %s"):format(tostring(value)))
+assert(value, "bad value")
+assert(value, MESSAGE)
+
+assert(false, "unreachable: " .. reason)
+assert(nil, string.format("unreachable: %s", reason))
+assert(value ~= nil, (("bad value: ") .. name))
@@ -0,0 +1,38 @@
+for index = 1, 10 do
+	local function getIndex()
+		return index
+	end
+
+	callbacks[index] = function()
+		return index
+	end
+end
+
+for _, player in ipairs(players) do
+	player.Chatted:Connect(function(message)
+		print(message)
+	end)
+end
+
+while running do
+	function Module.update() end
+end
+
+repeat
+	local handler = function() end
+until done
+
+local function outside()
+	for _ = 1, 10 do
+		table.sort(list, function(a, b)
+			return a < b
+		end)
+	end
+end
+
+for _ = 1, 10 do
+	local function wrapper()
+		-- Only created when wrapper is called
+		return function() end
+	end
+end
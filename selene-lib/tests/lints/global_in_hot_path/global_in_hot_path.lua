@@ -0,0 +1,30 @@
+local points = {}
+
+for i = 1, math.floor(count) do
+    points[i] = math.sin(i) + math.sin(i * 2)
+end
+
+for _, point in ipairs(points) do
+    print(tostring(point))
+end
+
+while running do
+    local format = string.format
+    for _, line in pairs(lines) do
+        io.write(format("%s\n", line), string.format("%d", #line))
+    end
+end
+
+local sin = math.sin
+for i = 1, 10 do
+    local function helper()
+        return math.cos(i)
+    end
+
+    print(sin(i), math.pi, helper)
+end
+
+repeat
+    local math = {}
+    math.sin(1)
+until string.len(input) > 10
@@ -0,0 +1,101 @@
+local function isAlive(player)
+	if player.Health > 0 then
+		return true
+	else
+		return false
+	end
+end
+
+local function isDead(player)
+	if player.Health > 0 then
+		return false
+	else
+		return true
+	end
+end
+
+local function isEmpty(list)
+	if not next(list) then
+		return true
+	end
+
+	return false
+end
+
+local function hasItem(inventory, item)
+	if inventory[item] then
+		return false
+	end
+	return true
+end
+
+local function sameBranches(x)
+	if x then
+		return true
+	else
+		return true
+	end
+end
+
+local function doesSomething(x)
+	if x then
+		print("x")
+		return true
+	else
+		return false
+	end
+end
+
+local function withElseIf(x)
+	if x == 1 then
+		return true
+	elseif x == 2 then
+		return false
+	else
+		return false
+	end
+end
+
+local function notLast(x)
+	if x then
+		return true
+	end
+
+	print("not x")
+	return false
+end
+
+local function assignments(x, player)
+	local alive
+	if player.Health > 0 then
+		alive = true
+	else
+		alive = false
+	end
+
+	if x.enabled then
+		player.visible = false
+	else
+		player.visible = true
+	end
+
+	if x then
+		alive = true
+	else
+		player.visible = false
+	end
+
+	if x then
+		alive = true
+	else
+		alive = true
+	end
+end
+
+local function hasEither(a, b)
+	if a or b then
+		return true
+	else
+		return false
+	end
+end
@@ -0,0 +1,28 @@
+local script = arg[0]
+
+local function sum(...)
+	local total = 0
+
+	for index = 1, arg.n do
+		total = total + arg[index]
+	end
+
+	return total
+end
+
+local function first(...)
+	return unpack(arg)
+end
+
+local function notVariadic(a)
+	return arg
+end
+
+local function shadowed(...)
+	local arg = { ... }
+	return arg[1]
+end
+
+local function withParameter(arg, ...)
+	return arg
+end
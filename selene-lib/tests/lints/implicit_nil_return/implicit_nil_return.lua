@@ -0,0 +1,104 @@
+local function noReturn()
+    print("hello")
+end
+
+local function explicitReturn()
+    print("hello")
+    return nil
+end
+
+local function bareReturn()
+    return
+end
+
+local function ifWithoutElse(x)
+    if x then
+        return 1
+    end
+end
+
+local function ifElse(x)
+    if x then
+        return 1
+    elseif x == nil then
+        return 2
+    else
+        return 3
+    end
+end
+
+local function elseifFallsThrough(x)
+    if x then
+        return 1
+    elseif x == nil then
+        print("nil")
+    else
+        return 3
+    end
+end
+
+local function errors(x)
+    if x then
+        return 1
+    end
+
+    error("x is required")
+end
+
+local function insideDo()
+    do
+        return 1
+    end
+end
+
+local function forLoop(list)
+    for _, item in ipairs(list) do
+        return item
+    end
+end
+
+local function foreverLoop()
+    while true do
+        coroutine.yield()
+    end
+end
+
+local function breakingLoop()
+    while true do
+        if done() then
+            break
+        end
+    end
+end
+
+local function nestedBreak()
+    while true do
+        for _ = 1, 10 do
+            break
+        end
+    end
+end
+
+local function repeatReturns()
+    repeat
+        return 1
+    until done()
+end
+
+local function repeatForever()
+    repeat
+        coroutine.yield()
+    until false
+end
+
+local callback = function() end
+
+local object = {}
+
+function object:method()
+    local function inner()
+        return 1
+    end
+
+    inner()
+end
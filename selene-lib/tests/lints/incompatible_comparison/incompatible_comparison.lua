@@ -0,0 +1,30 @@
+local x, y = ...
+
+print("10" < 5)
+print(5 >= "10")
+print(#x < "3")
+print(type(x) <= 5)
+print(x < 5)
+print(x < y)
+print(1 < 2)
+print({} < 1)
+print(x .. "" > 5)
+print(nil < 3)
+print("a" == 1)
+
+local limit = "10"
+print(#x > limit)
+print(limit < 5)
+
+local count = 3
+if count <= "3" then
+    count = x
+end
+print(count < "3")
+
+do
+    local name = "a"
+    if x then
+        print(name < 3)
+    end
+end
@@ -0,0 +1,17 @@
+local x, y = ...
+
+print("1" == 1)
+print(someString == true)
+print(1 ~= "1")
+print(type(x) == 5)
+print(#x == "3")
+print(type(x) == nil)
+print(not x == 1)
+print((x) == nil)
+print(x == nil)
+print(x == y)
+print(1 == 2)
+print(#x == type(y))
+print(x + 1 == "2")
+print(("a") == 1)
+print({} == nil)
@@ -0,0 +1,26 @@
+local Car = {}
+
+function Car:drive(speed)
+    self.speed = speed
+end
+
+function Car.stop(this)
+    this.speed = 0
+end
+
+function Car.honk(self, times)
+    print(self, times)
+end
+
+Car.reverse = function(me, speed, ...)
+    me.speed = -speed
+end
+
+function Car.new(speed)
+    return setmetatable({ speed = speed }, Car)
+end
+
+function Car.parts.wheel.spin(this) end
+
+Car.other = function(speed) end
+local function add(self, other) end
@@ -0,0 +1,46 @@
+while true do
+	print("forever")
+end
+
+repeat
+	print("forever")
+until false
+
+while (true) do
+	for index = 1, 10 do
+		break
+	end
+
+	local function stop()
+		return
+	end
+end
+
+while true do
+	if done() then
+		break
+	end
+end
+
+while true do
+	local value = read()
+	if value then
+		return value
+	end
+end
+
+repeat
+	error("failed")
+until false
+
+while true do
+	coroutine.yield()
+end
+
+while true do
+	wait()
+end
+
+while running do
+	print("not forever")
+end
@@ -0,0 +1,15 @@
+while true do
+	wait(1)
+end
+
+while true do
+	task.wait()
+end
+
+while true do
+	RunService.Heartbeat:Wait()
+end
+
+while true do
+	print("forever")
+end
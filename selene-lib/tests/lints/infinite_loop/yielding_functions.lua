@@ -0,0 +1,8 @@
+while true do
+	local message = socket:receive()
+	handle(message)
+end
+
+while true do
+	coroutine.yield()
+end
@@ -0,0 +1,17 @@
+print("value: " .. nil)
+print("alive: " .. true)
+print({} .. "x")
+print("callback: " .. function() end)
+print("a" .. "b" .. false)
+
+local items = {}
+print("items: " .. items)
+
+print("count: " .. 5)
+print(1 .. 2)
+print("name: " .. name)
+print("name: " .. tostring(true))
+
+local message = nil
+message = "hello"
+print(message .. "!")
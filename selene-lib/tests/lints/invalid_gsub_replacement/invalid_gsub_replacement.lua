@@ -0,0 +1,19 @@
+local text, replacement = ...
+
+print(string.gsub(text, "(%w+)=(%w+)", "%2=%1"))
+print(string.gsub(text, "(%w+)=(%w+)", "%3"))
+print(text:gsub("%s+", "%2"))
+print(text:gsub("%s+", "<%1>"))
+print(text:gsub("(%d+)", "%1%"))
+print(text:gsub("(%d+)", "%1%%"))
+print(text:lower():gsub("(a)(b)", "%0%3"))
+
+-- Not literals
+print(text:gsub("(%w+)", replacement))
+print(text:gsub(replacement, "%3"))
+
+-- Invalid patterns are left for Lua to report
+print(text:gsub("(%w+", "%2"))
+
+local string = {}
+print(string.gsub(text, "x", "%5"))
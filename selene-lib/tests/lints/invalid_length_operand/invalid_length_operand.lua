@@ -0,0 +1,34 @@
+print(#1)
+print(#true)
+print(#nil)
+print(#function() end)
+print(#(5))
+print(1 + #false)
+
+print(#"hello")
+print(#{ 1, 2, 3 })
+print(#items)
+
+local count = 5
+print(#count)
+
+local empty = nil
+if condition then
+	print(#empty)
+end
+
+local reassigned = nil
+reassigned = {}
+print(#reassigned)
+
+local shadowed = 1
+local shadowed = "one"
+print(#shadowed)
+
+local outer = 1
+local function f()
+	local outer = #outer
+end
+
+local multiple, values = 1
+print(#multiple, #values)
@@ -0,0 +1,4 @@
+print(os.date("%F %T"))
+print(os.date("%Ec %Oy"))
+print(os.date("%Q"))
+print(os.date("%Eq"))
@@ -0,0 +1,16 @@
+print(os.date("%Y-%m-%d %H:%M:%S"))
+print(os.date("!%c"))
+print(os.date("*t"))
+print(os.date("!*t", 0))
+print(os.date("100%%"))
+print(os.date(format))
+print(os.date())
+
+print(os.date("%Q"))
+print(os.date("%Y-%m-%d %"))
+print(os.date("*t %Y"))
+print(os.date "%F")
+print(os.date("%Ec"))
+
+local os = {}
+print(os.date("%Q"))
@@ -0,0 +1,15 @@
+print(math.random(0))
+print(math.random(-5))
+print(math.random(10, 1))
+print(math.random(-1, -10))
+
+print(math.random())
+print(math.random(1))
+print(math.random(10))
+print(math.random(1, 10))
+print(math.random(5, 5))
+print(math.random(0x10, 0xFF))
+print(math.random(count))
+print(math.random(10, count))
+print(math.random(1.5, 2.5))
+print(math.random(1.5, 1.2))
@@ -0,0 +1,4 @@
+print(math.random(1.5))
+print(math.random(1, 2.5))
+print(math.random(1, 10))
+print(math.random(1e3))
@@ -0,0 +1,10 @@
+print(Random.new():NextInteger(10, 1))
+
+local rng = Random.new(seed)
+print(rng:NextInteger(100, 0))
+print(rng:NextInteger(0, 100))
+print(rng:NextNumber(100, 0))
+print(Random.new():NextInteger(1, 6))
+
+local other = {}
+print(other:NextInteger(10, 1))
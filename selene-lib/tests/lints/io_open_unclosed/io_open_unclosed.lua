@@ -0,0 +1,49 @@
+local function readAll(path)
+	local file = io.open(path, "r")
+	local contents = file:read("*a")
+	return contents
+end
+
+local function readChecked(path)
+	local file = assert(io.open(path))
+	print(file:read("*l"))
+end
+
+local function readSafely(path)
+	local ok, file = pcall(io.open, path)
+	if ok then
+		print(file:read("*a"))
+	end
+end
+
+local function closed(path)
+	local file = io.open(path)
+	local contents = file:read("*a")
+	file:close()
+	return contents
+end
+
+local function closedWithIo(path)
+	local file, err = io.open(path)
+	print(file:read("*a"))
+	io.close(file)
+end
+
+local function returned(path)
+	local file = io.open(path, "w")
+	file:write("hello")
+	return file
+end
+
+local function passed(path)
+	local file = io.open(path)
+	process(file)
+end
+
+local function closedLater(path)
+	local file = io.open(path)
+
+	return function()
+		file:close()
+	end
+end
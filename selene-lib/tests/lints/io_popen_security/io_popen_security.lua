@@ -0,0 +1,8 @@
+local handle = io.popen("ls")
+local file = io.open("file.txt")
+
+local popen = io.popen
+popen("whoami", "r")
+
+local lib = io
+lib.popen("date"):close()
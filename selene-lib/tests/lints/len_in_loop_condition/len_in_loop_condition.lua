@@ -0,0 +1,32 @@
+local index = 1
+while index <= #items do
+	print(items[index])
+	index = index + 1
+end
+
+while #queue > 0 do
+	process(table.remove(queue, 1))
+end
+
+while #queue > 0 do
+	table.remove(queue, 1)
+end
+
+repeat
+	results[#results + 1] = next()
+until #results >= limit
+
+while (index < #self.children) and running do
+	self.children[index] = nil
+	index = index + 1
+end
+
+-- The length isn't in the condition
+for i = 1, #items do
+	print(items[i])
+end
+
+local count = #items
+while index <= count do
+	index = index + 1
+end
@@ -0,0 +1,4 @@
+local short = "this line is fine"
+-- これは日本語のコメントです
+-- こんにちは
+local long = compute(first, second, third, fourth)
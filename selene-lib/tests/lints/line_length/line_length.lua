@@ -0,0 +1,7 @@
+local short = "this line is fine"
+local long = compute(first, second, third, fourth)
+-- これは日本語のコメントで、バイト数では長いです
+local message = "this string literal goes on far past the limit"
+-- see https://example.com/a/very/long/path/to/some/documentation
+-- this comment is just long and has no links in it at all
+local x = "short" .. "strings" .. "that go past the limit"
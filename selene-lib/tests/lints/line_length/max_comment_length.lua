@@ -0,0 +1,7 @@
+local long = compute(first, second, third, fourth)
+-- this comment is longer than the code limit
+    -- this comment goes on even further past the comment limit
+local value = 1 -- a trailing comment is part of the code
+--[[
+    a multi-line comment is allowed to be longer than code
+]]
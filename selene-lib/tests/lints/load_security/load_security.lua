@@ -0,0 +1,10 @@
+local chunk = loadstring(code)
+local other = load(code, "chunk")
+dofile("config.lua")
+local loaded = loadfile("config.lua")
+
+local function load(data)
+	return data
+end
+
+load(code)
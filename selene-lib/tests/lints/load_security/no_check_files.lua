@@ -0,0 +1,3 @@
+local chunk = loadstring(code)
+dofile("config.lua")
+local loaded = loadfile("config.lua")
@@ -0,0 +1,33 @@
+local SECONDS_PER_MINUTE = 60
+local HALF_PI = -1.5707
+MAX_HEALTH = 100
+Player.speed = 16
+
+local angle = rotation % 360
+local color = value * 0xFF
+print(3.14159)
+
+local offset = -5 + x
+local timeout = wait(30)
+
+local floored = math.floor(10.5)
+local max = math.max(value, 100)
+local part = string.sub(text, 2, -3)
+
+local primes = { 2, 3, 5, 7 }
+local lookup = { [10] = "ten", size = 12 }
+
+for index = 0, 1 do
+	print(index * -1)
+end
+
+local function pick(tbl)
+	return tbl[2]
+end
+
+local math = {}
+math.floor(4.5)
+
+for level = 10, 50, 10 do
+	print(level)
+end
@@ -0,0 +1,3 @@
+local minutes = seconds / 60
+local channel = color * 255
+local fallback = count + 1
@@ -0,0 +1,11 @@
+local SECONDS_PER_MINUTE = 60
+local timeout = 30
+Player.speed = 16
+
+local primes = { 3, 5, 7 }
+
+for index = 10, 100, 5 do
+	print(index)
+end
+
+local floored = math.floor(10.5)
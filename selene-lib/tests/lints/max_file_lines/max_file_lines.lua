@@ -0,0 +1,12 @@
+local a = 1
+local b = 2
+
+local function add(x, y)
+    return x + y
+end
+
+print(add(a, b))
+print(add(b, a))
+print(add(a, a))
+print(add(b, b))
+print(add(1, 2))
@@ -0,0 +1,8 @@
+local a = 1
+local b = 2
+
+local function add(x, y)
+    return x + y
+end
+
+print(add(a, b))
@@ -0,0 +1,33 @@
+local function update(players)
+	for _, player in ipairs(players) do
+		if player.alive then
+			while player.moving do
+				if player.touching then
+					print("too deep")
+				end
+			end
+		end
+	end
+end
+
+for _, item in ipairs(items) do
+	if item.valid then
+		item.callback = function()
+			if item.ready then
+				print("fresh depth inside of the function")
+			end
+		end
+	end
+end
+
+return {
+	nested = {
+		deeper = {
+			method = function()
+				do
+					print("tables do not count")
+				end
+			end,
+		},
+	},
+}
@@ -0,0 +1,27 @@
+function fine(a, b, c, d, e, f, g, h)
+end
+
+function tooMany(a, b, c, d, e, f, g, h, i)
+end
+
+local function localTooMany(a, b, c, d, e, f, g, h, i)
+end
+
+function varargs(a, b, c, d, e, f, g, h, ...)
+end
+
+function Class:Method(a, b, c, d, e, f, g, h)
+end
+
+function Class:TooManyMethod(a, b, c, d, e, f, g, h, i)
+end
+
+function Class.ExplicitSelf(self, a, b, c, d, e, f, g, h)
+end
+
+local anonymousTooMany = function(a, b, c, d, e, f, g, h, i)
+end
+
+table.sort(list, function(a, b)
+	return a < b
+end)
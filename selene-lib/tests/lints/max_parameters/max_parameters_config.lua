@@ -0,0 +1,14 @@
+local function fine(a, b)
+end
+
+local function tooMany(a, b, c)
+end
+
+function Class:Method(a)
+end
+
+function Class:TooManyMethod(a, b)
+end
+
+local callback = function(a, b, c)
+end
@@ -0,0 +1,19 @@
+local object = setmetatable({}, {
+	__Index = Class,
+	__newIndex = function() end,
+	__toString = function() end,
+	__tostrin = function() end,
+	__Eq = function() end,
+	__indx = Class,
+	__toStr = function() end,
+})
+
+setmetatable({}, {
+	__index = Class,
+	__tostring = function() end,
+	__mode = "k",
+	__metatable = false,
+	__className = "Player",
+	__private = true,
+	value = 1,
+})
@@ -0,0 +1,17 @@
+player.move(player, 1, 2)
+player.move(player)
+player.inventory.add(player.inventory, "sword")
+player.inventory.add( player . inventory , "sword")
+players[1].kill(players[1])
+local position = self.getPosition(self):add(offset)
+
+-- Called with something else, such as a base class's method
+Base.new(self)
+player.move(other)
+player.inventory.add(player, "sword")
+player.move()
+player:move(1, 2)
+
+-- Each call could give a different object
+getPlayer().move(getPlayer())
+players[nextIndex()].kill(players[nextIndex()])
@@ -0,0 +1,16 @@
+local file = io.open("data.txt")
+local chunk = loadstring("return 1")
+ok = os.remove("old.txt")
+local a, b = os.rename("a", "b"), 1
+
+local handle, err = io.open("data.txt")
+local chunk, message = load(reader)
+local renamed, c, d = os.rename("a", "b")
+local file = assert(io.open("data.txt"))
+io.open("data.txt")
+local text = string.format("%d", 1)
+
+local function shadowed()
+    local io = { open = print }
+    local file = io.open("data.txt")
+end
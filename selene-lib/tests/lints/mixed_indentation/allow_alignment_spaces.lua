@@ -0,0 +1,13 @@
+if x then
+	print("tabs")
+    print("spaces")
+	  print("tab then spaces")
+  	print("spaces then tab")
+	 	print("tab, space, tab")
+	local text = [[
+	  kept
+]]
+end
+--[[
+	  comment
+]]
@@ -0,0 +1,19 @@
+local name, extra = tostring(value)
+local result, count, other = string.gsub("hello", "l", "L")
+local first, second, third = 1, math.floor(2.5)
+local nothing = print("hello")
+integer, fraction = math.modf(2.5)
+integer, fraction, rest = math.modf(2.5)
+
+local a, b = string.gsub("hello", "l", "L")
+local c, d = tostring(value), 2
+local e, f = (tostring(value))
+local g, h = select(2, ...)
+local i, j = math.floor(x) + 1
+local k, l = string.format("%d", 1), nil
+
+local tostring = function()
+	return 1, 2
+end
+
+local m, n = tostring()
@@ -0,0 +1,24 @@
+local MAX_HEALTH = 100
+local MAX_SPEED_2 = 50
+
+local Player = {}
+local Player_Data = {}
+local Enemy = setmetatable({}, Player)
+
+local player_count = 0
+local playerName = "Player"
+local x = 1
+
+local function update_character(character)
+end
+
+local function getService()
+end
+
+function Player.new_player(name)
+	local Instance = {}
+	return Instance
+end
+
+for Index = 1, 10 do
+end
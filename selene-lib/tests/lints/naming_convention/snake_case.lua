@@ -0,0 +1,28 @@
+local MAX_HEALTH = 100
+local defaultName = "Player"
+local Gravity = -196.2
+
+local playerCount = 0
+local player_name = getName()
+local _private_value = nil
+local x, Y = 1, 2
+
+local function UpdateCharacter(character, walk_speed)
+	local HumanoidRootPart = character.PrimaryPart
+	local localConstant = 5
+end
+
+local function getHTTPService()
+end
+
+local onTouched = function(otherPart)
+end
+
+function Module.DoSomething(self) end
+function Module:do_something_else() end
+
+for index, child_value in ipairs(children) do
+end
+
+for Index = 1, 10 do
+end
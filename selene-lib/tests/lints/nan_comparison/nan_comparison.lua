@@ -0,0 +1,16 @@
+if x == 0/0 then end
+if x ~= 0 / 0 then end
+if (0/0) == x then end
+if x == math.huge - math.huge then end
+if x == 0 * math.huge then end
+if x == -math.huge * 0 then end
+if x < 0/0 then end
+if tbl.value == -(0/0) then end
+if f() == 0/0 then end
+if 0/0 == 0/0 then end
+
+if x ~= x then end
+if x == 1/0 then end
+if x == math.huge then end
+if x == 0/y then end
+local nan = 0/0
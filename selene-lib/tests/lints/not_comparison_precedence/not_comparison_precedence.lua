@@ -0,0 +1,9 @@
+if not x == y then end
+if not player.Name ~= "Roblox" then end
+if not items[1] == expected and ready then end
+
+if not x == true then end
+if not x == false then end
+if not isReady() == y then end
+if not (x == y) then end
+if (not x) == y then end
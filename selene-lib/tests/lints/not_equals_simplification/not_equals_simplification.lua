@@ -0,0 +1,12 @@
+if not (a == b) then end
+if not (a ~= b) then end
+if not (player.Name == "Roblox") and alive then end
+local different = not (count + 1 == total)
+local nested = not ((a == b))
+
+if not (a == b and c) then end
+if not (a < b) then end
+if not a == b then end
+if not (a) then end
+local joined = "equal: " .. tostring(not (a == b))
+local compared = not (a == b) == c
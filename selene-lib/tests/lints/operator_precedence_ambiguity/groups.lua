@@ -0,0 +1,5 @@
+local _ = a == b and c
+local _ = a or b == c
+
+-- No longer in a group
+local _ = a + b * c
@@ -0,0 +1,25 @@
+local _ = a or b and c
+local _ = a + b * c
+local _ = a * b + c
+local _ = "total: " .. a + b
+local _ = a - b ^ 2
+local _ = x == 1 or y and z
+
+-- The same precedence, read left to right
+local _ = a + b - c
+local _ = a or b or c
+
+-- `a and b or c` is a ternary
+local _ = a and b or c
+
+-- Already parenthesized
+local _ = a or (b and c)
+local _ = a + (b * c)
+local _ = (a * b) + c
+
+-- Not in the same group
+local _ = a + b == c
+local _ = a == b and c
+
+-- Inside of other expressions
+print(a or b and c, { a + b * c })
@@ -0,0 +1,19 @@
+os.execute("ls")
+os.execute("rm -rf " .. path)
+os.exit(0)
+
+local execute = os.execute
+execute("whoami")
+
+local system = os
+system.execute("date")
+system.exit(1)
+
+local ok = pcall(os.execute, command)
+
+local function shadowed(os)
+    os.execute("fine")
+end
+
+local os = { execute = print }
+os.execute("also fine")
@@ -0,0 +1 @@
+package.path = "./lib/?.lua;" .. package.path
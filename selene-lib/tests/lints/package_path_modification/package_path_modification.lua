@@ -0,0 +1,19 @@
+package.path = package.path .. ";./lib/?.lua"
+package.cpath = "./?.so"
+package.loaded.inventory = nil
+package.loaded["inventory"] = nil
+package.preload.config = function()
+    return {}
+end
+package.loaded = {}
+
+-- Reading is fine
+print(package.path, package.loaded.inventory)
+
+-- Other fields of `package`
+package.config = ""
+package.path.x = 1
+
+local function shadowed(package)
+    package.path = ""
+end
@@ -0,0 +1,43 @@
+local count = 42
+pcall(count)
+
+local name, message = "print", "hello"
+pcall(name, message)
+
+local handler = nil
+xpcall(handler, debug.traceback)
+
+local ok = true
+local result = pcall(ok)
+
+-- Reported by incorrect_standard_library_use instead
+pcall(42)
+pcall(nil)
+pcall({})
+
+pcall(print, "hello")
+pcall(function() end)
+pcall(callback)
+pcall()
+
+local callable = {}
+setmetatable(callable, { __call = print })
+pcall(callable)
+
+local fn = function() end
+pcall(fn)
+
+local reassigned = 1
+reassigned = print
+pcall(reassigned)
+
+local outer = 1
+do
+    pcall(outer)
+end
+
+local function shadowed()
+    local pcall = function(value) return value end
+    local value = 42
+    pcall(value)
+end
@@ -0,0 +1,6 @@
+pcall(riskyOperation)
+safeCall(riskyOperation)
+Promise.try(riskyOperation)
+
+local success = safeCall(riskyOperation)
+Promise.resolve(riskyOperation)
@@ -0,0 +1,16 @@
+pcall(error, "oops")
+xpcall(riskyOperation, print)
+
+local success, result = pcall(riskyOperation)
+local ok = xpcall(riskyOperation, print)
+
+local function safely(callback)
+	return pcall(callback)
+end
+
+if pcall(riskyOperation) then
+	print("it worked")
+end
+
+print(pcall(riskyOperation))
+safeCall(riskyOperation)
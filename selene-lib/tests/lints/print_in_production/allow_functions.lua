@@ -0,0 +1,17 @@
+local function main()
+    print("usage: tool <file>")
+
+    pcall(function()
+        print("inside of main")
+    end)
+end
+
+function Logger:info(message)
+    print(message)
+end
+
+function Logger.debug(message)
+    print(message)
+end
+
+print("outside")
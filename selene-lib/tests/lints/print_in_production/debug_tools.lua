@@ -0,0 +1 @@
+print("matches the pattern")
@@ -0,0 +1 @@
+print("in a test")
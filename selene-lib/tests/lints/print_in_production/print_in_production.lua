@@ -0,0 +1,17 @@
+print("debugging")
+print(value, other)
+
+local function update()
+    print("updating")
+end
+
+local callback = function()
+    print("called")
+end
+
+local function shadowed()
+    local print = log
+    print("not the global")
+end
+
+warn("not print")
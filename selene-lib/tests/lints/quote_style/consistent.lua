@@ -0,0 +1,4 @@
+print('single')
+print("double")
+print("it's")
+print('again')
@@ -0,0 +1,6 @@
+print("double")
+print('single')
+print('say "hi"')
+print("it's")
+print([[long "string" with 'quotes']])
+local name = require 'module'
@@ -0,0 +1,32 @@
+do
+    print("hello")
+end
+
+do
+end
+
+do
+    local x = 1
+    print(x)
+end
+
+do
+    local function helper() end
+    helper()
+end
+
+local function f()
+    do return end
+    print("unreachable")
+end
+
+while true do
+    print("loop")
+    break
+end
+
+do
+    if x then
+        local y = 1
+    end
+end
@@ -0,0 +1,29 @@
+local function update(player)
+	player:Update()
+	return
+end
+
+local callback = function()
+	return
+end
+
+local function early(player)
+	if not player then
+		return
+	end
+
+	for _, child in ipairs(player.children) do
+		return
+	end
+
+	player:Update()
+end
+
+local function value()
+	return nil
+end
+
+local function empty()
+end
+
+return
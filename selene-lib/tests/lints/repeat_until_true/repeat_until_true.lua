@@ -0,0 +1,27 @@
+repeat
+    print("once")
+until true
+
+repeat
+    if not ready then
+        break
+    end
+
+    print("ready")
+until true
+
+repeat
+    for _, item in ipairs(items) do
+        if item then
+            break
+        end
+    end
+until (true)
+
+repeat
+    count = count + 1
+until count > 10
+
+repeat
+    print("forever")
+until false
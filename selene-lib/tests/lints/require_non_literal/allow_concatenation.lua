@@ -0,0 +1,3 @@
+local plugin = require("plugins/" .. name)
+local module = require(name .. "/init")
+local other = require(moduleName)
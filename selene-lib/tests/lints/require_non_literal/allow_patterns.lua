@@ -0,0 +1,3 @@
+local plugin = require("plugins/" .. name)
+local theme = require("themes/" .. name)
+local module = require(moduleName)
@@ -0,0 +1,11 @@
+local json = require("json")
+local utils = require "utils"
+local config = require(("config"))
+
+local module = require(moduleName)
+local plugin = require("plugins/" .. name)
+local nothing = require()
+
+local req = require
+local other = req(otherName)
+local fine = req("fine")
@@ -0,0 +1,11 @@
+local Module = require(script.Parent.Module)
+local Shared = require(game:GetService("ReplicatedStorage").Shared)
+local Waited = require(script:WaitForChild("Waited"))
+local Indexed = require(script.Parent["Indexed"])
+local Strings = require("strings")
+local Asset = require(1234567)
+
+local Dynamic = require(script.Plugins[name])
+local Found = require(game.ReplicatedStorage:FindFirstChild(name))
+local Other = require(moduleScript)
+local Children = require(script:GetChildren()[1])
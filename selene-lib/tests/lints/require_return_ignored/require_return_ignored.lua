@@ -0,0 +1,7 @@
+require("game.inventory")
+require "polyfills"
+require(script.Parent.Setup)
+
+local inventory = require("game.inventory")
+print(require("game.shop"))
+local result = require("game.shop").open()
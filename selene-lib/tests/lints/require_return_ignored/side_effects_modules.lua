@@ -0,0 +1,3 @@
+require("polyfills")
+require(script.Parent.Setup)
+require("game.inventory")
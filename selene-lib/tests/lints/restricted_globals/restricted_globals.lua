@@ -0,0 +1,19 @@
+print(getfenv(1))
+debug.sethook(nil)
+print(debug.traceback())
+os.execute("rm -rf /")
+print(os.time())
+local library = LoadLibrary("RbxUtility")
+
+local d = debug
+d.sethook(nil)
+local sethook = d.sethook
+sethook(nil)
+
+local execute = os.execute
+execute("ls")
+
+local os = { execute = print }
+os.execute("not the real one")
+
+print(debug)
@@ -0,0 +1,7 @@
+local part = Instance.new("Part", workspace)
+
+local model = Instance.new("Model")
+model.Name = "Tree"
+model.Parent = workspace
+
+local folder = Instance.new("Folder", script.Parent)
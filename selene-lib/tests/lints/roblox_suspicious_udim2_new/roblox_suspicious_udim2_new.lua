@@ -0,0 +1,8 @@
+local size = UDim2.new(1, 1)
+local position = UDim2.new(0.5, 0, 0.5, 0)
+local offset = UDim2.new(100, 0.5, 200, 0.5)
+local mixed = UDim2.new(0.5, 10, 0, 20)
+local variables = UDim2.new(width, height)
+local scale = UDim2.fromScale(1, 1)
+local hex = UDim2.new(0x64, 0.5, 0xC8, 0.5)
+local negative = UDim2.new(-1, 10)
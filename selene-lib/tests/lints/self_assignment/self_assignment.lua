@@ -0,0 +1,14 @@
+local x, y, t = 1, 2, {}
+
+x = x
+t.a = t.a
+t[x] = t[x]
+x, y = x, t
+x, y = y, x
+x = y
+
+t[f()] = t[f()]
+t.list = t.list(x)
+x = x + 1
+x = (x)
+t.a = t .a
@@ -0,0 +1,49 @@
+local Class = {}
+
+function Class:Shadow()
+    local self = {}
+    return self
+end
+
+function Class:Reassign()
+    self = Class.new()
+    return self
+end
+
+function Class:Nested()
+    if self.enabled then
+        local self = self.child
+        print(self)
+    end
+end
+
+function Class:Closure()
+    return function()
+        self = nil
+    end
+end
+
+function Class:Fields()
+    self.x = 1
+    self.a.b = 1
+    self.count = self.count + 1
+    self.items[1] = self
+end
+
+function Class.Plain(self)
+    self = self or Class.new()
+    local self = self
+    return self
+end
+
+function Class:InnerParameter()
+    return function(self)
+        self = self.parent
+        return self
+    end
+end
+
+local function plain()
+    local self = {}
+    return self
+end
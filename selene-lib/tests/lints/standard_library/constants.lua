@@ -5,3 +5,6 @@ collectgarbage("doge")
 collectgarbage(1)
 
 collectgarbage("coun" .. "t")
+
+io.open("file.txt", "r+")
+io.open("file.txt", "x")
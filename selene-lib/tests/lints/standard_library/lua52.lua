@@ -1,2 +1,4 @@
 table.unpack({})
-setfenv() -- This would error if it was defined, since setfenv requires an argument
+setfenv() -- Reported by deprecated_global, rather than here for not passing any arguments
+package.searchpath("inventory", package.path)
+print(package.loaders)
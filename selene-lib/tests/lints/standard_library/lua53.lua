@@ -0,0 +1,12 @@
+print(utf8.char(72, 105))
+for _, code in utf8.codes("hi") do end
+print(utf8.len("hi", 1, 2, 3))
+print(math.type(1), math.tointeger(1.0), math.maxinteger)
+print(math.ult(1))
+table.move({}, 1, 2, 1)
+print(string.pack("i4", 1), string.unpack("i4", data))
+print(table.unpack({}), rawlen({}))
+print(load("return 1", "chunk", "t", {}))
+print(load("return 1", "chunk", "x"))
+print(utf8.foo)
+bit32.band(1, 2) -- Reported by deprecated_global, rather than as a missing field
@@ -0,0 +1,24 @@
+print(bit.band(flags, 0xff), bit.tohex(value), bit.lshift(1, 4))
+print(bit.band())
+
+ffi.cdef([[int printf(const char *format, ...);]])
+ffi.C.printf("%s\n", "hello")
+local point = ffi.new("struct point", 1, 2)
+print(ffi.sizeof(point), ffi.string(buffer, 4), ffi.os)
+ffi.custom = true
+print(ffi.custom)
+
+jit.off()
+jit.opt.start("hotloop=10", 3)
+print(jit.version, jit.os, jit.status())
+
+local list = table.new(10, 0)
+table.clear(list)
+table.new(10)
+
+local buffer = string.buffer.new()
+print(string.buffer.decode(string.buffer.encode({})))
+
+print(load("return 1", "chunk", "t"))
+print(math.log(8, 2))
+print(unpack({}))
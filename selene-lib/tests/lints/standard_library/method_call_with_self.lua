@@ -0,0 +1,5 @@
+foo.bar(foo, 1)
+foo.bar(foo, "a")
+foo.bar(foo)
+foo.bar(1)
+foo.bar(foo, 1, 2)
@@ -0,0 +1,8 @@
+string.find("hello", "l")
+string.find("hello", "l", 1)
+string.find("hello", "l", 1, true)
+
+string.find("hello")
+string.find("hello", "l", 1, true, "extra")
+
+math.max()
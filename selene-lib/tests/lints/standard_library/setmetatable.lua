@@ -0,0 +1,8 @@
+setmetatable("hello", {})
+setmetatable(5, {})
+setmetatable(true, {})
+setmetatable(false, { __index = base })
+
+setmetatable({}, {})
+setmetatable(object, mt)
+setmetatable(create(), mt)
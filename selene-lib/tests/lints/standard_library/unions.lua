@@ -0,0 +1,12 @@
+tostring_or_number("hello")
+tostring_or_number(1)
+tostring_or_number({})
+
+read(1)
+read("*a")
+read("*x")
+read(true)
+
+mode("nil")
+mode("string")
+mode(nil)
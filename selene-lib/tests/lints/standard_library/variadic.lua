@@ -0,0 +1,8 @@
+math.max(1, 2)
+math.max(1, 2, 3, 4)
+math.max(1, 2, "3", 4)
+math.max(1)
+math.max(1, ...)
+
+string.char(72, 105)
+string.char(72, "i", 33)
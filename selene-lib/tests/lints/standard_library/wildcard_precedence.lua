@@ -0,0 +1,9 @@
+print(Enum.Material.Plastic)
+print(Enum.Material.Neon)
+print(Enum.KeyCode.Space)
+print(Enum.KeyCode.Space.Value)
+
+redis.call("GET", "key")
+redis.call(1, "key")
+redis.pcall("GET", "key")
+redis.sha1hex("script")
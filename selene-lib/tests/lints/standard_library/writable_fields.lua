@@ -0,0 +1,4 @@
+config.debug = true
+cache.items = {}
+title.bold = true
+state.ready = true
@@ -0,0 +1,4 @@
+config = {}
+cache = {}
+title = "Hello"
+state = {}
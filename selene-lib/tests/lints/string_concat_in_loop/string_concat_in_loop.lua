@@ -0,0 +1,46 @@
+local output = ""
+for _, line in ipairs(lines) do
+	output = output .. line .. "\n"
+end
+
+local i = 0
+while i < 10 do
+	output = "> " .. output
+	self.buffer = self.buffer .. tostring(i)
+	i = i + 1
+end
+
+repeat
+	output = output .. "."
+until #output > 10
+
+-- Not concatenating onto itself
+for index = 1, 10 do
+	local label = "item " .. index
+	output = prefix .. suffix
+end
+
+-- Uses table.concat, so presumably the concatenation is intentional
+for _, row in ipairs(rows) do
+	local cells = {}
+	for _, cell in ipairs(row) do
+		table.insert(cells, cell)
+	end
+	output = output .. table.concat(cells, ",")
+end
+
+for _, row in ipairs(rows) do
+	for _, cell in ipairs(row) do
+		output = output .. cell
+	end
+	print(table.concat(row))
+end
+
+-- Not run every iteration
+for _, name in ipairs(names) do
+	callbacks[name] = function()
+		output = output .. name
+	end
+end
+
+output = output .. "done"
@@ -0,0 +1,13 @@
+print(string.format("%d %s", 1))
+print(string.format("%d", 1, 2))
+print(string.format("hello"))
+print(string.format("%d%%", 50))
+print(string.format("%s: %5.2f", name, value))
+print(string.format("%s %s %s", ...))
+print(string.format("%s", first, getRest()))
+print(string.format("%s %s", getValues()))
+print(("%s = %s"):format(key))
+print(string.format(template, value))
+
+local string = {}
+string.format("%d")
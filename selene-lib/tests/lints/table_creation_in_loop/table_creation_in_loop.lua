@@ -0,0 +1,53 @@
+local results = {}
+local last
+
+for _, item in ipairs(items) do
+    local point = { x = item.x, y = item.y }
+    print(point.x + point.y)
+
+    local parts = {}
+    for part in item.name:gmatch("%w+") do
+        table.insert(parts, part)
+    end
+    print(table.concat(parts, " "), #parts)
+
+    local result = { item.id }
+    table.insert(results, result)
+
+    local stored = {}
+    results[item.id] = stored
+
+    local kept = {}
+    last = kept
+
+    local passed = {}
+    process(passed)
+
+    local method = {}
+    method:thing()
+
+    local captured = {}
+    later(function()
+        captured.x = 1
+    end)
+end
+
+while running do
+    local buffer = {}
+    buffer[1] = read()
+    print(buffer[1])
+end
+
+local function build()
+    local outside = {}
+    outside.x = 1
+    return outside
+end
+
+for i = 1, 10 do
+    local function create()
+        local inner = {}
+        inner.x = i
+        print(inner.x)
+    end
+end
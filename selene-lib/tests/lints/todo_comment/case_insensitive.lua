@@ -0,0 +1,4 @@
+-- todo: lowercase
+-- Todo: capitalized
+-- Note: configured tag
+-- FIXME is not configured
@@ -0,0 +1,9 @@
+-- TODO: handle errors
+local x = 1 -- FIXME this is wrong
+--[[
+    HACK: works around a bug
+    XXX
+]]
+-- TODOS and todo are not tags
+print("TODO: strings are not comments")
+-- TODO and FIXME on one line
@@ -0,0 +1,12 @@
+local clean = 1
+local spaces = 2   
+local tab = 3	
+-- comment 
+    
+local text = [[
+kept   
+]]
+print(clean)
+--[[
+comment   
+]]
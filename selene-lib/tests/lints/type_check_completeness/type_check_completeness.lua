@@ -0,0 +1,37 @@
+local function describe(value)
+    if type(value) == "number" then
+        print("number")
+    elseif type(value) == "string" or "table" == type(value) then
+        print("string or table")
+    end
+
+    if type(value) == "number" then
+        print("number")
+    elseif type(value) == "string" then
+        print("string")
+    else
+        print("something else")
+    end
+
+    if type(value) == "number" then
+        print("number")
+    elseif type(other) == "string" then
+        print("other is a string")
+    end
+
+    if type(value) == "number" then
+        print("number")
+    elseif value == nil then
+        print("nil")
+    end
+
+    if type(value) == "boolean" or type(value) == "function" or type(value) == "nil" then
+    elseif type(value) == "number" or type(value) == "string" then
+    elseif type(value) == "table" or type(value) == "thread" then
+    elseif type(value) == "userdata" then
+    end
+
+    if type(value) == "table" then
+        print("table")
+    end
+end
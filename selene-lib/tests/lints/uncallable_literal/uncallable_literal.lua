@@ -0,0 +1,11 @@
+(5)()
+local greeting = ("hi")()
+print((true)(1, 2))
+;(nil)()
+local value = ((false))()
+
+;({})()
+;(function() end)()
+local upper = ("hi"):upper()
+local length = ("hi").len
+local result = (callback)()
@@ -0,0 +1,5 @@
+local result = ({})()
+local other = ({ 1, 2, 3 })("argument")
+
+local fine = (setmetatable({}, mt))()
+local number = (5)()
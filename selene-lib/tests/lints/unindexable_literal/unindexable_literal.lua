@@ -0,0 +1,27 @@
+print((nil).x)
+print((true)[1])
+print((42).foo)
+;(5):method()
+;(false).value = 1
+print((function() end).name)
+
+print(("hello"):upper())
+print(("hello").len)
+print(({ 1, 2, 3 })[1])
+
+local count = 5
+print(count.value)
+
+local settings = nil
+if condition then
+	print(settings.enabled)
+end
+
+local player = nil
+player = getPlayer()
+print(player.Name)
+
+local name = "player"
+print(name:upper())
+
+local call = (nil)()
@@ -0,0 +1,100 @@
+local a
+if a then
+    print("never")
+end
+
+local b
+b = 1
+print(b)
+
+local c
+if condition then
+    c = 1
+end
+print(c)
+
+local d
+if condition then
+    d = 1
+else
+    d = 2
+end
+print(d)
+
+local e
+if condition then
+    e = 1
+else
+    error("no e")
+end
+print(e)
+
+local f
+if condition then
+    return
+end
+f = 1
+print(f)
+
+local g
+while condition do
+    g = 1
+end
+print(g)
+
+local h
+repeat
+    h = 1
+until h
+print(h)
+
+local i
+for _ = 1, 10 do
+    if condition then
+        i = 1
+        break
+    end
+end
+print(i)
+
+local j
+repeat
+    if condition then
+        break
+    end
+    j = 1
+until true
+print(j)
+
+local k, l = call()
+print(k, l)
+
+local m, n = 1
+print(m, n)
+
+local o
+setup(function()
+    o = 1
+end)
+print(o)
+
+local p
+do
+    p = 1
+end
+print(p)
+
+local q
+print(q)
+print(q)
+
+local function shadow()
+    local r
+    function r()
+    end
+    r()
+end
+
+local s = {}
+local t
+s[t] = 1
@@ -0,0 +1,32 @@
+Count = 0
+print(Count)
+
+function Foo.bar() end
+function Foo:baz() end
+function update() end
+function update() end
+
+Config.enabled = true
+Config.nested.value = 1
+
+local Tools = {}
+Tools.value = 1
+function Tools.run() end
+
+local function helper() end
+helper = nil
+
+local a, b
+a, b, c = 1, 2, 3
+
+_G.value = 1
+string.custom = nil
+getOverride().value = 1
+
+local function scoped()
+	Scoped = true
+end
+
+package.path = package.path .. ";./lib/?.lua"
+package.loaded.inventory = nil
+package.searchers = {}
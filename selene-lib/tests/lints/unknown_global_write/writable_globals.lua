@@ -0,0 +1,10 @@
+shared.value = 1
+Legacy = 2
+function Legacy.run() end
+Other = 3
+shared = {}
+function callback() end
+callback.value = 1
+Constants.value = 1
+Constants.nested.value = 1
+Constants.items[1] = true
@@ -0,0 +1,29 @@
+local cache = {}
+cache.size = 0
+print(rawget(cache, "size"))
+rawset(cache, "size", cache.size + 1)
+print(rawequal(cache, nil))
+print(rawget({ 1, 2, 3 }, 1))
+
+local proxy = setmetatable({}, {})
+print(rawget(proxy, "key"))
+
+local passed = {}
+register(passed)
+print(rawget(passed, "key"))
+
+local method = {}
+method:init()
+print(rawget(method, "key"))
+
+local reassigned = {}
+reassigned = getTable()
+print(rawget(reassigned, "key"))
+
+print(rawget(unknown, "key"))
+print(rawequal(a, b))
+print(rawequal(1, "1"))
+
+local function scoped(rawget)
+    return rawget(cache, "size")
+end
@@ -0,0 +1,13 @@
+local a = 1;
+local b = 2; local c = 3
+print(b); -- comment
+local t = { 1; 2; 3; }
+
+local d = f;
+(g or h)()
+
+local function foo()
+	return 1;
+end
+
+if a then print(a); end
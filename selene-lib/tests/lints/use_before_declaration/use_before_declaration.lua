@@ -0,0 +1,34 @@
+helper()
+
+local function update()
+	return format(1)
+end
+
+local function helper()
+end
+
+local function format(value)
+	return tostring(value)
+end
+
+local print = print
+local count = count or 0
+
+do
+	value = 1
+end
+
+local value = 2
+
+if condition then
+	inner()
+	local function inner()
+	end
+end
+
+outside()
+
+do
+	local function outside()
+	end
+end
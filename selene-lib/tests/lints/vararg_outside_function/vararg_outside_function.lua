@@ -0,0 +1,23 @@
+local arguments = { ... }
+
+local function variadic(...)
+	print(...)
+end
+
+local function notVariadic(a, b)
+	print(...)
+end
+
+local function outer(...)
+	local function inner()
+		return select("#", ...)
+	end
+
+	local callback = function(...)
+		return ...
+	end
+end
+
+call(function()
+	return ...
+end)
@@ -0,0 +1,50 @@
+local count = 0
+count = count + 1
+count = count * 2
+
+local total = 0
+total = total + 1
+print(total)
+
+local first = 1
+local second = first + 1
+second = second + first
+
+local sum = 0
+for index = 1, 10 do
+    sum = sum + index
+end
+
+local called = 0
+called = tostring(called)
+
+local settings = {}
+local stored = 0
+stored = stored + 1
+settings.value = stored
+
+local captured = 0
+captured = captured + 1
+local function read()
+    return captured
+end
+read()
+
+local a, b = 1, 2
+a, b = b, a
+
+local index = 1
+while index < 10 do
+    index = index + 1
+end
+
+local unused = 1
+unused = 2
+
+local key = "name"
+local t = {}
+t[key] = 1
+print(t)
+
+global = 1
+global = global + 1
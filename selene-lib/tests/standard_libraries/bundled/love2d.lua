@@ -0,0 +1,21 @@
+local image
+
+function love.load()
+    love.window.setTitle("Game")
+    image = love.graphics.newImage("player.png")
+end
+
+function love.update(dt)
+    if love.keyboard.isDown("escape") then
+        love.event.quit()
+    end
+end
+
+function love.draw()
+    love.graphics.setColor(1, 1, 1)
+    love.graphics.draw(image, 100, 100, 0, 2, 2)
+    love.graphics.print(love.timer.getFPS(), 10, 10)
+end
+
+love.handlers.custom = function() end
+print(bit.band(1, 3), jit.version)
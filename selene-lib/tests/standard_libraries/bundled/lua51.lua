@@ -0,0 +1,6 @@
+local list = { 3, 1, 2 }
+table.sort(list)
+print(unpack(list), math.floor(1.5), string.format("%d", #list))
+setfenv(1, getfenv(1))
+local chunk = loadstring("return 1")
+print(pcall(chunk), select("#", 1, 2), os.time(), io.write("done\n"))
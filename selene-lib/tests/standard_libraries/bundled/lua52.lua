@@ -0,0 +1,4 @@
+local list = table.pack(1, 2, 3)
+print(table.unpack(list), rawlen(list), bit32.band(0xff, 0x0f))
+print(load("return 1", "chunk", "t", _ENV), math.log(8, 2), string.rep("a", 3, ","))
+print(xpcall(print, debug.traceback, "message"))
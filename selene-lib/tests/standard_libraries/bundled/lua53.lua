@@ -0,0 +1,4 @@
+print(utf8.char(72, 105), utf8.len("hi"), utf8.charpattern)
+print(math.type(1), math.tointeger(2.0), math.maxinteger, math.ult(1, 2))
+print(table.move({ 1, 2 }, 1, 2, 1, {}), string.pack("i4", 1), string.unpack("i4", "\0\0\0\1"))
+print(coroutine.isyieldable(), math.atan(1, 2))
@@ -0,0 +1,8 @@
+ffi.cdef([[int printf(const char *format, ...);]])
+ffi.C.printf("%d\n", bit.band(0xff, 0x0f))
+local point = ffi.new("struct point")
+print(ffi.sizeof(point), jit.version, jit.os, bit.tohex(255))
+jit.off()
+local list = table.new(10, 0)
+table.clear(list)
+print(string.buffer.new(), load("return 1", "chunk", "t"), math.log(8, 2))
@@ -5,7 +5,7 @@ use std::{
     path::Path,
     sync::{
         atomic::{AtomicUsize, Ordering},
-        Arc, RwLock,
+        Arc, Mutex, RwLock,
     },
 };
 
@@ -16,7 +16,7 @@ use codespan_reporting::{
     term::DisplayStyle as CodespanDisplayStyle,
 };
 use full_moon::ast::owned::Owned;
-use selene_lib::{rules::Severity, standard_library::StandardLibrary, *};
+use selene_lib::{project::RequireGraph, rules::Severity, standard_library::StandardLibrary, *};
 use structopt::{clap, StructOpt};
 use termcolor::{Color, ColorChoice, ColorSpec, StandardStream, WriteColor};
 use threadpool::ThreadPool;
@@ -37,6 +37,8 @@ macro_rules! error {
 
 lazy_static::lazy_static! {
     static ref OPTIONS: RwLock<Option<opts::Options>> = RwLock::new(None);
+    // Only created when circular_require is enabled, as it needs every file to be kept track of
+    static ref REQUIRE_GRAPH: Mutex<Option<RequireGraph>> = Mutex::new(None);
 }
 
 static LINT_ERRORS: AtomicUsize = AtomicUsize::new(0);
@@ -177,7 +179,11 @@ fn read<R: Read>(checker: &Checker<toml::value::Value>, filename: &Path, mut rea
         }
     };
 
-    let mut diagnostics = checker.test_on(&ast);
+    if let Some(require_graph) = REQUIRE_GRAPH.lock().unwrap().as_mut() {
+        require_graph.add_file(filename, &ast);
+    }
+
+    let mut diagnostics = checker.test_on_path(&ast, filename);
     diagnostics.sort_by_key(|diagnostic| diagnostic.diagnostic.start_position());
 
     let (mut errors, mut warnings) = (0, 0);
@@ -277,6 +283,86 @@ fn read<R: Read>(checker: &Checker<toml::value::Value>, filename: &Path, mut rea
     }
 }
 
+fn report_circular_requires(require_graph: &RequireGraph, severity: Severity) {
+    let lock = OPTIONS.read().unwrap();
+    let opts = lock.as_ref().unwrap();
+
+    let stdout = termcolor::StandardStream::stdout(get_color());
+    let mut stdout = stdout.lock();
+
+    for cycle in require_graph.cycles() {
+        match severity {
+            Severity::Error => LINT_ERRORS.fetch_add(1, Ordering::Release),
+            Severity::Warning => LINT_WARNINGS.fetch_add(1, Ordering::Release),
+        };
+
+        let mut chain = cycle
+            .requires
+            .iter()
+            .map(|(path, _)| path.display().to_string())
+            .collect::<Vec<_>>();
+        chain.push(chain[0].to_owned());
+
+        let message = format!("circular require: {}", chain.join(" -> "));
+
+        let mut files = codespan::Files::new();
+        let mut labels = Vec::new();
+
+        for (path, require) in &cycle.requires {
+            let source_id = files.add(
+                path.to_string_lossy(),
+                fs::read_to_string(path).unwrap_or_default(),
+            );
+
+            labels.push(CodespanLabel::new(
+                source_id,
+                codespan::Span::new(require.range.0 as u32, require.range.1 as u32),
+                format!("requires `{}`", require.module),
+            ));
+        }
+
+        let primary_label = labels.remove(0);
+
+        if opts.luacheck {
+            let start = files
+                .location(primary_label.file_id, primary_label.span.start())
+                .unwrap();
+
+            writeln!(
+                stdout,
+                "{}:{}:{}: ({}000) [circular_require] {}",
+                cycle.requires[0].0.display(),
+                start.line.number(),
+                start.column.number(),
+                match severity {
+                    Severity::Error => "E",
+                    Severity::Warning => "W",
+                },
+                message,
+            )
+            .unwrap();
+        } else {
+            emit_codespan(
+                &mut stdout,
+                &files,
+                &CodespanDiagnostic {
+                    severity: match severity {
+                        Severity::Error => CodespanSeverity::Error,
+                        Severity::Warning => CodespanSeverity::Warning,
+                    },
+                    code: Some("circular_require".to_owned()),
+                    message,
+                    primary_label,
+                    notes: vec![
+                        "note: a module that is required while it is still loading will error or be nil".to_owned(),
+                    ],
+                    secondary_labels: labels,
+                },
+            );
+        }
+    }
+}
+
 fn read_file(checker: &Checker<toml::value::Value>, filename: &Path) {
     read(
         checker,
@@ -341,6 +427,17 @@ fn start(matches: opts::Options) {
         },
     };
 
+    // circular_require is not a normal rule, as it needs every file at once
+    let circular_require_severity = match config.rules.get("circular_require") {
+        Some(RuleVariation::Deny) => Some(Severity::Error),
+        Some(RuleVariation::Warn) => Some(Severity::Warning),
+        _ => None,
+    };
+
+    if circular_require_severity.is_some() {
+        *REQUIRE_GRAPH.lock().unwrap() = Some(RequireGraph::new());
+    }
+
     let current_dir = std::env::current_dir().unwrap();
 
     let standard_library = match StandardLibrary::from_config_name(&config.std, Some(&current_dir))
@@ -446,6 +543,13 @@ fn start(matches: opts::Options) {
 
     pool.join();
 
+    if let (Some(require_graph), Some(severity)) = (
+        REQUIRE_GRAPH.lock().unwrap().as_ref(),
+        circular_require_severity,
+    ) {
+        report_circular_requires(require_graph, severity);
+    }
+
     let (parse_errors, lint_errors, lint_warnings) = (
         PARSE_ERRORS.load(Ordering::Relaxed),
         LINT_ERRORS.load(Ordering::Relaxed),
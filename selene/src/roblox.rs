@@ -16,6 +16,7 @@ pub struct RobloxGenerator {
 pub enum GenerateError {
     Http(reqwest::Error),
     Io(std::io::Error),
+    Std(StandardLibraryError),
     TomlDe(toml::de::Error),
     TomlSer(toml::ser::Error),
 }
@@ -25,6 +26,7 @@ impl fmt::Display for GenerateError {
         match self {
             GenerateError::Http(error) => write!(formatter, "HTTP error: {}", error),
             GenerateError::Io(error) => write!(formatter, "IO error: {}", error),
+            GenerateError::Std(error) => write!(formatter, "standard library error: {}", error),
             GenerateError::TomlDe(error) => {
                 write!(formatter, "TOML deserialization error: {}", error)
             }
@@ -69,7 +71,7 @@ impl RobloxGenerator {
                 .unwrap(),
         );
 
-        self.std.inflate();
+        self.std.inflate().map_err(GenerateError::Std)?;
 
         Ok((bytes, self.std))
     }
@@ -182,9 +184,13 @@ impl RobloxGenerator {
                             .map(|_| Argument {
                                 argument_type: ArgumentType::Any,
                                 required: Required::NotRequired,
+                                variadic: false,
                             })
                             .collect(),
                         method: true,
+                        returns: None,
+                        failable: false,
+                        deprecated: None,
                     }),
                 ),
 
@@ -249,6 +255,9 @@ impl RobloxGenerator {
                 Field::Function {
                     arguments: vec![],
                     method: true,
+                    returns: None,
+                    failable: false,
+                    deprecated: None,
                 },
             );
 
@@ -284,8 +293,12 @@ impl RobloxGenerator {
                 arguments: vec![Argument {
                     argument_type: ArgumentType::Constant(instance_names),
                     required: Required::Required(None),
+                    variadic: false,
                 }],
                 method: false,
+                returns: None,
+                failable: false,
+                deprecated: None,
             };
         } else {
             unreachable!()
@@ -313,8 +326,12 @@ impl RobloxGenerator {
             arguments: vec![Argument {
                 argument_type: ArgumentType::Constant(service_names),
                 required: Required::Required(None),
+                variadic: false,
             }],
             method: true,
+            returns: None,
+            failable: false,
+            deprecated: None,
         };
     }
 